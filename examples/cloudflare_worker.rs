@@ -3,10 +3,13 @@
 //! This example demonstrates how to use the edgar-rs library in a Cloudflare Worker
 //! to access SEC EDGAR API endpoints. This example requires the `cloudflare-workers`
 //! feature to be enabled.
+//!
+//! `GET /?cik=320193` returns a summary of the company's submissions and
+//! `GET /facts?cik=320193` a summary of its company facts.
 
 #[cfg(feature = "cloudflare-workers")]
 mod worker_example {
-    use edgar_rs::{EdgarApi, EdgarClient};
+    use edgar_rs::{Cik, Config, EdgarApi, EdgarClient};
     use worker::*;
 
     #[event(fetch)]
@@ -19,70 +22,53 @@ mod worker_example {
             .map(|(_, value)| value.to_string())
             .unwrap_or_else(|| "0000320193".to_string()); // Default to Apple Inc.
 
-        // Create Edgar client for Cloudflare Workers
-        let edgar_client = EdgarClient::new_worker("YourCompany contact@yourcompany.com");
-
-        // Get company submissions
-        match edgar_client.get_submissions_history(&cik).await {
-            Ok(submissions) => {
-                let response_data = serde_json::json!({
-                    "company": submissions.data.name,
-                    "cik": submissions.data.cik,
-                    "entityType": submissions.data.entity_type,
-                    "recent_filings_count": submissions.data.filings.recent.accession_number.len(),
-                    "status": submissions.status
-                });
-
-                Response::from_json(&response_data)
-            }
-            Err(e) => {
-                let error_response = serde_json::json!({
-                    "error": e.to_string(),
-                    "cik": cik
-                });
-
-                Response::from_json(&error_response)?.with_status(500)
-            }
-        }
-    }
+        let parsed_cik = match cik.parse::<Cik>() {
+            Ok(parsed_cik) => parsed_cik,
+            Err(e) => return error_response(e, &cik),
+        };
 
-    #[event(fetch)]
-    pub async fn company_facts(req: Request, _env: Env, _ctx: Context) -> Result<Response> {
-        let url = req.url()?;
-        let cik = url
-            .query_pairs()
-            .find(|(key, _)| key == "cik")
-            .map(|(_, value)| value.to_string())
-            .unwrap_or_else(|| "0000320193".to_string());
-
-        let edgar_client = EdgarClient::new_worker("YourCompany contact@yourcompany.com");
+        // Create Edgar client for Cloudflare Workers
+        let edgar_client =
+            EdgarClient::new_worker(Config::new("YourCompany contact@yourcompany.com"));
 
-        match edgar_client.get_company_facts(&cik).await {
-            Ok(facts) => {
-                let response_data = serde_json::json!({
+        if url.path() == "/facts" {
+            return match edgar_client.get_company_facts(parsed_cik).await {
+                Ok(facts) => Response::from_json(&serde_json::json!({
                     "company": facts.data.entity_name,
                     "cik": facts.data.cik,
                     "facts_count": facts.data.facts.len(),
                     "status": facts.status
-                });
-
-                Response::from_json(&response_data)
-            }
-            Err(e) => {
-                let error_response = serde_json::json!({
-                    "error": e.to_string(),
-                    "cik": cik
-                });
+                })),
+                Err(e) => error_response(e, &cik),
+            };
+        }
 
-                Response::from_json(&error_response)?.with_status(500)
-            }
+        // Get company submissions
+        match edgar_client.get_submissions_history(parsed_cik).await {
+            Ok(submissions) => Response::from_json(&serde_json::json!({
+                "company": submissions.data.name,
+                "cik": submissions.data.cik,
+                "entityType": submissions.data.entity_type,
+                "recent_filings_count": submissions.data.filings.recent.accession_number.len(),
+                "status": submissions.status
+            })),
+            Err(e) => error_response(e, &cik),
         }
     }
+
+    fn error_response(error: impl std::fmt::Display, cik: &str) -> Result<Response> {
+        let error_response = serde_json::json!({
+            "error": error.to_string(),
+            "cik": cik
+        });
+
+        Ok(Response::from_json(&error_response)?.with_status(500))
+    }
 }
 
-// For non-worker builds, provide a placeholder
-#[cfg(not(feature = "cloudflare-workers"))]
+// Outside of a Worker, provide a placeholder
+#[cfg(not(all(target_arch = "wasm32", feature = "cloudflare-workers")))]
 fn main() {
-    println!("This example requires the 'cloudflare-workers' feature to be enabled.");
-    println!("Build with: cargo build --example cloudflare_worker --features cloudflare-workers");
-}
\ No newline at end of file
+    println!("This example runs as a Cloudflare Worker and requires the 'cloudflare-workers' feature.");
+    println!("Build with: cargo build --example cloudflare_worker --target wasm32-unknown-unknown --features cloudflare-workers");
+}
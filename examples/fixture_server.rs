@@ -0,0 +1,95 @@
+//! Local mock of the SEC endpoints for the browser test suite.
+//!
+//! Serves the fixtures in `tests/fixtures`, hand-trimmed excerpts of SEC
+//! responses, over HTTP with CORS headers the way a CORS proxy would: a request
+//! for `http://127.0.0.1:8787/data.sec.gov/submissions/CIK0000320193.json`
+//! returns the fixture for `https://data.sec.gov/submissions/CIK0000320193.json`.
+//!
+//! Run with `cargo run --example fixture_server [address] [fixtures dir]`.
+
+use edgar_rs::{HttpClient, MockHttpClient};
+use std::env;
+use std::error::Error;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+
+const DEFAULT_ADDRESS: &str = "127.0.0.1:8787";
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = env::args().skip(1);
+    let address = args.next().unwrap_or_else(|| DEFAULT_ADDRESS.to_string());
+    let fixtures = args
+        .next()
+        .unwrap_or_else(|| concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures").to_string());
+
+    let client = MockHttpClient::from_fixtures(&fixtures)?;
+    let listener = TcpListener::bind(&address)?;
+    println!("Serving {} on http://{}/", fixtures, address);
+
+    for stream in listener.incoming() {
+        if let Err(e) = handle(stream?, &client).await {
+            eprintln!("Request failed: {}", e);
+        }
+    }
+    Ok(())
+}
+
+async fn handle(mut stream: TcpStream, client: &MockHttpClient) -> Result<(), Box<dyn Error>> {
+    let mut reader = BufReader::new(stream.try_clone()?);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+
+    // Skip the request headers
+    let mut line = String::new();
+    while reader.read_line(&mut line)? > 2 {
+        line.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let method = parts.next().unwrap_or_default();
+    let path = parts.next().unwrap_or("/");
+    println!("{} {}", method, path);
+
+    if method == "OPTIONS" {
+        return respond(&mut stream, 204, "", &[]);
+    }
+
+    let url = format!("https://{}", path.trim_start_matches('/'));
+    match client.get(&url, &[]).await {
+        Ok(response) => {
+            let content_type = response
+                .headers
+                .get("content-type")
+                .map_or("application/octet-stream", String::as_str);
+            respond(&mut stream, response.status, content_type, &response.body)
+        }
+        Err(e) => {
+            let body = serde_json::json!({ "error": e.to_string() }).to_string();
+            respond(&mut stream, 404, "application/json", body.as_bytes())
+        }
+    }
+}
+
+fn respond(
+    stream: &mut TcpStream,
+    status: u16,
+    content_type: &str,
+    body: &[u8],
+) -> Result<(), Box<dyn Error>> {
+    write!(
+        stream,
+        "HTTP/1.1 {} Mock\r\n\
+         Access-Control-Allow-Origin: *\r\n\
+         Access-Control-Allow-Headers: *\r\n\
+         Access-Control-Expose-Headers: *\r\n\
+         Content-Type: {}\r\n\
+         Content-Length: {}\r\n\
+         Connection: close\r\n\r\n",
+        status,
+        content_type,
+        body.len()
+    )?;
+    stream.write_all(body)?;
+    Ok(())
+}
@@ -3,8 +3,7 @@
 //! This example demonstrates how to retrieve revenue-related taxonomy tags
 //! for a company using the SEC EDGAR API.
 
-use edgar_rs::{EdgarClient, EdgarApi, Config};
-use env_logger;
+use edgar_rs::{Cik, EdgarClient, EdgarApi, Config};
 use log::info;
 use std::env;
 use std::error::Error;
@@ -22,12 +21,12 @@ async fn main() -> Result<(), Box<dyn Error>> {
 
     // Get CIK from command line arguments
     let args: Vec<String> = env::args().collect();
-    let cik: &str = if args.len() > 1 {
-        &args[1]
+    let cik: Cik = if args.len() > 1 {
+        args[1].parse()?
     } else {
         // Fallback to Apple Inc. if no argument provided
         println!("No CIK provided. Using Apple Inc. (0000320193) as default.");
-        "0000320193"
+        Cik::new(320193)
     };
 
     println!("Fetching revenue taxonomies for company with CIK: {}", cik);
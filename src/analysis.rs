@@ -0,0 +1,211 @@
+//! Analysis of reported XBRL values.
+//!
+//! The company facts and company concept endpoints list a value once for every
+//! filing that reports it, so a fiscal year shows up in the 10-K that covers it
+//! and again, possibly restated, in the following years' 10-Ks. The helpers in
+//! this module work on any [`ReportedValue`] and are also exposed as methods on
+//! [`CompanyFacts`](crate::CompanyFacts) and [`CompanyConcept`](crate::CompanyConcept).
+
+mod calendar;
+mod metrics;
+mod point_in_time;
+mod quarterly;
+mod restatements;
+mod statements;
+
+pub use calendar::FiscalCalendar;
+pub use metrics::{
+    Formula, Metric, MetricDiagnostic, MetricEngine, MetricInput, MetricSeries, MetricSource,
+    MetricValue,
+};
+#[cfg(feature = "chrono")]
+pub(crate) use point_in_time::as_of_cutoff;
+pub use point_in_time::values_as_of;
+pub(crate) use point_in_time::{parse_as_of_date, values_known_on};
+pub use quarterly::{
+    quarterly_values, trailing_twelve_months, QuarterSource, QuarterlyValue, TtmValue,
+};
+pub use restatements::{
+    deduplicate_values, restatement_histories, DedupPolicy, ReportingPeriod, RestatementHistory,
+};
+pub use statements::{
+    Concept, LineItem, Statement, StatementBuilder, StatementKind, StatementPeriod, StatementValue,
+    UnitKind,
+};
+
+use crate::identifiers::AccessionNumber;
+use crate::models::company_concept::ConceptValue;
+use crate::models::company_facts::FactValue;
+
+/// A value as reported in a single XBRL filing.
+///
+/// Implemented by [`FactValue`] and [`ConceptValue`] so the analysis helpers
+/// work on both company facts and company concepts.
+pub trait ReportedValue {
+    /// The end date of the reporting period (YYYY-MM-DD).
+    fn end(&self) -> &str;
+
+    /// The start date of the reporting period, or `None` for instant values.
+    fn start(&self) -> Option<&str>;
+
+    /// The date the filing was made (YYYY-MM-DD).
+    fn filed(&self) -> &str;
+
+    /// The accession number of the filing.
+    fn accession_number(&self) -> AccessionNumber;
+
+    /// The form type of the filing, e.g. "10-K".
+    fn form(&self) -> &str;
+
+    /// The fiscal year of the filing, if reported.
+    fn fiscal_year(&self) -> Option<i32>;
+
+    /// The fiscal period of the filing, e.g. "Q1" or "FY", if reported.
+    fn fiscal_period(&self) -> Option<&str>;
+
+    /// The calendar frame the SEC aligned the value to, e.g. "CY2023Q4I".
+    fn frame(&self) -> Option<&str>;
+
+    /// The value as a number, or `None` for non-numeric facts.
+    fn numeric_value(&self) -> Option<f64>;
+
+    /// The reporting period the value covers.
+    fn period(&self) -> ReportingPeriod {
+        ReportingPeriod::new(self.start().map(str::to_string), self.end())
+    }
+}
+
+impl ReportedValue for FactValue {
+    fn end(&self) -> &str {
+        &self.end
+    }
+
+    fn start(&self) -> Option<&str> {
+        self.start.as_deref()
+    }
+
+    fn filed(&self) -> &str {
+        &self.filed
+    }
+
+    fn accession_number(&self) -> AccessionNumber {
+        self.accn
+    }
+
+    fn form(&self) -> &str {
+        &self.form
+    }
+
+    fn fiscal_year(&self) -> Option<i32> {
+        self.fy
+    }
+
+    fn fiscal_period(&self) -> Option<&str> {
+        self.fp.as_deref()
+    }
+
+    fn frame(&self) -> Option<&str> {
+        self.frame.as_deref()
+    }
+
+    fn numeric_value(&self) -> Option<f64> {
+        self.as_f64()
+    }
+}
+
+impl ReportedValue for ConceptValue {
+    fn end(&self) -> &str {
+        &self.end
+    }
+
+    fn start(&self) -> Option<&str> {
+        self.start.as_deref()
+    }
+
+    fn filed(&self) -> &str {
+        &self.filed
+    }
+
+    fn accession_number(&self) -> AccessionNumber {
+        self.accn
+    }
+
+    fn form(&self) -> &str {
+        &self.form
+    }
+
+    fn fiscal_year(&self) -> Option<i32> {
+        Some(self.fy)
+    }
+
+    fn fiscal_period(&self) -> Option<&str> {
+        Some(&self.fp)
+    }
+
+    fn frame(&self) -> Option<&str> {
+        self.frame.as_deref()
+    }
+
+    fn numeric_value(&self) -> Option<f64> {
+        Some(self.val)
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod test_support {
+    //! Fixture factories for the analysis tests.
+
+    use serde_json::{json, Value};
+
+    use crate::models::company_concept::ConceptValue;
+    use crate::models::company_facts::CompanyFacts;
+
+    /// Accession number for values whose filing doesn't matter to a test.
+    pub(crate) const ACCN: &str = "0000000001-24-000001";
+
+    /// Builds a value reported in a 10-K. An empty `start` makes it an instant.
+    pub(crate) fn concept_value(
+        start: &str,
+        end: &str,
+        val: f64,
+        accn: &str,
+        filed: &str,
+    ) -> ConceptValue {
+        ConceptValue {
+            end: end.to_string(),
+            val,
+            accn: accn.parse().unwrap(),
+            fy: 2023,
+            fp: "FY".to_string(),
+            form: "10-K".to_string(),
+            filed: filed.to_string(),
+            frame: None,
+            start: (!start.is_empty()).then(|| start.to_string()),
+        }
+    }
+
+    /// Builds the JSON of a company facts value reported in a 10-K, for use
+    /// with [`company_facts`]. A `None` start makes it an instant.
+    pub(crate) fn fact(start: Option<&str>, end: &str, val: f64, filed: &str) -> Value {
+        json!({
+            "start": start,
+            "end": end,
+            "val": val,
+            "accn": ACCN,
+            "fy": 2024,
+            "fp": "FY",
+            "form": "10-K",
+            "filed": filed
+        })
+    }
+
+    /// Builds the company facts of "Example Corp" from its us-gaap concepts.
+    pub(crate) fn company_facts(us_gaap: Value) -> CompanyFacts {
+        serde_json::from_value(json!({
+            "cik": 1,
+            "entityName": "Example Corp",
+            "facts": {"us-gaap": us_gaap}
+        }))
+        .unwrap()
+    }
+}
@@ -0,0 +1,328 @@
+//! Fiscal calendars and alignment of reporting periods with calendar frames.
+
+use std::fmt;
+
+use super::ReportingPeriod;
+use crate::error::{EdgarApiError, Result};
+use crate::types::Period;
+use crate::utils::date::{add_days, days_between, days_in_month, parse_ymd};
+
+/// A company's fiscal year end, such as September 30 for Apple.
+///
+/// Fiscal years are named after the calendar year they end in, so Apple's
+/// fiscal 2024 runs from October 2023 to September 2024 and its first fiscal
+/// quarter lines up with CY2023Q4. Filers with 52-53 week years end near,
+/// rather than on, the nominal date; the `*_of_period` methods and
+/// [`ReportingPeriod::calendar_frame`] use the midpoint of a period, so a few
+/// days either way don't change the quarter it is assigned to.
+///
+/// # Example
+///
+/// ```
+/// use edgar_rs::{FiscalCalendar, Period};
+///
+/// let apple = FiscalCalendar::parse("0930")?;
+/// assert_eq!(apple.fiscal_quarter_of("2023-11-15"), Some((2024, 1)));
+/// assert_eq!(apple.to_calendar_quarter(2024, 1), Some(Period::Quarterly(2023, 4)));
+/// assert_eq!(apple.from_calendar_quarter(2024, 2), Some((2024, 3)));
+/// # Ok::<(), edgar_rs::EdgarApiError>(())
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FiscalCalendar {
+    month: u32,
+    day: u32,
+}
+
+impl Default for FiscalCalendar {
+    fn default() -> Self {
+        Self::calendar_year()
+    }
+}
+
+impl FiscalCalendar {
+    /// Creates a calendar whose fiscal year ends on `month`/`day`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`] if the month and day don't form a date.
+    pub fn new(month: u32, day: u32) -> Result<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(2000, month) {
+            return Err(EdgarApiError::invalid_date(format!(
+                "fiscal year end {:02}-{:02}",
+                month, day
+            )));
+        }
+        Ok(Self { month, day })
+    }
+
+    /// A fiscal year that is the calendar year, ending December 31.
+    pub fn calendar_year() -> Self {
+        Self { month: 12, day: 31 }
+    }
+
+    /// Parses a fiscal year end as written by EDGAR: "MMDD" in the submissions
+    /// `fiscalYearEnd` field, or "--MM-DD" in the DEI `CurrentFiscalYearEndDate`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`] if the string is in neither format.
+    pub fn parse(fiscal_year_end: &str) -> Result<Self> {
+        let trimmed = fiscal_year_end.trim();
+        let digits = trimmed
+            .strip_prefix("--")
+            .unwrap_or(trimmed)
+            .replace('-', "");
+        let invalid = || {
+            EdgarApiError::invalid_date(format!("{:?}, expected MMDD or --MM-DD", fiscal_year_end))
+        };
+        if digits.len() != 4 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let month = digits[..2].parse().map_err(|_| invalid())?;
+        let day = digits[2..].parse().map_err(|_| invalid())?;
+        Self::new(month, day).map_err(|_| invalid())
+    }
+
+    /// The month the fiscal year ends in (1-12).
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// The day of the month the fiscal year ends on.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns true if fiscal years are calendar years.
+    pub fn is_calendar_year(&self) -> bool {
+        self.month == 12 && self.day == 31
+    }
+
+    /// Returns the nominal last day (YYYY-MM-DD) of quarter `quarter` (1-4) of
+    /// `fiscal_year`, or `None` for any other quarter.
+    ///
+    /// A fiscal year ending on the last day of a month has quarters ending on
+    /// the last day of a month too.
+    pub fn quarter_end(&self, fiscal_year: i32, quarter: u8) -> Option<String> {
+        if !(1..=4).contains(&quarter) {
+            return None;
+        }
+        let months =
+            i64::from(fiscal_year) * 12 + i64::from(self.month) - 1 - 3 * i64::from(4 - quarter);
+        let year = months.div_euclid(12) as i32;
+        let month = months.rem_euclid(12) as u32 + 1;
+        let month_end = self.day >= days_in_month(2001, self.month);
+        let day = if month_end {
+            days_in_month(year, month)
+        } else {
+            self.day.min(days_in_month(year, month))
+        };
+        Some(format!("{:04}-{:02}-{:02}", year, month, day))
+    }
+
+    /// Returns the nominal last day (YYYY-MM-DD) of `fiscal_year`.
+    pub fn year_end(&self, fiscal_year: i32) -> String {
+        self.quarter_end(fiscal_year, 4).unwrap_or_default()
+    }
+
+    /// Returns the fiscal year and quarter containing `date` (YYYY-MM-DD), or
+    /// `None` if the date is malformed.
+    pub fn fiscal_quarter_of(&self, date: &str) -> Option<(i32, u8)> {
+        let (year, _, _) = parse_ymd(date)?;
+        for fiscal_year in [year, year + 1] {
+            for quarter in 1..=4 {
+                let end = self.quarter_end(fiscal_year, quarter)?;
+                if days_between(date, &end)? >= 0 {
+                    return Some((fiscal_year, quarter));
+                }
+            }
+        }
+        None
+    }
+
+    /// Returns the fiscal year containing `date` (YYYY-MM-DD).
+    pub fn fiscal_year_of(&self, date: &str) -> Option<i32> {
+        self.fiscal_quarter_of(date)
+            .map(|(fiscal_year, _)| fiscal_year)
+    }
+
+    /// Returns the fiscal year and quarter a period belongs to: the quarter
+    /// containing the midpoint of a duration, or the quarter ending closest to
+    /// an instant.
+    pub fn fiscal_quarter_of_period(&self, period: &ReportingPeriod) -> Option<(i32, u8)> {
+        if period.is_instant() {
+            let (fiscal_year, quarter) = self.fiscal_quarter_of(&period.end)?;
+            let (prior_year, prior_quarter) = previous_quarter(fiscal_year, quarter);
+            let end = self.quarter_end(fiscal_year, quarter)?;
+            let prior_end = self.quarter_end(prior_year, prior_quarter)?;
+            let closer_to_prior =
+                days_between(&prior_end, &period.end)? < days_between(&period.end, &end)?;
+            return Some(if closer_to_prior {
+                (prior_year, prior_quarter)
+            } else {
+                (fiscal_year, quarter)
+            });
+        }
+        self.fiscal_quarter_of(&midpoint(period)?)
+    }
+
+    /// Returns the calendar quarter frame (CY####Q#) that quarter `quarter` of
+    /// `fiscal_year` best aligns with.
+    pub fn to_calendar_quarter(&self, fiscal_year: i32, quarter: u8) -> Option<Period> {
+        let (prior_year, prior_quarter) = previous_quarter(fiscal_year, quarter);
+        let start = add_days(&self.quarter_end(prior_year, prior_quarter)?, 1)?;
+        let end = self.quarter_end(fiscal_year, quarter)?;
+        ReportingPeriod::new(Some(start), end).calendar_frame()
+    }
+
+    /// Returns the fiscal year and quarter that calendar quarter `quarter` of
+    /// `year` best aligns with.
+    pub fn from_calendar_quarter(&self, year: i32, quarter: u8) -> Option<(i32, u8)> {
+        let calendar = FiscalCalendar::calendar_year();
+        let (prior_year, prior_quarter) = previous_quarter(year, quarter);
+        let start = add_days(&calendar.quarter_end(prior_year, prior_quarter)?, 1)?;
+        let end = calendar.quarter_end(year, quarter)?;
+        self.fiscal_quarter_of_period(&ReportingPeriod::new(Some(start), end))
+    }
+}
+
+/// Displays the fiscal year end the way DEI writes it, e.g. "--09-30".
+impl fmt::Display for FiscalCalendar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "--{:02}-{:02}", self.month, self.day)
+    }
+}
+
+impl ReportingPeriod {
+    /// Returns the calendar frame the SEC would align the period to: CY#### for
+    /// a year, CY####Q# for a quarter, and CY####Q#I for an instant.
+    ///
+    /// Durations are assigned by their midpoint and instants to the closest
+    /// calendar quarter end, so Apple's fiscal 2023 (2022-09-25 to 2023-09-30)
+    /// is CY2023. Other durations, such as six months, have no frame.
+    pub fn calendar_frame(&self) -> Option<Period> {
+        let calendar = FiscalCalendar::calendar_year();
+        let (year, quarter) = calendar.fiscal_quarter_of_period(self)?;
+        let year = u16::try_from(year).ok()?;
+        if self.is_instant() {
+            Some(Period::Instantaneous(year, quarter))
+        } else if self.is_annual() {
+            Some(Period::Annual(year))
+        } else if self.is_quarterly() {
+            Some(Period::Quarterly(year, quarter))
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the midpoint of a duration.
+fn midpoint(period: &ReportingPeriod) -> Option<String> {
+    let start = period.start.as_deref()?;
+    add_days(start, days_between(start, &period.end)? / 2)
+}
+
+/// Returns the quarter before `quarter` of `fiscal_year`.
+fn previous_quarter(fiscal_year: i32, quarter: u8) -> (i32, u8) {
+    if quarter <= 1 {
+        (fiscal_year - 1, 4)
+    } else {
+        (fiscal_year, quarter - 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn period(start: &str, end: &str) -> ReportingPeriod {
+        ReportingPeriod::new((!start.is_empty()).then(|| start.to_string()), end)
+    }
+
+    #[test]
+    fn test_parse_fiscal_calendar() {
+        assert_eq!(
+            FiscalCalendar::parse("0930").unwrap(),
+            FiscalCalendar::new(9, 30).unwrap()
+        );
+        assert_eq!(FiscalCalendar::parse("--01-31").unwrap().month(), 1);
+        assert_eq!(
+            FiscalCalendar::parse("--06-30").unwrap().to_string(),
+            "--06-30"
+        );
+        assert!(FiscalCalendar::parse("1231").unwrap().is_calendar_year());
+        assert!(FiscalCalendar::parse("0231").is_err());
+        assert!(FiscalCalendar::parse("September").is_err());
+    }
+
+    #[test]
+    fn test_fiscal_quarters() {
+        let apple = FiscalCalendar::new(9, 30).unwrap();
+        assert_eq!(apple.year_end(2024), "2024-09-30");
+        assert_eq!(apple.quarter_end(2024, 1).unwrap(), "2023-12-31");
+        assert_eq!(apple.quarter_end(2024, 5), None);
+        assert_eq!(apple.fiscal_quarter_of("2023-10-01"), Some((2024, 1)));
+        assert_eq!(apple.fiscal_quarter_of("2024-09-30"), Some((2024, 4)));
+        assert_eq!(apple.fiscal_year_of("2024-01-15"), Some(2024));
+
+        // 52-53 week quarters that end a few days off the nominal dates
+        assert_eq!(
+            apple.fiscal_quarter_of_period(&period("2023-07-02", "2023-09-30")),
+            Some((2023, 4))
+        );
+        assert_eq!(
+            apple.fiscal_quarter_of_period(&period("", "2023-07-01")),
+            Some((2023, 3))
+        );
+
+        let retailer = FiscalCalendar::parse("0131").unwrap();
+        assert_eq!(retailer.quarter_end(2024, 1).unwrap(), "2023-04-30");
+        assert_eq!(retailer.quarter_end(2024, 4).unwrap(), "2024-01-31");
+        assert_eq!(
+            retailer.to_calendar_quarter(2024, 4),
+            Some(Period::Quarterly(2023, 4))
+        );
+        assert_eq!(retailer.from_calendar_quarter(2023, 2), Some((2024, 2)));
+    }
+
+    #[test]
+    fn test_calendar_frame() {
+        assert_eq!(
+            period("2022-09-25", "2023-09-30").calendar_frame(),
+            Some(Period::Annual(2023))
+        );
+        assert_eq!(
+            period("2023-07-02", "2023-09-30").calendar_frame(),
+            Some(Period::Quarterly(2023, 3))
+        );
+        assert_eq!(
+            period("", "2023-07-01").calendar_frame(),
+            Some(Period::Instantaneous(2023, 2))
+        );
+        assert_eq!(period("2023-01-01", "2023-06-30").calendar_frame(), None);
+    }
+
+    #[test]
+    fn test_fiscal_calendar_from_company_facts() {
+        let annual = |start: &str, end: &str| {
+            serde_json::json!({
+                "start": start, "end": end, "val": 1, "accn": "0000320193-23-000106",
+                "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2023-11-03"
+            })
+        };
+        let facts: crate::CompanyFacts = serde_json::from_value(serde_json::json!({
+            "cik": 320193,
+            "entityName": "Apple Inc.",
+            "facts": {"us-gaap": {"Revenues": {"units": {"USD": [
+                annual("2021-09-26", "2022-09-24"),
+                annual("2022-09-25", "2023-09-30"),
+                annual("2022-09-25", "2023-09-30")
+            ]}}}}
+        }))
+        .unwrap();
+        assert_eq!(
+            facts.fiscal_calendar(),
+            Some(FiscalCalendar::new(9, 30).unwrap())
+        );
+    }
+}
@@ -0,0 +1,1026 @@
+//! Financial ratios and other metrics computed from company facts.
+//!
+//! A [`Metric`] is a named [`Formula`] over statement line items and raw XBRL
+//! concepts. [`MetricEngine`] evaluates metrics for every reporting period an
+//! input was reported for, records the facts each value was computed from and
+//! explains why a value could not be computed instead of returning NaN.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt;
+use std::ops;
+
+use super::{deduplicate_values, Concept, LineItem, ReportedValue, ReportingPeriod};
+use super::{StatementBuilder, StatementKind, StatementValue};
+use crate::models::company_facts::CompanyFacts;
+use crate::utils::date::days_between;
+
+/// How far, in days, a period may be from exactly a year earlier and still
+/// count as the prior year's period. Covers 52-53 week years.
+const PRIOR_YEAR_TOLERANCE_DAYS: i64 = 15;
+
+/// A value a formula reads.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum MetricInput {
+    /// A standardized line item, read with the engine's [`StatementBuilder`].
+    Item(LineItem),
+    /// A concept in a specific unit, e.g. "us-gaap:Goodwill" in "USD".
+    Concept {
+        /// The concept.
+        concept: Concept,
+        /// The unit of measure.
+        unit: String,
+    },
+}
+
+impl fmt::Display for MetricInput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetricInput::Item(item) => write!(f, "{}", item),
+            MetricInput::Concept { concept, unit } => write!(f, "{} ({})", concept, unit),
+        }
+    }
+}
+
+/// A formula over metric inputs.
+///
+/// Formulas combine with `+`, `-`, `*` and `/`. Balance sheet inputs in a
+/// formula over a duration are read at the end of the duration, so
+/// `NetIncome / StockholdersEquity` is net income for the year over equity at
+/// year end.
+///
+/// # Example
+///
+/// ```
+/// use edgar_rs::{Formula, LineItem};
+///
+/// let revenue = Formula::item(LineItem::Revenue);
+/// let gross_margin = (revenue.clone() - Formula::item(LineItem::CostOfRevenue)) / revenue;
+/// let revenue_growth = Formula::growth(Formula::item(LineItem::Revenue));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub enum Formula {
+    /// An input for the period, or for the same period `years_back` years earlier.
+    Input {
+        /// The input.
+        input: MetricInput,
+        /// How many years before the period to read the input.
+        years_back: u32,
+    },
+    /// A constant.
+    Constant(f64),
+    /// The sum of two formulas.
+    Add(Box<Formula>, Box<Formula>),
+    /// The difference of two formulas.
+    Sub(Box<Formula>, Box<Formula>),
+    /// The product of two formulas.
+    Mul(Box<Formula>, Box<Formula>),
+    /// The quotient of two formulas; a zero denominator is a diagnostic.
+    Div(Box<Formula>, Box<Formula>),
+    /// The absolute value of a formula.
+    Abs(Box<Formula>),
+    /// A formula that has to be positive; zero or a negative value is a diagnostic.
+    Positive(Box<Formula>),
+    /// The first formula, or the second if the first can't be computed.
+    Or(Box<Formula>, Box<Formula>),
+}
+
+impl Formula {
+    /// A standardized line item.
+    pub fn item(item: LineItem) -> Self {
+        Self::input(MetricInput::Item(item))
+    }
+
+    /// A concept in a unit of measure.
+    pub fn concept(concept: Concept, unit: impl Into<String>) -> Self {
+        Self::input(MetricInput::Concept {
+            concept,
+            unit: unit.into(),
+        })
+    }
+
+    /// An input for the period.
+    pub fn input(input: MetricInput) -> Self {
+        Formula::Input {
+            input,
+            years_back: 0,
+        }
+    }
+
+    /// A constant.
+    pub fn constant(value: f64) -> Self {
+        Formula::Constant(value)
+    }
+
+    /// The same formula for the period a year earlier.
+    pub fn prior_year(self) -> Self {
+        self.shift(1)
+    }
+
+    /// The change over the prior year relative to the prior year's value, e.g.
+    /// 0.1 for 10% growth.
+    ///
+    /// Growth from a zero or negative prior year value isn't meaningful, so it
+    /// is a [`MetricDiagnostic::NotPositive`] instead of a value.
+    pub fn growth(self) -> Self {
+        let prior = self.clone().prior_year();
+        (self - prior.clone()) / prior.positive()
+    }
+
+    /// The absolute value of the formula.
+    pub fn abs(self) -> Self {
+        Formula::Abs(Box::new(self))
+    }
+
+    /// The formula, which has to be positive to be computed.
+    pub fn positive(self) -> Self {
+        Formula::Positive(Box::new(self))
+    }
+
+    /// The formula, or `fallback` if the formula can't be computed.
+    pub fn or(self, fallback: Formula) -> Self {
+        Formula::Or(Box::new(self), Box::new(fallback))
+    }
+
+    /// Returns every input the formula reads for the period itself.
+    pub fn inputs(&self) -> Vec<&MetricInput> {
+        let mut inputs = Vec::new();
+        self.collect_inputs(&mut inputs);
+        inputs
+    }
+
+    fn collect_inputs<'a>(&'a self, inputs: &mut Vec<&'a MetricInput>) {
+        match self {
+            Formula::Input { input, years_back } => {
+                if *years_back == 0 && !inputs.contains(&input) {
+                    inputs.push(input);
+                }
+            }
+            Formula::Constant(_) => {}
+            Formula::Abs(formula) | Formula::Positive(formula) => formula.collect_inputs(inputs),
+            Formula::Add(a, b)
+            | Formula::Sub(a, b)
+            | Formula::Mul(a, b)
+            | Formula::Div(a, b)
+            | Formula::Or(a, b) => {
+                a.collect_inputs(inputs);
+                b.collect_inputs(inputs);
+            }
+        }
+    }
+
+    fn shift(self, years: u32) -> Self {
+        let shift = |formula: Box<Formula>| Box::new(formula.shift(years));
+        match self {
+            Formula::Input { input, years_back } => Formula::Input {
+                input,
+                years_back: years_back + years,
+            },
+            Formula::Constant(value) => Formula::Constant(value),
+            Formula::Add(a, b) => Formula::Add(shift(a), shift(b)),
+            Formula::Sub(a, b) => Formula::Sub(shift(a), shift(b)),
+            Formula::Mul(a, b) => Formula::Mul(shift(a), shift(b)),
+            Formula::Div(a, b) => Formula::Div(shift(a), shift(b)),
+            Formula::Abs(a) => Formula::Abs(shift(a)),
+            Formula::Positive(a) => Formula::Positive(shift(a)),
+            Formula::Or(a, b) => Formula::Or(shift(a), shift(b)),
+        }
+    }
+}
+
+impl ops::Add for Formula {
+    type Output = Formula;
+
+    fn add(self, rhs: Formula) -> Formula {
+        Formula::Add(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl ops::Sub for Formula {
+    type Output = Formula;
+
+    fn sub(self, rhs: Formula) -> Formula {
+        Formula::Sub(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl ops::Mul for Formula {
+    type Output = Formula;
+
+    fn mul(self, rhs: Formula) -> Formula {
+        Formula::Mul(Box::new(self), Box::new(rhs))
+    }
+}
+
+impl ops::Div for Formula {
+    type Output = Formula;
+
+    fn div(self, rhs: Formula) -> Formula {
+        Formula::Div(Box::new(self), Box::new(rhs))
+    }
+}
+
+/// A named formula.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Metric {
+    /// The name, e.g. "gross_margin".
+    pub name: String,
+
+    /// The formula.
+    pub formula: Formula,
+}
+
+impl Metric {
+    /// Creates a metric.
+    pub fn new(name: impl Into<String>, formula: Formula) -> Self {
+        Self {
+            name: name.into(),
+            formula,
+        }
+    }
+
+    /// The metrics computed by [`MetricEngine::new`]:
+    ///
+    /// - `gross_margin`: gross profit, or revenue less cost of revenue, over revenue
+    /// - `operating_margin`: operating income over revenue
+    /// - `net_margin`: net income over revenue
+    /// - `return_on_equity`: net income over stockholders' equity at period end
+    /// - `return_on_assets`: net income over total assets at period end
+    /// - `current_ratio`: current assets over current liabilities
+    /// - `debt_to_equity`: long-term debt over stockholders' equity
+    /// - `free_cash_flow`: operating cash flow less capital expenditures
+    /// - `revenue_growth`: revenue growth over the prior year
+    /// - `eps_growth`: diluted EPS growth over the prior year
+    pub fn standard() -> Vec<Metric> {
+        let item = Formula::item;
+        vec![
+            Metric::new(
+                "gross_margin",
+                item(LineItem::GrossProfit)
+                    .or(item(LineItem::Revenue) - item(LineItem::CostOfRevenue))
+                    / item(LineItem::Revenue),
+            ),
+            Metric::new(
+                "operating_margin",
+                item(LineItem::OperatingIncome) / item(LineItem::Revenue),
+            ),
+            Metric::new(
+                "net_margin",
+                item(LineItem::NetIncome) / item(LineItem::Revenue),
+            ),
+            Metric::new(
+                "return_on_equity",
+                item(LineItem::NetIncome) / item(LineItem::StockholdersEquity),
+            ),
+            Metric::new(
+                "return_on_assets",
+                item(LineItem::NetIncome) / item(LineItem::TotalAssets),
+            ),
+            Metric::new(
+                "current_ratio",
+                item(LineItem::CurrentAssets) / item(LineItem::CurrentLiabilities),
+            ),
+            Metric::new(
+                "debt_to_equity",
+                item(LineItem::LongTermDebt) / item(LineItem::StockholdersEquity),
+            ),
+            Metric::new(
+                "free_cash_flow",
+                item(LineItem::OperatingCashFlow) - item(LineItem::CapitalExpenditures),
+            ),
+            Metric::new("revenue_growth", item(LineItem::Revenue).growth()),
+            Metric::new("eps_growth", item(LineItem::EpsDiluted).growth()),
+        ]
+    }
+}
+
+/// A reported value a metric was computed from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricSource {
+    /// The input the value was read for.
+    pub input: MetricInput,
+
+    /// The period of the value, which differs from the metric's period for
+    /// balance sheet inputs and prior-year inputs.
+    pub period: ReportingPeriod,
+
+    /// The value and the concept and filing it was taken from.
+    pub value: StatementValue,
+}
+
+/// Why a metric could not be computed for a period.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetricDiagnostic {
+    /// An input has no value for the period.
+    MissingInput {
+        /// The input.
+        input: MetricInput,
+        /// The period the input was needed for.
+        period: ReportingPeriod,
+        /// How many years before `period` the input was needed.
+        years_back: u32,
+    },
+    /// A denominator was zero.
+    DivisionByZero,
+    /// A value that has to be positive, such as the base of a growth rate, was
+    /// zero or negative.
+    NotPositive,
+}
+
+impl fmt::Display for MetricDiagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            MetricDiagnostic::MissingInput {
+                input,
+                period,
+                years_back: 0,
+            } => write!(f, "missing {} for {}", input, period),
+            MetricDiagnostic::MissingInput {
+                input,
+                period,
+                years_back,
+            } => write!(
+                f,
+                "missing {} for {} year(s) before {}",
+                input, years_back, period
+            ),
+            MetricDiagnostic::DivisionByZero => write!(f, "division by zero"),
+            MetricDiagnostic::NotPositive => write!(f, "zero or negative base"),
+        }
+    }
+}
+
+/// A metric for one reporting period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricValue {
+    /// The period.
+    pub period: ReportingPeriod,
+
+    /// The value, or `None` if `diagnostics` explains why it couldn't be computed.
+    pub value: Option<f64>,
+
+    /// The reported values the metric was computed from.
+    pub sources: Vec<MetricSource>,
+
+    /// Why the value couldn't be computed; empty if it was.
+    pub diagnostics: Vec<MetricDiagnostic>,
+}
+
+/// A metric for every period one of its inputs was reported for.
+#[derive(Debug, Clone, PartialEq)]
+pub struct MetricSeries {
+    /// The name of the metric.
+    pub name: String,
+
+    /// The values, sorted by period.
+    pub values: Vec<MetricValue>,
+}
+
+impl MetricSeries {
+    /// Returns the value for `period`, if the metric was evaluated for it.
+    pub fn get_period(&self, period: &ReportingPeriod) -> Option<&MetricValue> {
+        self.values.iter().find(|value| &value.period == period)
+    }
+
+    /// Returns the values for periods about a year long.
+    pub fn annual(&self) -> Vec<&MetricValue> {
+        self.values
+            .iter()
+            .filter(|value| value.period.is_annual())
+            .collect()
+    }
+
+    /// Returns the values for periods about three months long.
+    pub fn quarterly(&self) -> Vec<&MetricValue> {
+        self.values
+            .iter()
+            .filter(|value| value.period.is_quarterly())
+            .collect()
+    }
+
+    /// Returns the values that could be computed, as (period, value) pairs.
+    pub fn computed(&self) -> Vec<(&ReportingPeriod, f64)> {
+        self.values
+            .iter()
+            .filter_map(|value| Some((&value.period, value.value?)))
+            .collect()
+    }
+}
+
+/// Computes metrics from company facts.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use edgar_rs::{Cik, Config, EdgarApi, EdgarClient, Formula, LineItem, Metric, MetricEngine};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = EdgarClient::new(Config::new("Your Company Name your.email@example.com"))?;
+///     let facts = client.get_company_facts(Cik::new(320193)).await?;
+///
+///     let engine = MetricEngine::new().with_metric(Metric::new(
+///         "rd_intensity",
+///         Formula::item(LineItem::ResearchAndDevelopment) / Formula::item(LineItem::Revenue),
+///     ));
+///     for series in engine.compute(&facts.data) {
+///         for value in series.annual() {
+///             match value.value {
+///                 Some(v) => println!("{} {}: {:.3}", series.name, value.period, v),
+///                 None => println!("{} {}: {:?}", series.name, value.period, value.diagnostics),
+///             }
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct MetricEngine {
+    metrics: Vec<Metric>,
+    builder: StatementBuilder,
+}
+
+impl Default for MetricEngine {
+    fn default() -> Self {
+        Self {
+            metrics: Metric::standard(),
+            builder: StatementBuilder::new(),
+        }
+    }
+}
+
+impl MetricEngine {
+    /// Creates an engine with the [standard metrics](Metric::standard) and the
+    /// default statement builder.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates an engine without any metrics.
+    pub fn empty() -> Self {
+        Self {
+            metrics: Vec::new(),
+            builder: StatementBuilder::new(),
+        }
+    }
+
+    /// Adds a metric, replacing any metric with the same name.
+    pub fn with_metric(mut self, metric: Metric) -> Self {
+        match self.metrics.iter_mut().find(|m| m.name == metric.name) {
+            Some(existing) => *existing = metric,
+            None => self.metrics.push(metric),
+        }
+        self
+    }
+
+    /// Sets the builder that reads line items, for custom concept chains,
+    /// currency or de-duplication policy.
+    pub fn with_statement_builder(mut self, builder: StatementBuilder) -> Self {
+        self.builder = builder;
+        self
+    }
+
+    /// Returns the registered metrics, in the order they are computed.
+    pub fn metrics(&self) -> &[Metric] {
+        &self.metrics
+    }
+
+    /// Computes every registered metric.
+    pub fn compute(&self, facts: &CompanyFacts) -> Vec<MetricSeries> {
+        let inputs = Inputs::new(&self.builder, facts);
+        self.metrics
+            .iter()
+            .map(|metric| inputs.evaluate(metric))
+            .collect()
+    }
+
+    /// Computes a single registered metric, or returns `None` if no metric has
+    /// that name.
+    pub fn compute_metric(&self, name: &str, facts: &CompanyFacts) -> Option<MetricSeries> {
+        let metric = self.metrics.iter().find(|metric| metric.name == name)?;
+        Some(Inputs::new(&self.builder, facts).evaluate(metric))
+    }
+}
+
+type Series = BTreeMap<ReportingPeriod, StatementValue>;
+
+/// The values of every input, by period.
+struct Inputs<'a> {
+    items: HashMap<LineItem, Series>,
+    builder: &'a StatementBuilder,
+    facts: &'a CompanyFacts,
+}
+
+/// The values and diagnostics collected while evaluating a formula.
+#[derive(Default)]
+struct Evaluation {
+    sources: Vec<MetricSource>,
+    diagnostics: Vec<MetricDiagnostic>,
+}
+
+impl Evaluation {
+    /// Records a diagnostic once, even if the formula reads the input again.
+    fn diagnose(&mut self, diagnostic: MetricDiagnostic) {
+        if !self.diagnostics.contains(&diagnostic) {
+            self.diagnostics.push(diagnostic);
+        }
+    }
+}
+
+impl<'a> Inputs<'a> {
+    fn new(builder: &'a StatementBuilder, facts: &'a CompanyFacts) -> Self {
+        let mut items: HashMap<LineItem, Series> = HashMap::new();
+        for kind in [
+            StatementKind::IncomeStatement,
+            StatementKind::BalanceSheet,
+            StatementKind::CashFlow,
+        ] {
+            for column in builder.build(kind, facts).periods {
+                for (item, value) in column.items {
+                    items
+                        .entry(item)
+                        .or_default()
+                        .insert(column.period.clone(), value);
+                }
+            }
+        }
+        Self {
+            items,
+            builder,
+            facts,
+        }
+    }
+
+    fn series(&self, input: &MetricInput) -> Series {
+        match input {
+            MetricInput::Item(item) => self.items.get(item).cloned().unwrap_or_default(),
+            MetricInput::Concept { concept, unit } => {
+                let values = self
+                    .facts
+                    .get_fact(concept.taxonomy.as_str(), &concept.tag)
+                    .and_then(|fact| fact.units.get(unit))
+                    .map_or(&[][..], Vec::as_slice);
+                deduplicate_values(values, self.builder.policy())
+                    .into_iter()
+                    .filter_map(|value| {
+                        let source = StatementValue {
+                            value: value.numeric_value()?,
+                            concept: concept.clone(),
+                            unit: unit.clone(),
+                            accession_number: value.accn,
+                            filed: value.filed.clone(),
+                            derived: false,
+                        };
+                        Some((value.period(), source))
+                    })
+                    .collect()
+            }
+        }
+    }
+
+    fn evaluate(&self, metric: &Metric) -> MetricSeries {
+        let mut series: HashMap<&MetricInput, Series> = HashMap::new();
+        let mut periods: BTreeSet<ReportingPeriod> = BTreeSet::new();
+        for input in metric.formula.inputs() {
+            let values = self.series(input);
+            periods.extend(values.keys().cloned());
+            series.insert(input, values);
+        }
+        collect_shifted_series(&metric.formula, self, &mut series);
+
+        // Balance sheet inputs are read at the end of a duration, so a formula
+        // with any duration input is only evaluated over durations
+        if periods.iter().any(|period| !period.is_instant()) {
+            periods.retain(|period| !period.is_instant());
+        }
+
+        let values = periods
+            .into_iter()
+            .map(|period| {
+                let mut evaluation = Evaluation::default();
+                let value = evaluate(&metric.formula, &period, &series, &mut evaluation);
+                MetricValue {
+                    period,
+                    value: value.filter(|_| evaluation.diagnostics.is_empty()),
+                    sources: evaluation.sources,
+                    diagnostics: evaluation.diagnostics,
+                }
+            })
+            .collect();
+
+        MetricSeries {
+            name: metric.name.clone(),
+            values,
+        }
+    }
+}
+
+/// Adds the series of inputs that are only read for earlier years.
+fn collect_shifted_series<'f>(
+    formula: &'f Formula,
+    inputs: &Inputs<'_>,
+    series: &mut HashMap<&'f MetricInput, Series>,
+) {
+    match formula {
+        Formula::Input { input, .. } => {
+            if !series.contains_key(input) {
+                series.insert(input, inputs.series(input));
+            }
+        }
+        Formula::Constant(_) => {}
+        Formula::Abs(formula) | Formula::Positive(formula) => {
+            collect_shifted_series(formula, inputs, series)
+        }
+        Formula::Add(a, b)
+        | Formula::Sub(a, b)
+        | Formula::Mul(a, b)
+        | Formula::Div(a, b)
+        | Formula::Or(a, b) => {
+            collect_shifted_series(a, inputs, series);
+            collect_shifted_series(b, inputs, series);
+        }
+    }
+}
+
+fn evaluate(
+    formula: &Formula,
+    period: &ReportingPeriod,
+    series: &HashMap<&MetricInput, Series>,
+    evaluation: &mut Evaluation,
+) -> Option<f64> {
+    let both = |a: &Formula, b: &Formula, evaluation: &mut Evaluation| {
+        let a = evaluate(a, period, series, evaluation);
+        let b = evaluate(b, period, series, evaluation);
+        Some((a?, b?))
+    };
+
+    match formula {
+        Formula::Input { input, years_back } => {
+            let found = series
+                .get(input)
+                .and_then(|values| lookup(values, period, *years_back));
+            match found {
+                Some((source_period, value)) => {
+                    evaluation.sources.push(MetricSource {
+                        input: input.clone(),
+                        period: source_period.clone(),
+                        value: value.clone(),
+                    });
+                    Some(value.value)
+                }
+                None => {
+                    evaluation.diagnose(MetricDiagnostic::MissingInput {
+                        input: input.clone(),
+                        period: period.clone(),
+                        years_back: *years_back,
+                    });
+                    None
+                }
+            }
+        }
+        Formula::Constant(value) => Some(*value),
+        Formula::Add(a, b) => both(a, b, evaluation).map(|(a, b)| a + b),
+        Formula::Sub(a, b) => both(a, b, evaluation).map(|(a, b)| a - b),
+        Formula::Mul(a, b) => both(a, b, evaluation).map(|(a, b)| a * b),
+        Formula::Div(a, b) => {
+            let (a, b) = both(a, b, evaluation)?;
+            if b == 0.0 {
+                evaluation.diagnose(MetricDiagnostic::DivisionByZero);
+                return None;
+            }
+            Some(a / b)
+        }
+        Formula::Abs(formula) => evaluate(formula, period, series, evaluation).map(f64::abs),
+        Formula::Positive(formula) => {
+            let value = evaluate(formula, period, series, evaluation)?;
+            if value <= 0.0 {
+                evaluation.diagnose(MetricDiagnostic::NotPositive);
+                return None;
+            }
+            Some(value)
+        }
+        Formula::Or(first, fallback) => {
+            let mut attempt = Evaluation::default();
+            if let Some(value) = evaluate(first, period, series, &mut attempt) {
+                if attempt.diagnostics.is_empty() {
+                    evaluation.sources.extend(attempt.sources);
+                    return Some(value);
+                }
+            }
+            let mut second = Evaluation::default();
+            let value = evaluate(fallback, period, series, &mut second);
+            if value.is_none() || !second.diagnostics.is_empty() {
+                for diagnostic in attempt.diagnostics {
+                    evaluation.diagnose(diagnostic);
+                }
+            }
+            evaluation.sources.extend(second.sources);
+            for diagnostic in second.diagnostics {
+                evaluation.diagnose(diagnostic);
+            }
+            value
+        }
+    }
+}
+
+/// Finds the value of an input for `period`, or for the matching period
+/// `years_back` years earlier. Instants are used for durations ending on the
+/// same date.
+fn lookup<'s>(
+    values: &'s Series,
+    period: &ReportingPeriod,
+    years_back: u32,
+) -> Option<(&'s ReportingPeriod, &'s StatementValue)> {
+    if years_back == 0 {
+        let instant = ReportingPeriod::new(None, period.end.clone());
+        return values
+            .get_key_value(period)
+            .or_else(|| values.get_key_value(&instant));
+    }
+
+    let expected_shift = (f64::from(years_back) * 365.25).round() as i64;
+    values
+        .iter()
+        .filter_map(|(candidate, value)| {
+            let shift = days_between(&candidate.end, &period.end)?;
+            let off_by = (shift - expected_shift).abs();
+            let same_length = match (candidate.duration_days(), period.duration_days()) {
+                (Some(candidate_days), Some(days)) => {
+                    (candidate_days - days).abs() <= PRIOR_YEAR_TOLERANCE_DAYS
+                }
+                _ => candidate.is_instant(),
+            };
+            (same_length && off_by <= PRIOR_YEAR_TOLERANCE_DAYS)
+                .then_some((off_by, (candidate, value)))
+        })
+        .min_by_key(|(off_by, _)| *off_by)
+        .map(|(_, found)| found)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_support::{company_facts, fact};
+    use serde_json::json;
+
+    fn facts() -> CompanyFacts {
+        let fy2022 = Some("2022-01-01");
+        let fy2023 = Some("2023-01-01");
+        company_facts(json!({
+            "Revenues": {"units": {"USD": [
+                fact(fy2022, "2022-12-31", 80.0, "2024-02-01"),
+                fact(fy2023, "2023-12-31", 100.0, "2024-02-01")
+            ]}},
+            "CostOfRevenue": {"units": {"USD": [
+                fact(fy2023, "2023-12-31", 60.0, "2024-02-01")
+            ]}},
+            "NetIncomeLoss": {"units": {"USD": [
+                fact(fy2022, "2022-12-31", 8.0, "2024-02-01"),
+                fact(fy2023, "2023-12-31", 10.0, "2024-02-01")
+            ]}},
+            "StockholdersEquity": {"units": {"USD": [
+                fact(None, "2023-12-31", 50.0, "2024-02-01")
+            ]}},
+            "AssetsCurrent": {"units": {"USD": [
+                fact(None, "2023-12-31", 30.0, "2024-02-01")
+            ]}},
+            "LiabilitiesCurrent": {"units": {"USD": [
+                fact(None, "2023-12-31", 0.0, "2024-02-01")
+            ]}},
+            "Goodwill": {"units": {"USD": [
+                fact(None, "2023-12-31", 5.0, "2024-02-01")
+            ]}}
+        }))
+    }
+
+    fn series<'a>(results: &'a [MetricSeries], name: &str) -> &'a MetricSeries {
+        results.iter().find(|series| series.name == name).unwrap()
+    }
+
+    #[test]
+    fn test_standard_metrics() {
+        let results = MetricEngine::new().compute(&facts());
+
+        let gross_margin = series(&results, "gross_margin");
+        assert_eq!(gross_margin.values.len(), 2);
+        assert_eq!(gross_margin.values[1].value, Some(0.4));
+        assert_eq!(gross_margin.values[1].sources.len(), 3);
+        assert_eq!(
+            gross_margin.values[0].diagnostics,
+            vec![
+                MetricDiagnostic::MissingInput {
+                    input: MetricInput::Item(LineItem::GrossProfit),
+                    period: gross_margin.values[0].period.clone(),
+                    years_back: 0,
+                },
+                MetricDiagnostic::MissingInput {
+                    input: MetricInput::Item(LineItem::CostOfRevenue),
+                    period: gross_margin.values[0].period.clone(),
+                    years_back: 0,
+                },
+            ]
+        );
+
+        // Net income for the year over equity at year end
+        let roe = series(&results, "return_on_equity");
+        assert_eq!(roe.computed().len(), 1);
+        assert_eq!(roe.computed()[0].1, 0.2);
+        let equity = &roe.values[1].sources[1];
+        assert!(equity.period.is_instant());
+        assert_eq!(equity.value.concept, Concept::us_gaap("StockholdersEquity"));
+
+        let growth = series(&results, "revenue_growth");
+        assert_eq!(growth.annual()[1].value, Some(0.25));
+        assert_eq!(growth.annual()[0].value, None);
+
+        let current_ratio = series(&results, "current_ratio");
+        assert_eq!(
+            current_ratio.values[0].diagnostics,
+            vec![MetricDiagnostic::DivisionByZero]
+        );
+    }
+
+    #[test]
+    fn test_custom_metric_over_concepts() {
+        let engine = MetricEngine::empty().with_metric(Metric::new(
+            "goodwill_to_equity",
+            Formula::concept(Concept::us_gaap("Goodwill"), "USD")
+                / Formula::item(LineItem::StockholdersEquity),
+        ));
+        assert_eq!(engine.metrics().len(), 1);
+
+        let series = engine
+            .compute_metric("goodwill_to_equity", &facts())
+            .unwrap();
+        assert_eq!(series.computed().len(), 1);
+        assert_eq!(series.computed()[0].1, 0.1);
+        assert_eq!(
+            series.values[0].sources[0].input.to_string(),
+            "us-gaap:Goodwill (USD)"
+        );
+        assert!(engine.compute_metric("unknown", &facts()).is_none());
+    }
+
+    fn period(start: &str, end: &str) -> ReportingPeriod {
+        ReportingPeriod::new(Some(start.to_string()), end)
+    }
+
+    fn revenue_growth(us_gaap: serde_json::Value) -> MetricSeries {
+        MetricEngine::new()
+            .compute_metric("revenue_growth", &company_facts(us_gaap))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_prior_year_matches_within_tolerance() {
+        // A 52-53 week year ends on the last Sunday of December
+        let series = revenue_growth(json!({
+            "Revenues": {"units": {"USD": [
+                fact(Some("2021-12-27"), "2022-12-25", 80.0, "2023-02-01"),
+                fact(Some("2022-12-26"), "2023-12-31", 100.0, "2024-02-01"),
+                fact(Some("2024-01-21"), "2025-01-19", 110.0, "2025-02-01")
+            ]}}
+        }));
+
+        let fy2023 = series
+            .get_period(&period("2022-12-26", "2023-12-31"))
+            .unwrap();
+        assert_eq!(fy2023.value, Some(0.25));
+        assert_eq!(fy2023.sources[1].period, period("2021-12-27", "2022-12-25"));
+
+        // 19 days too late to be a year after FY2023
+        let late = series
+            .get_period(&period("2024-01-21", "2025-01-19"))
+            .unwrap();
+        assert_eq!(late.value, None);
+        assert_eq!(
+            late.diagnostics,
+            vec![MetricDiagnostic::MissingInput {
+                input: MetricInput::Item(LineItem::Revenue),
+                period: late.period.clone(),
+                years_back: 1,
+            }]
+        );
+        assert_eq!(
+            late.diagnostics[0].to_string(),
+            "missing Revenue for 1 year(s) before 2024-01-21 to 2025-01-19"
+        );
+    }
+
+    #[test]
+    fn test_or_falls_back_and_keeps_diagnostics_of_failed_attempts() {
+        let metric = Metric::new(
+            "gross_profit",
+            Formula::item(LineItem::GrossProfit)
+                .or(Formula::item(LineItem::Revenue) - Formula::item(LineItem::CostOfRevenue)),
+        );
+        let facts = company_facts(json!({
+            "GrossProfit": {"units": {"USD": [
+                fact(Some("2021-01-01"), "2021-12-31", 35.0, "2022-02-01")
+            ]}},
+            "Revenues": {"units": {"USD": [
+                fact(Some("2021-01-01"), "2021-12-31", 90.0, "2022-02-01"),
+                fact(Some("2022-01-01"), "2022-12-31", 100.0, "2023-02-01"),
+                fact(Some("2023-01-01"), "2023-12-31", 110.0, "2024-02-01")
+            ]}},
+            "CostOfRevenue": {"units": {"USD": [
+                fact(Some("2022-01-01"), "2022-12-31", 60.0, "2023-02-01")
+            ]}}
+        }));
+        let series = MetricEngine::empty()
+            .with_metric(metric)
+            .compute_metric("gross_profit", &facts)
+            .unwrap();
+        let [fy2021, fy2022, fy2023] = &series.values[..] else {
+            panic!("expected three periods, got {:?}", series.values);
+        };
+
+        // The first formula wins without reading the fallback
+        assert_eq!(fy2021.value, Some(35.0));
+        assert_eq!(fy2021.sources.len(), 1);
+        assert!(fy2021.diagnostics.is_empty());
+
+        // A successful fallback drops the first formula's missing inputs
+        assert_eq!(fy2022.value, Some(40.0));
+        assert_eq!(fy2022.sources.len(), 2);
+        assert!(fy2022.diagnostics.is_empty());
+
+        // If both fail, both explain why
+        assert_eq!(fy2023.value, None);
+        let missing: Vec<_> = fy2023
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.to_string())
+            .collect();
+        assert_eq!(
+            missing,
+            vec![
+                "missing GrossProfit for 2023-01-01 to 2023-12-31",
+                "missing CostOfRevenue for 2023-01-01 to 2023-12-31",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_eps_growth() {
+        let facts = company_facts(json!({
+            "EarningsPerShareDiluted": {"units": {"USD/shares": [
+                fact(Some("2022-01-01"), "2022-12-31", 2.0, "2023-02-01"),
+                fact(Some("2023-01-01"), "2023-12-31", 2.5, "2024-02-01")
+            ]}}
+        }));
+
+        let series = MetricEngine::new()
+            .compute_metric("eps_growth", &facts)
+            .unwrap();
+        assert_eq!(series.computed().len(), 1);
+        assert_eq!(series.computed()[0].1, 0.25);
+        assert_eq!(series.values[1].sources[0].value.unit, "USD/shares");
+    }
+
+    #[test]
+    fn test_growth_needs_a_positive_base() {
+        let series = revenue_growth(json!({
+            "Revenues": {"units": {"USD": [
+                fact(Some("2021-01-01"), "2021-12-31", -5.0, "2022-02-01"),
+                fact(Some("2022-01-01"), "2022-12-31", 0.0, "2023-02-01"),
+                fact(Some("2023-01-01"), "2023-12-31", 10.0, "2024-02-01")
+            ]}}
+        }));
+
+        for value in &series.values[1..] {
+            assert_eq!(value.value, None, "{}", value.period);
+            assert_eq!(value.diagnostics, vec![MetricDiagnostic::NotPositive]);
+        }
+    }
+
+    #[test]
+    fn test_missing_inputs_are_reported_per_period() {
+        let results = MetricEngine::new().compute(&facts());
+
+        // No revenue before FY2022 to grow from
+        let growth = series(&results, "revenue_growth");
+        assert_eq!(
+            growth.values[0].diagnostics,
+            vec![MetricDiagnostic::MissingInput {
+                input: MetricInput::Item(LineItem::Revenue),
+                period: period("2022-01-01", "2022-12-31"),
+                years_back: 1,
+            }]
+        );
+
+        // Operating income is never reported
+        let operating_margin = series(&results, "operating_margin");
+        assert!(operating_margin.computed().is_empty());
+        for value in &operating_margin.values {
+            assert_eq!(
+                value.diagnostics,
+                vec![MetricDiagnostic::MissingInput {
+                    input: MetricInput::Item(LineItem::OperatingIncome),
+                    period: value.period.clone(),
+                    years_back: 0,
+                }]
+            );
+            assert_eq!(value.sources.len(), 1);
+        }
+    }
+}
@@ -0,0 +1,127 @@
+//! Point-in-time views of reported values.
+
+use super::{deduplicate_values, DedupPolicy, ReportedValue};
+use crate::error::{EdgarApiError, Result};
+use crate::utils::date::parse_ymd;
+
+/// Returns the values known on `date` (YYYY-MM-DD): one per reporting period,
+/// taken from the latest report filed on or before that date.
+///
+/// Values with a malformed filing date are never known. The result is sorted
+/// by period end date.
+///
+/// # Errors
+///
+/// Returns [`EdgarApiError::InvalidDate`] if `date` is not a YYYY-MM-DD
+/// calendar date.
+pub fn values_as_of<'a, V, I>(values: I, date: &str) -> Result<Vec<&'a V>>
+where
+    V: ReportedValue + 'a,
+    I: IntoIterator<Item = &'a V>,
+{
+    Ok(values_known_on(values, parse_as_of_date(date)?))
+}
+
+/// Returns the values known on `cutoff`, a (year, month, day) date.
+pub(crate) fn values_known_on<'a, V, I>(values: I, cutoff: (i32, u32, u32)) -> Vec<&'a V>
+where
+    V: ReportedValue + 'a,
+    I: IntoIterator<Item = &'a V>,
+{
+    let known = values
+        .into_iter()
+        .filter(|value| parse_ymd(value.filed()).is_some_and(|filed| filed <= cutoff));
+    deduplicate_values(known, DedupPolicy::LatestFiled)
+}
+
+/// Parses the date of a point-in-time view.
+pub(crate) fn parse_as_of_date(date: &str) -> Result<(i32, u32, u32)> {
+    parse_ymd(date)
+        .ok_or_else(|| EdgarApiError::invalid_date(format!("{:?}, expected YYYY-MM-DD", date)))
+}
+
+/// Converts the date of a point-in-time view.
+#[cfg(feature = "chrono")]
+pub(crate) fn as_of_cutoff(date: chrono::NaiveDate) -> (i32, u32, u32) {
+    use chrono::Datelike;
+    (date.year(), date.month(), date.day())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_support::{concept_value, ACCN};
+
+    #[test]
+    fn test_values_as_of() {
+        let values = vec![
+            concept_value(
+                "",
+                "2022-12-31",
+                100.0,
+                "0000000001-23-000001",
+                "2023-02-01",
+            ),
+            concept_value("", "2022-12-31", 95.0, ACCN, "2024-02-01"),
+            concept_value("", "2023-12-31", 120.0, ACCN, "2024-02-01"),
+        ];
+
+        let known = |date| -> Vec<f64> {
+            values_as_of(&values, date)
+                .unwrap()
+                .iter()
+                .map(|v| v.val)
+                .collect()
+        };
+        assert!(known("2023-01-31").is_empty());
+        assert_eq!(known("2023-02-01"), vec![100.0]);
+        assert_eq!(known("2024-01-31"), vec![100.0]);
+        assert_eq!(known("2024-02-01"), vec![95.0, 120.0]);
+
+        assert!(matches!(
+            values_as_of(&values, "Feb 1, 2024"),
+            Err(EdgarApiError::InvalidDate(_))
+        ));
+    }
+
+    #[test]
+    fn test_values_filed_on_the_as_of_date_are_known() {
+        let values = vec![
+            concept_value("", "2023-12-31", 120.0, ACCN, "2024-02-29"),
+            concept_value("", "2023-12-31", 125.0, ACCN, "2024-03-01"),
+            concept_value("", "2024-03-31", 130.0, ACCN, "not a date"),
+        ];
+
+        let known = values_as_of(&values, "2024-02-29").unwrap();
+        assert_eq!(known.len(), 1);
+        assert_eq!(known[0].val, 120.0);
+        assert_eq!(values_as_of(&values, "2024-03-01").unwrap()[0].val, 125.0);
+        assert!(values_as_of(&values, "2024-02-28").unwrap().is_empty());
+        assert_eq!(values_known_on(&values, (2024, 3, 1)).len(), 1);
+    }
+
+    #[test]
+    fn test_as_of_rejects_impossible_dates() {
+        for date in [
+            "2023-02-29",
+            "2023-02-31",
+            "2024-04-31",
+            "2024-00-10",
+            "2024-3-1",
+        ] {
+            assert!(
+                matches!(parse_as_of_date(date), Err(EdgarApiError::InvalidDate(_))),
+                "{}",
+                date
+            );
+        }
+        assert_eq!(parse_as_of_date("2024-02-29").unwrap(), (2024, 2, 29));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_as_of_cutoff() {
+        let date = chrono::NaiveDate::from_ymd_opt(2024, 2, 29).unwrap();
+        assert_eq!(as_of_cutoff(date), parse_as_of_date("2024-02-29").unwrap());
+    }
+}
@@ -0,0 +1,394 @@
+//! Discrete quarterly values derived from year-to-date and annual values.
+//!
+//! 10-Qs report flow items such as revenue for the quarter and also for the
+//! fiscal year to date, and many filers only tag the year-to-date figure for
+//! Q2 and Q3. Q4 is never filed on its own: the 10-K only has the full year.
+//! Subtracting consecutive year-to-date values with the same start date gives
+//! the missing three-month values.
+
+use std::collections::{BTreeMap, HashMap};
+use std::ops::Bound;
+
+use super::{deduplicate_values, DedupPolicy, ReportedValue, ReportingPeriod};
+use crate::identifiers::AccessionNumber;
+use crate::types::Period;
+use crate::utils::date::{add_days, parse_ymd};
+
+/// Where a [`QuarterlyValue`] comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum QuarterSource {
+    /// Reported for the three months.
+    Reported,
+    /// Computed as the value of `cumulative` minus the value of `prior`, two
+    /// periods starting on the same date, e.g. Q4 as the fiscal year minus the
+    /// nine months to date.
+    Derived {
+        /// The longer period.
+        cumulative: ReportingPeriod,
+        /// The shorter period, ending the day before the quarter starts.
+        prior: ReportingPeriod,
+    },
+}
+
+/// The value of a flow item for a single quarter.
+#[derive(Debug, Clone, PartialEq)]
+pub struct QuarterlyValue {
+    /// The quarter.
+    pub period: ReportingPeriod,
+
+    /// The value for the quarter.
+    pub value: f64,
+
+    /// Whether the value was reported or computed.
+    pub source: QuarterSource,
+
+    /// The accession number of the filing that reported the quarter or, for a
+    /// derived value, the longer period.
+    pub accession_number: AccessionNumber,
+
+    /// The date the value could first be known (YYYY-MM-DD): the latest filing
+    /// date of the values it was computed from.
+    pub filed: String,
+}
+
+impl QuarterlyValue {
+    /// Returns true if the value was computed rather than reported.
+    pub fn is_derived(&self) -> bool {
+        matches!(self.source, QuarterSource::Derived { .. })
+    }
+}
+
+/// The sum of four consecutive quarters of a flow item.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TtmValue {
+    /// From the start of the first quarter to the end of the last.
+    pub period: ReportingPeriod,
+
+    /// The sum of the four quarters.
+    pub value: f64,
+
+    /// The calendar quarter (CY####Q#) the last quarter aligns with, so values
+    /// of companies with different fiscal years can be compared.
+    pub frame: Option<Period>,
+
+    /// The quarters summed, oldest first.
+    pub quarters: Vec<QuarterlyValue>,
+
+    /// The date the value could first be known (YYYY-MM-DD): the latest filing
+    /// date of the quarters.
+    pub filed: String,
+}
+
+impl TtmValue {
+    /// Returns true if any of the quarters was derived rather than reported.
+    pub fn is_derived(&self) -> bool {
+        self.quarters.iter().any(QuarterlyValue::is_derived)
+    }
+}
+
+/// A period-to-date value, as reported or summed from a reported quarter.
+#[derive(Debug, Clone)]
+struct ToDate {
+    value: f64,
+    accession_number: AccessionNumber,
+    filed: String,
+}
+
+/// Returns one value per quarter for a flow item such as revenue or operating
+/// cash flow, sorted by quarter.
+///
+/// Reported three-month values are used as they are. Missing quarters are
+/// derived from periods starting on the same date: six months minus the first
+/// quarter, nine months minus six months, and the fiscal year minus nine
+/// months. A reported quarter also extends the period to date, so Q3 can be
+/// derived from nine months, Q1 and a reported Q2 when the six-month value is
+/// missing. Re-reported periods are collapsed with `policy` first; instants and
+/// non-numeric values are ignored.
+///
+/// Derived per-share values such as EPS are only approximate, since the share
+/// count differs between the periods being subtracted.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use edgar_rs::{Cik, DedupPolicy, EdgarApi, EdgarClient, Config, quarterly_values};
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = Config::new("Your Company Name your.email@example.com");
+/// let edgar_api = EdgarClient::new(config)?;
+/// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
+/// if let Some(fact) = facts.data.get_fact("us-gaap", "NetIncomeLoss") {
+///     for quarter in quarterly_values(&fact.units["USD"], DedupPolicy::LatestFiled) {
+///         let flag = if quarter.is_derived() { " (derived)" } else { "" };
+///         println!("{}: {}{}", quarter.period, quarter.value, flag);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn quarterly_values<'a, V, I>(values: I, policy: DedupPolicy) -> Vec<QuarterlyValue>
+where
+    V: ReportedValue + 'a,
+    I: IntoIterator<Item = &'a V>,
+{
+    let mut quarters: BTreeMap<ReportingPeriod, QuarterlyValue> = BTreeMap::new();
+    let mut to_date: BTreeMap<String, BTreeMap<String, ToDate>> = BTreeMap::new();
+
+    for value in deduplicate_values(values, policy) {
+        let (start, amount) = match (value.start(), value.numeric_value()) {
+            (Some(start), Some(amount)) => (start, amount),
+            _ => continue,
+        };
+        let period = value.period();
+        if period.is_quarterly() {
+            quarters.insert(
+                period.clone(),
+                QuarterlyValue {
+                    period,
+                    value: amount,
+                    source: QuarterSource::Reported,
+                    accession_number: value.accession_number(),
+                    filed: value.filed().to_string(),
+                },
+            );
+        }
+        to_date.entry(start.to_string()).or_default().insert(
+            value.end().to_string(),
+            ToDate {
+                value: amount,
+                accession_number: value.accession_number(),
+                filed: value.filed().to_string(),
+            },
+        );
+    }
+
+    let reported: HashMap<String, QuarterlyValue> = quarters
+        .values()
+        .map(|quarter| {
+            (
+                quarter.period.start.clone().unwrap_or_default(),
+                quarter.clone(),
+            )
+        })
+        .collect();
+
+    for (start, mut ends) in to_date {
+        extend_with_reported_quarters(&mut ends, &reported);
+
+        let ends: Vec<(String, ToDate)> = ends.into_iter().collect();
+        for pair in ends.windows(2) {
+            let ((prior_end, prior), (end, cumulative)) = (&pair[0], &pair[1]);
+            let quarter = match add_days(prior_end, 1) {
+                Some(quarter_start) => ReportingPeriod::new(Some(quarter_start), end.clone()),
+                None => continue,
+            };
+            if !quarter.is_quarterly() || quarters.contains_key(&quarter) {
+                continue;
+            }
+
+            let filed =
+                std::cmp::max_by_key(&prior.filed, &cumulative.filed, |filed| parse_ymd(filed));
+            quarters.insert(
+                quarter.clone(),
+                QuarterlyValue {
+                    period: quarter,
+                    value: cumulative.value - prior.value,
+                    source: QuarterSource::Derived {
+                        cumulative: ReportingPeriod::new(Some(start.clone()), end.clone()),
+                        prior: ReportingPeriod::new(Some(start.clone()), prior_end.clone()),
+                    },
+                    accession_number: cumulative.accession_number,
+                    filed: filed.clone(),
+                },
+            );
+        }
+    }
+
+    quarters.into_values().collect()
+}
+
+/// Returns the trailing-twelve-month sums of a flow item, one for every run of
+/// four consecutive quarters from [`quarterly_values`], sorted by end date.
+///
+/// Each value is tagged with the calendar frame of its last quarter, so TTM
+/// revenue ending in Apple's fiscal Q4 (July to September) and in a calendar
+/// year filer's Q3 both line up on CY2023Q3, as in the SEC's frames.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use edgar_rs::{Cik, DedupPolicy, EdgarApi, EdgarClient, Config, trailing_twelve_months};
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = Config::new("Your Company Name your.email@example.com");
+/// let edgar_api = EdgarClient::new(config)?;
+/// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
+/// if let Some(fact) = facts.data.get_fact("us-gaap", "NetIncomeLoss") {
+///     for ttm in trailing_twelve_months(&fact.units["USD"], DedupPolicy::LatestFiled) {
+///         println!("{:?} ({}): {}", ttm.frame, ttm.period, ttm.value);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn trailing_twelve_months<'a, V, I>(values: I, policy: DedupPolicy) -> Vec<TtmValue>
+where
+    V: ReportedValue + 'a,
+    I: IntoIterator<Item = &'a V>,
+{
+    quarterly_values(values, policy)
+        .windows(4)
+        .filter(|quarters| {
+            quarters.windows(2).all(|pair| {
+                add_days(&pair[0].period.end, 1).as_ref() == pair[1].period.start.as_ref()
+            })
+        })
+        .map(|quarters| {
+            let last = &quarters[3];
+            let filed = quarters
+                .iter()
+                .map(|quarter| &quarter.filed)
+                .max_by_key(|filed| parse_ymd(filed))
+                .cloned()
+                .unwrap_or_default();
+            TtmValue {
+                period: ReportingPeriod::new(
+                    quarters[0].period.start.clone(),
+                    last.period.end.clone(),
+                ),
+                value: quarters.iter().map(|quarter| quarter.value).sum(),
+                frame: last.period.calendar_frame(),
+                quarters: quarters.to_vec(),
+                filed,
+            }
+        })
+        .collect()
+}
+
+/// Adds the period-to-date values that follow from a reported quarter starting
+/// the day after a known period-to-date value ends.
+fn extend_with_reported_quarters(
+    ends: &mut BTreeMap<String, ToDate>,
+    reported: &HashMap<String, QuarterlyValue>,
+) {
+    let mut cursor = match ends.keys().next() {
+        Some(end) => end.clone(),
+        None => return,
+    };
+    loop {
+        let quarter = add_days(&cursor, 1).and_then(|next| reported.get(&next));
+        if let Some(quarter) = quarter {
+            if !ends.contains_key(&quarter.period.end) {
+                let prior = &ends[&cursor];
+                let filed =
+                    std::cmp::max_by_key(&prior.filed, &quarter.filed, |filed| parse_ymd(filed));
+                let extended = ToDate {
+                    value: prior.value + quarter.value,
+                    accession_number: quarter.accession_number,
+                    filed: filed.clone(),
+                };
+                ends.insert(quarter.period.end.clone(), extended);
+            }
+        }
+
+        cursor = match ends
+            .range::<String, _>((Bound::Excluded(&cursor), Bound::Unbounded))
+            .next()
+        {
+            Some((end, _)) => end.clone(),
+            None => return,
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_support::{concept_value, ACCN};
+
+    fn summary(quarters: &[QuarterlyValue]) -> Vec<(String, f64, bool)> {
+        quarters
+            .iter()
+            .map(|q| (q.period.to_string(), q.value, q.is_derived()))
+            .collect()
+    }
+
+    #[test]
+    fn test_quarterly_values_from_year_to_date() {
+        let values = vec![
+            concept_value("2023-01-01", "2023-03-31", 10.0, ACCN, "2023-05-01"),
+            concept_value("2023-01-01", "2023-06-30", 25.0, ACCN, "2023-08-01"),
+            concept_value("2023-04-01", "2023-06-30", 15.0, ACCN, "2023-08-01"),
+            concept_value("2023-01-01", "2023-09-30", 45.0, ACCN, "2023-11-01"),
+            concept_value("2023-01-01", "2023-12-31", 70.0, ACCN, "2024-02-01"),
+        ];
+
+        let quarters = quarterly_values(&values, DedupPolicy::LatestFiled);
+        assert_eq!(
+            summary(&quarters),
+            vec![
+                ("2023-01-01 to 2023-03-31".to_string(), 10.0, false),
+                ("2023-04-01 to 2023-06-30".to_string(), 15.0, false),
+                ("2023-07-01 to 2023-09-30".to_string(), 20.0, true),
+                ("2023-10-01 to 2023-12-31".to_string(), 25.0, true),
+            ]
+        );
+
+        let q4 = &quarters[3];
+        assert_eq!(q4.filed, "2024-02-01");
+        assert_eq!(
+            q4.source,
+            QuarterSource::Derived {
+                cumulative: ReportingPeriod::new(Some("2023-01-01".to_string()), "2023-12-31"),
+                prior: ReportingPeriod::new(Some("2023-01-01".to_string()), "2023-09-30"),
+            }
+        );
+    }
+
+    #[test]
+    fn test_quarterly_values_extends_with_reported_quarters() {
+        // No six-month value: Q3 needs Q1 + Q2 as the prior period to date
+        let values = vec![
+            concept_value("2023-01-01", "2023-03-31", 10.0, ACCN, "2023-05-01"),
+            concept_value("2023-04-01", "2023-06-30", 15.0, ACCN, "2023-08-01"),
+            concept_value("2023-01-01", "2023-09-30", 45.0, ACCN, "2023-11-01"),
+            concept_value("2023-01-01", "2023-12-31", 70.0, ACCN, "2024-02-01"),
+        ];
+
+        let values: Vec<f64> = quarterly_values(&values, DedupPolicy::LatestFiled)
+            .iter()
+            .map(|q| q.value)
+            .collect();
+        assert_eq!(values, vec![10.0, 15.0, 20.0, 25.0]);
+    }
+
+    #[test]
+    fn test_trailing_twelve_months() {
+        // A September fiscal year: Q1 is October to December
+        let values = vec![
+            concept_value("2022-10-01", "2022-12-31", 10.0, ACCN, "2023-02-01"),
+            concept_value("2022-10-01", "2023-03-31", 30.0, ACCN, "2023-05-01"),
+            concept_value("2022-10-01", "2023-06-30", 60.0, ACCN, "2023-08-01"),
+            concept_value("2022-10-01", "2023-09-30", 100.0, ACCN, "2023-11-01"),
+            concept_value("2023-10-01", "2023-12-31", 15.0, ACCN, "2024-02-01"),
+        ];
+
+        let ttm = trailing_twelve_months(&values, DedupPolicy::LatestFiled);
+        assert_eq!(ttm.len(), 2);
+        assert_eq!(ttm[0].value, 100.0);
+        assert_eq!(ttm[0].frame, Some(Period::Quarterly(2023, 3)));
+        assert!(ttm[0].is_derived());
+        assert_eq!(ttm[1].period.to_string(), "2023-01-01 to 2023-12-31");
+        assert_eq!(ttm[1].value, 105.0);
+        assert_eq!(ttm[1].frame, Some(Period::Quarterly(2023, 4)));
+        assert_eq!(ttm[1].filed, "2024-02-01");
+    }
+
+    #[test]
+    fn test_quarterly_values_needs_adjacent_periods() {
+        // Six months and a full year leave a six-month gap, not a quarter
+        let values = vec![
+            concept_value("2023-01-01", "2023-06-30", 25.0, ACCN, "2023-08-01"),
+            concept_value("2023-01-01", "2023-12-31", 70.0, ACCN, "2024-02-01"),
+        ];
+        assert!(quarterly_values(&values, DedupPolicy::LatestFiled).is_empty());
+    }
+}
@@ -0,0 +1,291 @@
+//! De-duplication of re-reported values and restatement tracking.
+
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt;
+
+use super::ReportedValue;
+use crate::utils::date::{days_between, parse_ymd};
+
+/// Durations, in days from start to end date, counted as a fiscal year. Covers
+/// 52-53 week years and the odd transition period.
+const ANNUAL_DAYS: std::ops::RangeInclusive<i64> = 350..=380;
+
+/// Durations, in days from start to end date, counted as a fiscal quarter,
+/// including 13 and 14 week quarters.
+const QUARTER_DAYS: std::ops::RangeInclusive<i64> = 80..=100;
+
+/// Which of several reports of the same period to keep when de-duplicating.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum DedupPolicy {
+    /// The value from the most recently filed report, including any restatement.
+    #[default]
+    LatestFiled,
+    /// The value as first reported, ignoring later restatements.
+    OriginallyReported,
+}
+
+/// The period a value covers: a duration with a start and end date, or an
+/// instant with only an end date.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ReportingPeriod {
+    /// The start date (YYYY-MM-DD), or `None` for instant values.
+    pub start: Option<String>,
+
+    /// The end date (YYYY-MM-DD).
+    pub end: String,
+}
+
+impl ReportingPeriod {
+    /// Creates a period from its start and end dates.
+    pub fn new(start: Option<String>, end: impl Into<String>) -> Self {
+        Self {
+            start,
+            end: end.into(),
+        }
+    }
+
+    /// Returns true for instant values such as balance sheet items.
+    pub fn is_instant(&self) -> bool {
+        self.start.is_none()
+    }
+
+    /// Number of days from the start to the end date, or `None` for instants
+    /// and malformed dates.
+    pub fn duration_days(&self) -> Option<i64> {
+        days_between(self.start.as_deref()?, &self.end)
+    }
+
+    /// Returns true if the period is about a year long.
+    pub fn is_annual(&self) -> bool {
+        self.duration_days()
+            .is_some_and(|days| ANNUAL_DAYS.contains(&days))
+    }
+
+    /// Returns true if the period is about three months long.
+    pub fn is_quarterly(&self) -> bool {
+        self.duration_days()
+            .is_some_and(|days| QUARTER_DAYS.contains(&days))
+    }
+}
+
+/// Periods sort by end date and then by start date, with an instant before the
+/// durations ending on the same date and longer durations before shorter ones.
+impl Ord for ReportingPeriod {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (&self.end, &self.start).cmp(&(&other.end, &other.start))
+    }
+}
+
+impl PartialOrd for ReportingPeriod {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl fmt::Display for ReportingPeriod {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.start {
+            Some(start) => write!(f, "{} to {}", start, self.end),
+            None => write!(f, "{}", self.end),
+        }
+    }
+}
+
+/// Every report of a single period, in the order the reports were filed.
+///
+/// Built by [`restatement_histories`]; never empty.
+#[derive(Debug, Clone)]
+pub struct RestatementHistory<'a, V> {
+    period: ReportingPeriod,
+    values: Vec<&'a V>,
+}
+
+impl<'a, V: ReportedValue> RestatementHistory<'a, V> {
+    /// The period the values cover.
+    pub fn period(&self) -> &ReportingPeriod {
+        &self.period
+    }
+
+    /// Every report of the period, oldest filing first.
+    pub fn values(&self) -> &[&'a V] {
+        &self.values
+    }
+
+    /// The value as first reported.
+    pub fn original(&self) -> &'a V {
+        self.values[0]
+    }
+
+    /// The value from the most recently filed report.
+    pub fn latest(&self) -> &'a V {
+        self.values[self.values.len() - 1]
+    }
+
+    /// The value selected by `policy`.
+    pub fn select(&self, policy: DedupPolicy) -> &'a V {
+        match policy {
+            DedupPolicy::LatestFiled => self.latest(),
+            DedupPolicy::OriginallyReported => self.original(),
+        }
+    }
+
+    /// The original report followed by each report that changed the value,
+    /// skipping re-reports of an unchanged value.
+    pub fn revisions(&self) -> Vec<&'a V> {
+        let mut revisions: Vec<&'a V> = Vec::new();
+        for value in &self.values {
+            let unchanged = revisions
+                .last()
+                .is_some_and(|last| last.numeric_value() == value.numeric_value());
+            if !unchanged {
+                revisions.push(value);
+            }
+        }
+        revisions
+    }
+
+    /// Returns true if a later report changed the originally reported value.
+    pub fn is_restated(&self) -> bool {
+        self.revisions().len() > 1
+    }
+}
+
+/// Groups values by reporting period.
+///
+/// Histories are sorted by period end date; the values in each are sorted by
+/// filing date and then accession number.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config, restatement_histories};
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let config = Config::new("Your Company Name your.email@example.com");
+/// let edgar_api = EdgarClient::new(config)?;
+/// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
+/// if let Some(fact) = facts.data.get_fact("us-gaap", "Revenues") {
+///     for history in restatement_histories(&fact.units["USD"]) {
+///         if history.is_restated() {
+///             println!("{} was restated {} times", history.period(), history.revisions().len() - 1);
+///         }
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub fn restatement_histories<'a, V, I>(values: I) -> Vec<RestatementHistory<'a, V>>
+where
+    V: ReportedValue + 'a,
+    I: IntoIterator<Item = &'a V>,
+{
+    let mut periods: BTreeMap<ReportingPeriod, Vec<&'a V>> = BTreeMap::new();
+    for value in values {
+        periods.entry(value.period()).or_default().push(value);
+    }
+
+    periods
+        .into_iter()
+        .map(|(period, mut values)| {
+            values.sort_by_key(|value| (parse_ymd(value.filed()), value.accession_number()));
+            RestatementHistory { period, values }
+        })
+        .collect()
+}
+
+/// Collapses re-reported values into one value per reporting period, chosen by
+/// `policy` and sorted by period end date.
+pub fn deduplicate_values<'a, V, I>(values: I, policy: DedupPolicy) -> Vec<&'a V>
+where
+    V: ReportedValue + 'a,
+    I: IntoIterator<Item = &'a V>,
+{
+    restatement_histories(values)
+        .iter()
+        .map(|history| history.select(policy))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_support::concept_value;
+    use crate::models::company_concept::ConceptValue;
+
+    fn values() -> Vec<ConceptValue> {
+        vec![
+            // Reported again, restated, in the following two 10-Ks
+            concept_value(
+                "2022-01-01",
+                "2022-12-31",
+                100.0,
+                "0000000001-24-000001",
+                "2024-02-01",
+            ),
+            concept_value(
+                "2022-01-01",
+                "2022-12-31",
+                100.0,
+                "0000000001-23-000001",
+                "2023-02-01",
+            ),
+            concept_value(
+                "2022-01-01",
+                "2022-12-31",
+                95.0,
+                "0000000001-25-000001",
+                "2025-02-01",
+            ),
+            concept_value(
+                "2023-01-01",
+                "2023-12-31",
+                120.0,
+                "0000000001-24-000001",
+                "2024-02-01",
+            ),
+            concept_value("", "2023-12-31", 40.0, "0000000001-24-000001", "2024-02-01"),
+        ]
+    }
+
+    #[test]
+    fn test_restatement_histories() {
+        let values = values();
+        let histories = restatement_histories(&values);
+        assert_eq!(histories.len(), 3);
+
+        let fy2022 = &histories[0];
+        assert_eq!(fy2022.period().to_string(), "2022-01-01 to 2022-12-31");
+        assert_eq!(fy2022.values().len(), 3);
+        assert_eq!(fy2022.original().filed, "2023-02-01");
+        assert_eq!(fy2022.latest().val, 95.0);
+        assert!(fy2022.is_restated());
+        let revisions: Vec<f64> = fy2022.revisions().iter().map(|v| v.val).collect();
+        assert_eq!(revisions, vec![100.0, 95.0]);
+
+        assert!(fy2022.period().is_annual());
+        assert!(!fy2022.period().is_quarterly());
+
+        // The instant sorts before the duration ending on the same date
+        assert!(histories[1].period().is_instant());
+        assert_eq!(histories[1].period().duration_days(), None);
+        assert!(!histories[1].is_restated());
+        assert_eq!(histories[2].latest().val, 120.0);
+    }
+
+    #[test]
+    fn test_deduplicate_values() {
+        let values = values();
+
+        let latest: Vec<f64> = deduplicate_values(&values, DedupPolicy::LatestFiled)
+            .iter()
+            .map(|v| v.val)
+            .collect();
+        assert_eq!(latest, vec![95.0, 40.0, 120.0]);
+
+        let original: Vec<f64> = deduplicate_values(&values, DedupPolicy::OriginallyReported)
+            .iter()
+            .map(|v| v.val)
+            .collect();
+        assert_eq!(original, vec![100.0, 40.0, 120.0]);
+    }
+}
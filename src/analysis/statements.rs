@@ -0,0 +1,1001 @@
+//! Standardized financial statements built from company facts.
+//!
+//! Filers tag the same line item differently: revenue may be `Revenues`,
+//! `SalesRevenueNet` or `RevenueFromContractWithCustomerExcludingAssessedTax`
+//! in us-gaap, or `Revenue` in ifrs-full, and companies switch tags over time.
+//! Each [`LineItem`] has a chain of concepts to try; the first concept in the
+//! chain that reports a value for a period wins for that period.
+
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+
+use super::{
+    deduplicate_values, quarterly_values, DedupPolicy, FiscalCalendar, ReportedValue,
+    ReportingPeriod,
+};
+use crate::identifiers::AccessionNumber;
+use crate::models::company_facts::{CompanyFacts, Fact};
+use crate::types::Taxonomy;
+use crate::utils::date::days_between;
+
+/// How far, in days, a balance sheet date may be from the nominal end of a
+/// fiscal quarter and still count as that quarter's. Covers 52-53 week years.
+const QUARTER_END_TOLERANCE_DAYS: i64 = 7;
+
+/// The three primary financial statements.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum StatementKind {
+    /// Income statement: revenue, expenses and earnings over a period.
+    IncomeStatement,
+    /// Balance sheet: assets, liabilities and equity at a point in time.
+    BalanceSheet,
+    /// Cash flow statement: cash generated and spent over a period.
+    CashFlow,
+}
+
+impl StatementKind {
+    /// Returns true if the statement reports instants rather than durations.
+    pub fn is_instant(&self) -> bool {
+        matches!(self, StatementKind::BalanceSheet)
+    }
+}
+
+/// How a line item is measured, which decides the unit it is read in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum UnitKind {
+    /// An amount in the reporting currency, e.g. "USD".
+    Monetary,
+    /// An amount per share in the reporting currency, e.g. "USD/shares".
+    PerShare,
+    /// A number of shares.
+    Shares,
+}
+
+/// A canonical line item of a standardized statement.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum LineItem {
+    /// Total revenue.
+    Revenue,
+    /// Cost of goods and services sold.
+    CostOfRevenue,
+    /// Revenue less cost of revenue.
+    GrossProfit,
+    /// Research and development expense.
+    ResearchAndDevelopment,
+    /// Selling, general and administrative expense.
+    SellingGeneralAndAdministrative,
+    /// Total operating expenses.
+    OperatingExpenses,
+    /// Operating income or loss.
+    OperatingIncome,
+    /// Interest expense.
+    InterestExpense,
+    /// Income or loss before income taxes.
+    PretaxIncome,
+    /// Income tax expense or benefit.
+    IncomeTaxExpense,
+    /// Net income or loss attributable to the parent.
+    ///
+    /// `ProfitLoss`, which includes noncontrolling interests, isn't in the default
+    /// chain; add it with [`StatementBuilder::with_concepts`] to accept it.
+    NetIncome,
+    /// Basic earnings per share.
+    EpsBasic,
+    /// Diluted earnings per share.
+    EpsDiluted,
+    /// Weighted average diluted shares outstanding.
+    DilutedShares,
+    /// Cash and cash equivalents.
+    CashAndEquivalents,
+    /// Accounts receivable, current.
+    AccountsReceivable,
+    /// Inventories.
+    Inventory,
+    /// Total current assets.
+    CurrentAssets,
+    /// Total assets.
+    TotalAssets,
+    /// Accounts payable, current.
+    AccountsPayable,
+    /// Total current liabilities.
+    CurrentLiabilities,
+    /// Long-term debt, non-current.
+    LongTermDebt,
+    /// Total liabilities.
+    TotalLiabilities,
+    /// Stockholders' equity attributable to the parent.
+    StockholdersEquity,
+    /// Net cash from operating activities.
+    OperatingCashFlow,
+    /// Payments for property, plant and equipment.
+    CapitalExpenditures,
+    /// Depreciation, depletion and amortization.
+    DepreciationAndAmortization,
+    /// Net cash from investing activities.
+    InvestingCashFlow,
+    /// Net cash from financing activities.
+    FinancingCashFlow,
+    /// Dividends paid.
+    DividendsPaid,
+    /// Payments for repurchases of common stock.
+    ShareRepurchases,
+}
+
+impl LineItem {
+    /// Every line item, in statement order.
+    pub const ALL: [LineItem; 31] = [
+        LineItem::Revenue,
+        LineItem::CostOfRevenue,
+        LineItem::GrossProfit,
+        LineItem::ResearchAndDevelopment,
+        LineItem::SellingGeneralAndAdministrative,
+        LineItem::OperatingExpenses,
+        LineItem::OperatingIncome,
+        LineItem::InterestExpense,
+        LineItem::PretaxIncome,
+        LineItem::IncomeTaxExpense,
+        LineItem::NetIncome,
+        LineItem::EpsBasic,
+        LineItem::EpsDiluted,
+        LineItem::DilutedShares,
+        LineItem::CashAndEquivalents,
+        LineItem::AccountsReceivable,
+        LineItem::Inventory,
+        LineItem::CurrentAssets,
+        LineItem::TotalAssets,
+        LineItem::AccountsPayable,
+        LineItem::CurrentLiabilities,
+        LineItem::LongTermDebt,
+        LineItem::TotalLiabilities,
+        LineItem::StockholdersEquity,
+        LineItem::OperatingCashFlow,
+        LineItem::CapitalExpenditures,
+        LineItem::DepreciationAndAmortization,
+        LineItem::InvestingCashFlow,
+        LineItem::FinancingCashFlow,
+        LineItem::DividendsPaid,
+        LineItem::ShareRepurchases,
+    ];
+
+    /// The statement the line item belongs to.
+    pub fn statement(&self) -> StatementKind {
+        match self {
+            LineItem::Revenue
+            | LineItem::CostOfRevenue
+            | LineItem::GrossProfit
+            | LineItem::ResearchAndDevelopment
+            | LineItem::SellingGeneralAndAdministrative
+            | LineItem::OperatingExpenses
+            | LineItem::OperatingIncome
+            | LineItem::InterestExpense
+            | LineItem::PretaxIncome
+            | LineItem::IncomeTaxExpense
+            | LineItem::NetIncome
+            | LineItem::EpsBasic
+            | LineItem::EpsDiluted
+            | LineItem::DilutedShares => StatementKind::IncomeStatement,
+            LineItem::CashAndEquivalents
+            | LineItem::AccountsReceivable
+            | LineItem::Inventory
+            | LineItem::CurrentAssets
+            | LineItem::TotalAssets
+            | LineItem::AccountsPayable
+            | LineItem::CurrentLiabilities
+            | LineItem::LongTermDebt
+            | LineItem::TotalLiabilities
+            | LineItem::StockholdersEquity => StatementKind::BalanceSheet,
+            LineItem::OperatingCashFlow
+            | LineItem::CapitalExpenditures
+            | LineItem::DepreciationAndAmortization
+            | LineItem::InvestingCashFlow
+            | LineItem::FinancingCashFlow
+            | LineItem::DividendsPaid
+            | LineItem::ShareRepurchases => StatementKind::CashFlow,
+        }
+    }
+
+    /// How the line item is measured.
+    pub fn unit_kind(&self) -> UnitKind {
+        match self {
+            LineItem::EpsBasic | LineItem::EpsDiluted => UnitKind::PerShare,
+            LineItem::DilutedShares => UnitKind::Shares,
+            _ => UnitKind::Monetary,
+        }
+    }
+
+    /// The default concepts tried for the line item, us-gaap first.
+    pub fn default_concepts(&self) -> Vec<Concept> {
+        let (us_gaap, ifrs): (&[&str], &[&str]) = match self {
+            LineItem::Revenue => (
+                &[
+                    "Revenues",
+                    "RevenueFromContractWithCustomerExcludingAssessedTax",
+                    "RevenueFromContractWithCustomerIncludingAssessedTax",
+                    "SalesRevenueNet",
+                    "SalesRevenueGoodsNet",
+                ],
+                &["Revenue", "RevenueFromContractsWithCustomers"],
+            ),
+            LineItem::CostOfRevenue => (
+                &[
+                    "CostOfRevenue",
+                    "CostOfGoodsAndServicesSold",
+                    "CostOfGoodsSold",
+                    "CostOfServices",
+                ],
+                &["CostOfSales"],
+            ),
+            LineItem::GrossProfit => (&["GrossProfit"], &["GrossProfit"]),
+            LineItem::ResearchAndDevelopment => (
+                &["ResearchAndDevelopmentExpense"],
+                &["ResearchAndDevelopmentExpense"],
+            ),
+            LineItem::SellingGeneralAndAdministrative => (
+                &["SellingGeneralAndAdministrativeExpense"],
+                &["SellingGeneralAndAdministrativeExpense"],
+            ),
+            LineItem::OperatingExpenses => (&["OperatingExpenses"], &[]),
+            LineItem::OperatingIncome => (
+                &["OperatingIncomeLoss"],
+                &["ProfitLossFromOperatingActivities"],
+            ),
+            LineItem::InterestExpense => (
+                &["InterestExpense", "InterestExpenseNonoperating"],
+                &["FinanceCosts"],
+            ),
+            LineItem::PretaxIncome => (
+                &[
+                    "IncomeLossFromContinuingOperationsBeforeIncomeTaxesExtraordinaryItemsNoncontrollingInterest",
+                    "IncomeLossFromContinuingOperationsBeforeIncomeTaxesMinorityInterestAndIncomeLossFromEquityMethodInvestments",
+                ],
+                &["ProfitLossBeforeTax"],
+            ),
+            LineItem::IncomeTaxExpense => (
+                &["IncomeTaxExpenseBenefit"],
+                &["IncomeTaxExpenseContinuingOperations"],
+            ),
+            LineItem::NetIncome => (
+                &["NetIncomeLoss"],
+                &["ProfitLossAttributableToOwnersOfParent"],
+            ),
+            LineItem::EpsBasic => (&["EarningsPerShareBasic"], &["BasicEarningsLossPerShare"]),
+            LineItem::EpsDiluted => (
+                &["EarningsPerShareDiluted"],
+                &["DilutedEarningsLossPerShare"],
+            ),
+            LineItem::DilutedShares => (
+                &["WeightedAverageNumberOfDilutedSharesOutstanding"],
+                &["AdjustedWeightedAverageShares"],
+            ),
+            LineItem::CashAndEquivalents => (
+                &["CashAndCashEquivalentsAtCarryingValue", "Cash"],
+                &["CashAndCashEquivalents"],
+            ),
+            LineItem::AccountsReceivable => (
+                &["AccountsReceivableNetCurrent"],
+                &["TradeAndOtherCurrentReceivables"],
+            ),
+            LineItem::Inventory => (&["InventoryNet"], &["Inventories"]),
+            LineItem::CurrentAssets => (&["AssetsCurrent"], &["CurrentAssets"]),
+            LineItem::TotalAssets => (&["Assets"], &["Assets"]),
+            LineItem::AccountsPayable => (
+                &["AccountsPayableCurrent"],
+                &["TradeAndOtherCurrentPayables"],
+            ),
+            LineItem::CurrentLiabilities => (&["LiabilitiesCurrent"], &["CurrentLiabilities"]),
+            LineItem::LongTermDebt => (
+                &["LongTermDebtNoncurrent", "LongTermDebt"],
+                &["LongtermBorrowings"],
+            ),
+            LineItem::TotalLiabilities => (&["Liabilities"], &["Liabilities"]),
+            LineItem::StockholdersEquity => (
+                &[
+                    "StockholdersEquity",
+                    "StockholdersEquityIncludingPortionAttributableToNoncontrollingInterest",
+                ],
+                &["EquityAttributableToOwnersOfParent", "Equity"],
+            ),
+            LineItem::OperatingCashFlow => (
+                &[
+                    "NetCashProvidedByUsedInOperatingActivities",
+                    "NetCashProvidedByUsedInOperatingActivitiesContinuingOperations",
+                ],
+                &["CashFlowsFromUsedInOperatingActivities"],
+            ),
+            LineItem::CapitalExpenditures => (
+                &["PaymentsToAcquirePropertyPlantAndEquipment"],
+                &["PurchaseOfPropertyPlantAndEquipmentClassifiedAsInvestingActivities"],
+            ),
+            LineItem::DepreciationAndAmortization => (
+                &[
+                    "DepreciationDepletionAndAmortization",
+                    "DepreciationAndAmortization",
+                    "DepreciationAmortizationAndAccretionNet",
+                ],
+                &["DepreciationAndAmortisationExpense"],
+            ),
+            LineItem::InvestingCashFlow => (
+                &[
+                    "NetCashProvidedByUsedInInvestingActivities",
+                    "NetCashProvidedByUsedInInvestingActivitiesContinuingOperations",
+                ],
+                &["CashFlowsFromUsedInInvestingActivities"],
+            ),
+            LineItem::FinancingCashFlow => (
+                &[
+                    "NetCashProvidedByUsedInFinancingActivities",
+                    "NetCashProvidedByUsedInFinancingActivitiesContinuingOperations",
+                ],
+                &["CashFlowsFromUsedInFinancingActivities"],
+            ),
+            LineItem::DividendsPaid => (
+                &["PaymentsOfDividends", "PaymentsOfDividendsCommonStock"],
+                &["DividendsPaidClassifiedAsFinancingActivities"],
+            ),
+            LineItem::ShareRepurchases => (
+                &["PaymentsForRepurchaseOfCommonStock"],
+                &["PaymentsToAcquireOrRedeemEntitysShares"],
+            ),
+        };
+
+        us_gaap
+            .iter()
+            .map(|tag| Concept::us_gaap(*tag))
+            .chain(ifrs.iter().map(|tag| Concept::ifrs(*tag)))
+            .collect()
+    }
+}
+
+impl fmt::Display for LineItem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// An XBRL concept: a tag within a taxonomy.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Concept {
+    /// The taxonomy of the tag.
+    pub taxonomy: Taxonomy,
+
+    /// The tag, e.g. "Revenues".
+    pub tag: String,
+}
+
+impl Concept {
+    /// Creates a concept.
+    pub fn new(taxonomy: Taxonomy, tag: impl Into<String>) -> Self {
+        Self {
+            taxonomy,
+            tag: tag.into(),
+        }
+    }
+
+    /// Creates a us-gaap concept.
+    pub fn us_gaap(tag: impl Into<String>) -> Self {
+        Self::new(Taxonomy::UsGaap, tag)
+    }
+
+    /// Creates an ifrs-full concept.
+    pub fn ifrs(tag: impl Into<String>) -> Self {
+        Self::new(Taxonomy::IfrsFull, tag)
+    }
+}
+
+impl fmt::Display for Concept {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}", self.taxonomy.as_str(), self.tag)
+    }
+}
+
+/// A line item value and the report it was taken from.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatementValue {
+    /// The value, in the statement's currency for monetary items.
+    pub value: f64,
+
+    /// The concept the value was reported under.
+    pub concept: Concept,
+
+    /// The unit the value was reported in, e.g. "USD" or "USD/shares".
+    pub unit: String,
+
+    /// The accession number of the filing the value was taken from.
+    pub accession_number: AccessionNumber,
+
+    /// The date that filing was made (YYYY-MM-DD).
+    pub filed: String,
+
+    /// True for a quarter computed from year-to-date values rather than
+    /// reported, see [`quarterly_values`](crate::quarterly_values).
+    pub derived: bool,
+}
+
+/// The line items of a statement for one reporting period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatementPeriod {
+    /// The period the values cover.
+    pub period: ReportingPeriod,
+
+    /// The values found for the period.
+    pub items: BTreeMap<LineItem, StatementValue>,
+}
+
+impl StatementPeriod {
+    /// Returns the value of a line item, if it was reported for the period.
+    pub fn get(&self, item: LineItem) -> Option<f64> {
+        self.items.get(&item).map(|value| value.value)
+    }
+}
+
+/// A standardized statement: one column per reporting period.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Statement {
+    /// The kind of statement.
+    pub kind: StatementKind,
+
+    /// The periods, sorted by end date and then start date.
+    pub periods: Vec<StatementPeriod>,
+
+    /// The fiscal calendar used to find the balance sheets at a fiscal year or
+    /// quarter end.
+    pub fiscal_calendar: FiscalCalendar,
+}
+
+impl Statement {
+    /// Returns the column for `period`, if any line item was reported for it.
+    pub fn get_period(&self, period: &ReportingPeriod) -> Option<&StatementPeriod> {
+        self.periods.iter().find(|p| &p.period == period)
+    }
+
+    /// Returns the columns covering a full fiscal year or, for balance sheets,
+    /// the columns at a fiscal year end.
+    pub fn annual(&self) -> Vec<&StatementPeriod> {
+        self.periods
+            .iter()
+            .filter(|p| p.period.is_annual() || self.quarter_ending_at(&p.period) == Some(4))
+            .collect()
+    }
+
+    /// Returns the columns covering a single quarter or, for balance sheets,
+    /// the columns at a fiscal quarter end, fiscal year ends included.
+    pub fn quarterly(&self) -> Vec<&StatementPeriod> {
+        self.periods
+            .iter()
+            .filter(|p| p.period.is_quarterly() || self.quarter_ending_at(&p.period).is_some())
+            .collect()
+    }
+
+    /// Returns the fiscal quarter (1-4) an instant is the last day of, allowing
+    /// for 52-53 week years, or `None` for durations and other instants.
+    fn quarter_ending_at(&self, period: &ReportingPeriod) -> Option<u8> {
+        if !period.is_instant() {
+            return None;
+        }
+        let calendar = &self.fiscal_calendar;
+        let (fiscal_year, quarter) = calendar.fiscal_quarter_of_period(period)?;
+        let end = calendar.quarter_end(fiscal_year, quarter)?;
+        (days_between(&end, &period.end)?.abs() <= QUARTER_END_TOLERANCE_DAYS).then_some(quarter)
+    }
+
+    /// Returns the (period, value) pairs of a line item, oldest first.
+    pub fn series(&self, item: LineItem) -> Vec<(&ReportingPeriod, f64)> {
+        self.periods
+            .iter()
+            .filter_map(|p| Some((&p.period, p.get(item)?)))
+            .collect()
+    }
+}
+
+/// Builds standardized statements from company facts.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use edgar_rs::{Cik, Concept, Config, EdgarApi, EdgarClient, LineItem, StatementBuilder};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = EdgarClient::new(Config::new("Your Company Name your.email@example.com"))?;
+///     let facts = client.get_company_facts(Cik::new(320193)).await?;
+///
+///     let income = StatementBuilder::new()
+///         .with_concepts(
+///             LineItem::Revenue,
+///             vec![Concept::us_gaap("RevenueFromContractWithCustomerExcludingAssessedTax")],
+///         )
+///         .income_statement(&facts.data);
+///     for column in income.annual() {
+///         println!("{}: revenue {:?}", column.period, column.get(LineItem::Revenue));
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct StatementBuilder {
+    concepts: HashMap<LineItem, Vec<Concept>>,
+    currency: Option<String>,
+    fiscal_calendar: Option<FiscalCalendar>,
+    policy: DedupPolicy,
+}
+
+impl Default for StatementBuilder {
+    fn default() -> Self {
+        Self {
+            concepts: LineItem::ALL
+                .iter()
+                .map(|item| (*item, item.default_concepts()))
+                .collect(),
+            currency: None,
+            fiscal_calendar: None,
+            policy: DedupPolicy::LatestFiled,
+        }
+    }
+}
+
+impl StatementBuilder {
+    /// Creates a builder with the default concept chains, the latest filed
+    /// values, and the currency and fiscal calendar detected from the facts.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replaces the concepts tried for `item`, in order.
+    pub fn with_concepts(mut self, item: LineItem, concepts: Vec<Concept>) -> Self {
+        self.concepts.insert(item, concepts);
+        self
+    }
+
+    /// Tries `concept` before the other concepts for `item`.
+    pub fn with_preferred_concept(mut self, item: LineItem, concept: Concept) -> Self {
+        self.concepts.entry(item).or_default().insert(0, concept);
+        self
+    }
+
+    /// Reads monetary values in `currency`, e.g. "EUR", instead of detecting it.
+    pub fn with_currency(mut self, currency: impl Into<String>) -> Self {
+        self.currency = Some(currency.into());
+        self
+    }
+
+    /// Uses `calendar` to find fiscal year and quarter ends instead of
+    /// [`CompanyFacts::fiscal_calendar`].
+    pub fn with_fiscal_calendar(mut self, calendar: FiscalCalendar) -> Self {
+        self.fiscal_calendar = Some(calendar);
+        self
+    }
+
+    /// Sets which report of a period to use when it was reported more than once.
+    pub fn with_policy(mut self, policy: DedupPolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// Returns the concepts tried for `item`, in order.
+    pub fn concepts(&self, item: LineItem) -> &[Concept] {
+        self.concepts.get(&item).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns which report of a period is used.
+    pub fn policy(&self) -> DedupPolicy {
+        self.policy
+    }
+
+    /// Builds the income statement.
+    pub fn income_statement(&self, facts: &CompanyFacts) -> Statement {
+        self.build(StatementKind::IncomeStatement, facts)
+    }
+
+    /// Builds the balance sheet.
+    pub fn balance_sheet(&self, facts: &CompanyFacts) -> Statement {
+        self.build(StatementKind::BalanceSheet, facts)
+    }
+
+    /// Builds the cash flow statement.
+    pub fn cash_flow_statement(&self, facts: &CompanyFacts) -> Statement {
+        self.build(StatementKind::CashFlow, facts)
+    }
+
+    /// Builds a statement of the given kind.
+    ///
+    /// For each line item and period, the value comes from the first concept in
+    /// the item's chain that reports the period. Income and cash flow statements
+    /// only use durations and balance sheets only instants. Quarters that are
+    /// only reported year to date, as cash flows usually are, are derived with
+    /// [`quarterly_values`](crate::quarterly_values) and marked as such.
+    pub fn build(&self, kind: StatementKind, facts: &CompanyFacts) -> Statement {
+        let currency = self
+            .currency
+            .clone()
+            .or_else(|| detect_currency(facts))
+            .unwrap_or_else(|| "USD".to_string());
+        let fiscal_calendar = self
+            .fiscal_calendar
+            .or_else(|| facts.fiscal_calendar())
+            .unwrap_or_default();
+
+        let mut periods: BTreeMap<ReportingPeriod, BTreeMap<LineItem, StatementValue>> =
+            BTreeMap::new();
+
+        for item in LineItem::ALL.iter().filter(|item| item.statement() == kind) {
+            let unit = match item.unit_kind() {
+                UnitKind::Monetary => currency.clone(),
+                UnitKind::PerShare => format!("{}/shares", currency),
+                UnitKind::Shares => "shares".to_string(),
+            };
+
+            for concept in self.concepts(*item) {
+                let values = match facts
+                    .get_fact(concept.taxonomy.as_str(), &concept.tag)
+                    .and_then(|fact| fact.units.get(&unit))
+                {
+                    Some(values) => values,
+                    None => continue,
+                };
+
+                for value in deduplicate_values(values, self.policy) {
+                    let period = value.period();
+                    if period.is_instant() != kind.is_instant() {
+                        continue;
+                    }
+                    let amount = match value.numeric_value() {
+                        Some(amount) => amount,
+                        None => continue,
+                    };
+                    periods
+                        .entry(period)
+                        .or_default()
+                        .entry(*item)
+                        .or_insert_with(|| StatementValue {
+                            value: amount,
+                            concept: concept.clone(),
+                            unit: unit.clone(),
+                            accession_number: value.accn,
+                            filed: value.filed.clone(),
+                            derived: false,
+                        });
+                }
+
+                if kind.is_instant() {
+                    continue;
+                }
+                for quarter in quarterly_values(values, self.policy) {
+                    if !quarter.is_derived() {
+                        continue;
+                    }
+                    periods
+                        .entry(quarter.period)
+                        .or_default()
+                        .entry(*item)
+                        .or_insert_with(|| StatementValue {
+                            value: quarter.value,
+                            concept: concept.clone(),
+                            unit: unit.clone(),
+                            accession_number: quarter.accession_number,
+                            filed: quarter.filed,
+                            derived: true,
+                        });
+                }
+            }
+        }
+
+        Statement {
+            kind,
+            periods: periods
+                .into_iter()
+                .map(|(period, items)| StatementPeriod { period, items })
+                .collect(),
+            fiscal_calendar,
+        }
+    }
+}
+
+/// Returns the currency most of the default revenue and asset concepts are
+/// reported in.
+fn detect_currency(facts: &CompanyFacts) -> Option<String> {
+    let mut counts: BTreeMap<&str, usize> = BTreeMap::new();
+    for item in [
+        LineItem::Revenue,
+        LineItem::NetIncome,
+        LineItem::TotalAssets,
+    ] {
+        for concept in item.default_concepts() {
+            let fact: Option<&Fact> = facts.get_fact(concept.taxonomy.as_str(), &concept.tag);
+            for (unit, values) in fact.into_iter().flat_map(|fact| &fact.units) {
+                if unit.len() == 3 && unit.bytes().all(|b| b.is_ascii_uppercase()) {
+                    *counts.entry(unit.as_str()).or_default() += values.len();
+                }
+            }
+        }
+    }
+
+    counts
+        .into_iter()
+        .max_by_key(|(_, count)| *count)
+        .map(|(unit, _)| unit.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_support::{company_facts, fact};
+    use serde_json::json;
+
+    fn facts() -> CompanyFacts {
+        company_facts(json!({
+            "SalesRevenueNet": {"label": "Sales", "units": {"USD": [
+                fact(Some("2016-01-01"), "2016-12-31", 80.0, "2017-02-01"),
+                fact(Some("2017-01-01"), "2017-12-31", 90.0, "2018-02-01")
+            ]}},
+            "RevenueFromContractWithCustomerExcludingAssessedTax": {"units": {"USD": [
+                fact(Some("2017-01-01"), "2017-12-31", 91.0, "2019-02-01"),
+                fact(Some("2018-01-01"), "2018-12-31", 100.0, "2019-02-01"),
+                fact(Some("2018-10-01"), "2018-12-31", 30.0, "2019-02-01")
+            ]}},
+            "EarningsPerShareDiluted": {"units": {"USD/shares": [
+                fact(Some("2018-01-01"), "2018-12-31", 1.5, "2019-02-01")
+            ]}},
+            "Assets": {"units": {"USD": [
+                fact(None, "2018-12-31", 500.0, "2019-02-01"),
+                fact(Some("2018-01-01"), "2018-12-31", 1.0, "2019-02-01")
+            ]}}
+        }))
+    }
+
+    #[test]
+    fn test_income_statement_uses_fallback_chain_per_period() {
+        let facts = facts();
+        let income = StatementBuilder::new().income_statement(&facts);
+
+        let revenue: Vec<f64> = income
+            .annual()
+            .iter()
+            .filter_map(|p| p.get(LineItem::Revenue))
+            .collect();
+        // RevenueFromContract... comes first in the chain, so it wins for 2017
+        assert_eq!(revenue, vec![80.0, 91.0, 100.0]);
+
+        let fy2018 = income.annual()[2];
+        assert_eq!(fy2018.get(LineItem::EpsDiluted), Some(1.5));
+        assert_eq!(fy2018.items[&LineItem::EpsDiluted].unit, "USD/shares");
+        assert_eq!(
+            fy2018.items[&LineItem::Revenue].concept,
+            Concept::us_gaap("RevenueFromContractWithCustomerExcludingAssessedTax")
+        );
+        assert_eq!(fy2018.get(LineItem::TotalAssets), None);
+
+        assert_eq!(income.quarterly().len(), 1);
+        assert_eq!(income.series(LineItem::Revenue).len(), 4);
+    }
+
+    #[test]
+    fn test_balance_sheet_only_uses_instants() {
+        let balance_sheet = StatementBuilder::new().balance_sheet(&facts());
+        assert_eq!(balance_sheet.periods.len(), 1);
+        assert!(balance_sheet.periods[0].period.is_instant());
+        assert_eq!(
+            balance_sheet.periods[0].get(LineItem::TotalAssets),
+            Some(500.0)
+        );
+        // The facts' annual periods end December 31, so 2018-12-31 is a fiscal
+        // year end
+        assert_eq!(
+            balance_sheet.fiscal_calendar,
+            FiscalCalendar::calendar_year()
+        );
+        assert_eq!(balance_sheet.annual().len(), 1);
+    }
+
+    #[test]
+    fn test_balance_sheet_selects_fiscal_year_end_instants() {
+        let facts = company_facts(json!({
+            "Revenues": {"units": {"USD": [
+                fact(Some("2022-10-01"), "2023-09-30", 400.0, "2023-11-01")
+            ]}},
+            "Assets": {"units": {"USD": [
+                fact(None, "2023-09-30", 500.0, "2023-11-01"),
+                fact(None, "2023-12-30", 510.0, "2024-02-01"),
+                fact(None, "2024-02-15", 515.0, "2024-03-01"),
+                fact(None, "2024-09-28", 520.0, "2024-11-01")
+            ]}}
+        }));
+
+        // The fiscal year ends September 30, as the annual revenue shows, and
+        // fiscal 2024 is a 52-week year ending two days early
+        let balance_sheet = StatementBuilder::new().balance_sheet(&facts);
+        assert_eq!(
+            balance_sheet.fiscal_calendar,
+            FiscalCalendar::new(9, 30).unwrap()
+        );
+        let year_ends: Vec<f64> = balance_sheet
+            .annual()
+            .iter()
+            .filter_map(|p| p.get(LineItem::TotalAssets))
+            .collect();
+        assert_eq!(year_ends, vec![500.0, 520.0]);
+        let quarter_ends: Vec<&str> = balance_sheet
+            .quarterly()
+            .iter()
+            .map(|p| p.period.end.as_str())
+            .collect();
+        assert_eq!(quarter_ends, vec!["2023-09-30", "2023-12-30", "2024-09-28"]);
+
+        // With a calendar-year calendar only December is a year end
+        let balance_sheet = StatementBuilder::new()
+            .with_fiscal_calendar(FiscalCalendar::calendar_year())
+            .balance_sheet(&facts);
+        assert_eq!(balance_sheet.annual().len(), 1);
+        assert_eq!(balance_sheet.annual()[0].period.end, "2023-12-30");
+    }
+
+    #[test]
+    fn test_fallback_concepts_are_tried_in_order() {
+        let facts: CompanyFacts = serde_json::from_value(json!({
+            "cik": 1,
+            "entityName": "Example Corp",
+            "facts": {
+                "us-gaap": {
+                    "Revenues": {"units": {"USD": [
+                        fact(Some("2023-01-01"), "2023-12-31", 200.0, "2024-02-01")
+                    ]}},
+                    "SalesRevenueNet": {"units": {"USD": [
+                        fact(Some("2022-01-01"), "2022-12-31", 150.0, "2023-02-01"),
+                        fact(Some("2023-01-01"), "2023-12-31", 190.0, "2024-02-01")
+                    ]}}
+                },
+                "ifrs-full": {
+                    "Revenue": {"units": {"USD": [
+                        fact(Some("2021-01-01"), "2021-12-31", 100.0, "2022-02-01"),
+                        fact(Some("2023-01-01"), "2023-12-31", 180.0, "2024-02-01")
+                    ]}}
+                }
+            }
+        }))
+        .unwrap();
+
+        let concepts = |statement: &Statement| -> Vec<(f64, String)> {
+            statement
+                .annual()
+                .iter()
+                .map(|p| {
+                    let value = &p.items[&LineItem::Revenue];
+                    (value.value, value.concept.to_string())
+                })
+                .collect()
+        };
+
+        // us-gaap tags come before ifrs-full ones, each in chain order
+        let income = StatementBuilder::new().income_statement(&facts);
+        assert_eq!(
+            concepts(&income),
+            vec![
+                (100.0, "ifrs-full:Revenue".to_string()),
+                (150.0, "us-gaap:SalesRevenueNet".to_string()),
+                (200.0, "us-gaap:Revenues".to_string()),
+            ]
+        );
+
+        let income = StatementBuilder::new()
+            .with_preferred_concept(LineItem::Revenue, Concept::ifrs("Revenue"))
+            .income_statement(&facts);
+        assert_eq!(income.annual()[2].get(LineItem::Revenue), Some(180.0));
+
+        let income = StatementBuilder::new()
+            .with_concepts(
+                LineItem::Revenue,
+                vec![
+                    Concept::us_gaap("SalesRevenueNet"),
+                    Concept::us_gaap("Revenues"),
+                ],
+            )
+            .income_statement(&facts);
+        assert_eq!(
+            income.series(LineItem::Revenue),
+            vec![
+                (
+                    &ReportingPeriod::new(Some("2022-01-01".to_string()), "2022-12-31"),
+                    150.0
+                ),
+                (
+                    &ReportingPeriod::new(Some("2023-01-01".to_string()), "2023-12-31"),
+                    190.0
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_net_income_excludes_noncontrolling_interests() {
+        let facts: CompanyFacts = serde_json::from_value(json!({
+            "cik": 1,
+            "entityName": "Example Corp",
+            "facts": {
+                "us-gaap": {
+                    "ProfitLoss": {"units": {"USD": [
+                        fact(Some("2023-01-01"), "2023-12-31", 120.0, "2024-02-01")
+                    ]}}
+                }
+            }
+        }))
+        .unwrap();
+
+        let income = StatementBuilder::new().income_statement(&facts);
+        assert!(income.series(LineItem::NetIncome).is_empty());
+
+        let income = StatementBuilder::new()
+            .with_concepts(
+                LineItem::NetIncome,
+                vec![
+                    Concept::us_gaap("NetIncomeLoss"),
+                    Concept::us_gaap("ProfitLoss"),
+                ],
+            )
+            .income_statement(&facts);
+        assert_eq!(income.annual()[0].get(LineItem::NetIncome), Some(120.0));
+    }
+
+    #[test]
+    fn test_quarterly_income_is_reported_and_cash_flow_derived() {
+        let facts = company_facts(json!({
+            "Revenues": {"units": {"USD": [
+                fact(Some("2023-01-01"), "2023-03-31", 10.0, "2023-05-01"),
+                fact(Some("2023-04-01"), "2023-06-30", 20.0, "2023-08-01"),
+                fact(Some("2023-07-01"), "2023-09-30", 30.0, "2023-11-01"),
+                fact(Some("2023-01-01"), "2023-12-31", 100.0, "2024-02-01")
+            ]}},
+            "NetCashProvidedByUsedInOperatingActivities": {"units": {"USD": [
+                fact(Some("2023-01-01"), "2023-03-31", 5.0, "2023-05-01"),
+                fact(Some("2023-01-01"), "2023-06-30", 12.0, "2023-08-01"),
+                fact(Some("2023-01-01"), "2023-09-30", 20.0, "2023-11-01"),
+                fact(Some("2023-01-01"), "2023-12-31", 30.0, "2024-02-01")
+            ]}}
+        }));
+        let quarters = |statement: &Statement, item: LineItem| -> Vec<(f64, bool)> {
+            statement
+                .quarterly()
+                .iter()
+                .map(|p| (p.get(item).unwrap(), p.items[&item].derived))
+                .collect()
+        };
+
+        // Only Q4 has to be derived from the income statement's fiscal year
+        let income = StatementBuilder::new().income_statement(&facts);
+        assert_eq!(
+            quarters(&income, LineItem::Revenue),
+            vec![(10.0, false), (20.0, false), (30.0, false), (40.0, true)]
+        );
+
+        // Cash flows are reported year to date, so Q2 to Q4 are derived, while
+        // the six and nine month columns are kept as reported
+        let cash_flow = StatementBuilder::new().cash_flow_statement(&facts);
+        assert_eq!(
+            quarters(&cash_flow, LineItem::OperatingCashFlow),
+            vec![(5.0, false), (7.0, true), (8.0, true), (10.0, true)]
+        );
+        assert_eq!(cash_flow.annual().len(), 1);
+        assert_eq!(cash_flow.periods.len(), 7);
+        assert_eq!(
+            cash_flow.quarterly()[3].items[&LineItem::OperatingCashFlow].filed,
+            "2024-02-01"
+        );
+        assert!(income
+            .get_period(&cash_flow.quarterly()[1].period)
+            .is_some());
+        assert!(income.series(LineItem::OperatingCashFlow).is_empty());
+    }
+
+    #[test]
+    fn test_custom_concepts_and_currency() {
+        let facts = facts();
+        let income = StatementBuilder::new()
+            .with_concepts(LineItem::Revenue, vec![Concept::us_gaap("SalesRevenueNet")])
+            .income_statement(&facts);
+        assert_eq!(income.series(LineItem::Revenue).len(), 2);
+
+        let income = StatementBuilder::new()
+            .with_currency("EUR")
+            .income_statement(&facts);
+        assert!(income.series(LineItem::Revenue).is_empty());
+        assert_eq!(detect_currency(&facts), Some("USD".to_string()));
+    }
+}
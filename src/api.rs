@@ -5,39 +5,55 @@ use async_trait::async_trait;
 use std::path::Path;
 
 use crate::error::Result;
+#[cfg(feature = "native")]
+use crate::http::ProgressCallback;
+use crate::identifiers::{AccessionNumber, Cik};
 use crate::models::{
     company_concept::CompanyConcept, company_facts::CompanyFacts, company_tickers::CompanyTickers,
-    company_tickers_mf::CompanyTickersMf, frames::XbrlFrames, submission::{Recent, SubmissionHistory},
+    company_tickers_mf::CompanyTickersMf,
+    current_feed::{CurrentFeed, CurrentFeedQuery},
+    edgar_index::{IndexEntry, IndexKind},
+    filing_index::FilingIndex,
+    frames::XbrlFrames,
+    full_text_search::{FullTextSearchQuery, FullTextSearchResults},
+    submission::{Recent, SubmissionHistory},
 };
+#[cfg(feature = "native")]
+use crate::types::BulkDataset;
 use crate::types::{ApiResponse, Period, Taxonomy, Unit};
 
 /// The `EdgarApi` trait defines methods for accessing the SEC EDGAR API endpoints.
 ///
 /// This trait provides a common interface for accessing the various SEC EDGAR API
 /// endpoints, including submission history, company concepts, company facts, and XBRL frames.
-#[async_trait]
-#[cfg(feature = "native")]
+///
+/// With the `native` feature the returned futures are `Send`, so they can be
+/// spawned on a multi-threaded runtime. Without it, as on Cloudflare Workers, they
+/// aren't required to be. Methods that need a file system are only available on
+/// native.
+#[cfg_attr(feature = "native", async_trait)]
+#[cfg_attr(not(feature = "native"), async_trait(?Send))]
 pub trait EdgarApi {
     /// Get company's submissions history
     ///
     /// Endpoint: https://data.sec.gov/submissions/CIK##########.json
     ///
     /// # Parameters
-    /// * `cik` - 10-digit Central Index Key, including leading zeros
+    /// * `cik` - Central Index Key of the company
     ///
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let submissions = edgar_api.get_submissions_history("0000320193").await?;
+    /// let submissions = edgar_api.get_submissions_history(Cik::new(320193)).await?;
     /// println!("Company name: {}", submissions.data.name);
     /// # Ok(())
     /// # }
     /// ```
-    async fn get_submissions_history(&self, cik: &str) -> Result<ApiResponse<SubmissionHistory>>;
+    async fn get_submissions_history(&self, cik: Cik) -> Result<ApiResponse<SubmissionHistory>>;
 
     /// Get additional submissions history file
     ///
@@ -52,11 +68,11 @@ pub trait EdgarApi {
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let submissions = edgar_api.get_submissions_history("0000320193").await?;
+    /// let submissions = edgar_api.get_submissions_history(Cik::new(320193)).await?;
     /// if let Some(files) = submissions.data.filings.files {
     ///     if !files.is_empty() {
     ///         let additional_submissions = edgar_api.get_submissions_file(&files[0].name).await?;
@@ -68,265 +84,240 @@ pub trait EdgarApi {
     /// ```
     async fn get_submissions_file(&self, filename: &str) -> Result<ApiResponse<Recent>>;
 
-    /// Get company concept data for a specific taxonomy and tag
+    /// Search the full text of EDGAR filings
     ///
-    /// Endpoint: https://data.sec.gov/api/xbrl/companyconcept/CIK##########/{taxonomy}/{tag}.json
+    /// Endpoint: https://efts.sec.gov/LATEST/search-index
+    ///
+    /// Covers filings since 2001. Results are returned 100 hits at a time; use
+    /// [`FullTextSearchQuery::next_page`] to paginate.
     ///
     /// # Parameters
-    /// * `cik` - 10-digit Central Index Key, including leading zeros
-    /// * `taxonomy` - XBRL taxonomy (e.g. "us-gaap", "dei")
-    /// * `tag` - XBRL tag identifier (e.g. "AccountsPayableCurrent")
+    /// * `query` - Search terms with optional form, date range and CIK filters
     ///
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config, Taxonomy};
+    /// # use edgar_rs::{EdgarApi, EdgarClient, Config, FullTextSearchQuery};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let concept = edgar_api.get_company_concept(
-    ///     "0000320193",
-    ///     Taxonomy::UsGaap,
-    ///     "AccountsPayableCurrent"
-    /// ).await?;
-    /// println!("Concept label: {}", concept.data.label);
+    /// let query = FullTextSearchQuery::new("\"going concern\"")
+    ///     .with_forms(["10-K"])
+    ///     .with_date_range("2023-01-01", "2023-12-31");
+    /// let results = edgar_api.search_full_text(&query).await?;
+    /// for hit in results.data.hits() {
+    ///     println!("{} {}", hit.accession_number(), hit.source.display_names.join(", "));
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    async fn get_company_concept(
+    async fn search_full_text(
         &self,
-        cik: &str,
-        taxonomy: Taxonomy,
-        tag: &str,
-    ) -> Result<ApiResponse<CompanyConcept>>;
+        query: &FullTextSearchQuery,
+    ) -> Result<ApiResponse<FullTextSearchResults>>;
 
-    /// Get all company facts for a company
+    /// Get the list of documents in a filing
     ///
-    /// Endpoint: https://data.sec.gov/api/xbrl/companyfacts/CIK##########.json
+    /// Endpoint: https://www.sec.gov/Archives/edgar/data/{cik}/{accession-number-without-dashes}/index.json
     ///
     /// # Parameters
-    /// * `cik` - 10-digit Central Index Key, including leading zeros
+    /// * `cik` - Central Index Key of the filer
+    /// * `accession_number` - Accession number of the filing
     ///
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let facts = edgar_api.get_company_facts("0000320193").await?;
-    /// println!("Company CIK: {}", facts.data.cik);
+    /// let index = edgar_api.get_filing_index(Cik::new(320193), "0000320193-23-000106".parse()?).await?;
+    /// for document in index.data.documents() {
+    ///     println!("{} ({} bytes)", document.name, document.size);
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    async fn get_company_facts(&self, cik: &str) -> Result<ApiResponse<CompanyFacts>>;
+    async fn get_filing_index(
+        &self,
+        cik: Cik,
+        accession_number: AccessionNumber,
+    ) -> Result<ApiResponse<FilingIndex>>;
 
-    /// Get XBRL frames data for a specific taxonomy, tag, unit and period
+    /// Download a document of a filing
     ///
-    /// Endpoint: https://data.sec.gov/api/xbrl/frames/{taxonomy}/{tag}/{unit}/{period}.json
+    /// Endpoint: https://www.sec.gov/Archives/edgar/data/{cik}/{accession-number-without-dashes}/{document}
     ///
     /// # Parameters
-    /// * `taxonomy` - XBRL taxonomy (e.g. "us-gaap", "dei")
-    /// * `tag` - XBRL tag identifier (e.g. "AccountsPayableCurrent")
-    /// * `unit` - Unit of measure (e.g. Unit::Simple("USD".to_string()))
-    /// * `period` - Time period (e.g. Period::Instantaneous(2019, 1) for Q1 2019)
+    /// * `cik` - Central Index Key of the filer
+    /// * `accession_number` - Accession number of the filing
+    /// * `document` - File name within the filing, e.g. `FilingEntry::primary_document`
     ///
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config, Taxonomy, Unit, Period};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let frames = edgar_api.get_xbrl_frames(
-    ///     Taxonomy::UsGaap,
-    ///     "AccountsPayableCurrent",
-    ///     Unit::Simple("USD".to_string()),
-    ///     Period::Instantaneous(2019, 1)
-    /// ).await?;
-    /// println!("Data count: {}", frames.data.data.len());
+    /// let submissions = edgar_api.get_submissions_history(Cik::new(320193)).await?;
+    /// if let Some(filing) = submissions.data.get_recent_filings().iter().find(|f| f.form == "10-K") {
+    ///     let html = edgar_api
+    ///         .get_filing_document(Cik::new(320193), filing.accession_number, &filing.primary_document)
+    ///         .await?;
+    ///     println!("Downloaded {} bytes", html.data.len());
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    async fn get_xbrl_frames(
+    async fn get_filing_document(
         &self,
-        taxonomy: Taxonomy,
-        tag: &str,
-        unit: Unit,
-        period: Period,
-    ) -> Result<ApiResponse<XbrlFrames>>;
+        cik: Cik,
+        accession_number: AccessionNumber,
+        document: &str,
+    ) -> Result<ApiResponse<Vec<u8>>>;
 
-    /// Get company tickers exchange data
+    /// Get the daily index of filings disseminated on a given day
     ///
-    /// Endpoint: https://www.sec.gov/files/company_tickers_exchange.json
-    ///
-    /// Returns a mapping of all public companies with their CIK, name, ticker, and exchange.
-    ///
-    /// # Example
-    ///
-    /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
-    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
-    /// let config = Config::new("Your Company Name your.email@example.com");
-    /// let edgar_api = EdgarClient::new(config)?;
-    /// let tickers = edgar_api.get_company_tickers().await?;
-    /// let entries = tickers.data.entries()?;
-    /// println!("Found {} companies", entries.len());
-    /// # Ok(())
-    /// # }
-    /// ```
-    async fn get_company_tickers(&self) -> Result<ApiResponse<CompanyTickers>>;
-
-    /// Get mutual fund tickers data
+    /// Endpoint: https://www.sec.gov/Archives/edgar/daily-index/{year}/QTR{n}/{kind}.{YYYYMMDD}.idx
     ///
-    /// Endpoint: https://www.sec.gov/files/company_tickers_mf.json
+    /// Daily indexes are only published for business days; other days return a 404.
     ///
-    /// Returns a mapping of all mutual funds with their CIK, series ID, class ID, and symbol.
+    /// # Parameters
+    /// * `kind` - Index layout to fetch; all kinds list the same filings
+    /// * `date` - Dissemination date (YYYY-MM-DD)
     ///
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{EdgarApi, EdgarClient, Config, IndexKind};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let mf_tickers = edgar_api.get_company_tickers_mf().await?;
-    /// let entries = mf_tickers.data.entries()?;
-    /// println!("Found {} mutual fund entries", entries.len());
+    /// let index = edgar_api.get_daily_index(IndexKind::Master, "2024-01-02").await?;
+    /// for entry in index.data.iter().filter(|e| e.form_type == "8-K") {
+    ///     println!("{} {:?}", entry.company_name, entry.accession_number());
+    /// }
     /// # Ok(())
     /// # }
     /// ```
-    async fn get_company_tickers_mf(&self) -> Result<ApiResponse<CompanyTickersMf>>;
-
-    /// Download bulk submissions data
-    ///
-    /// Note: This functionality is not available in Cloudflare Workers
-    /// as it requires file system access.
-    #[cfg(feature = "native")]
-    async fn download_bulk_submissions(&self, output_path: &str) -> Result<()>;
-
-    /// Download bulk company facts data
-    ///
-    /// Note: This functionality is not available in Cloudflare Workers
-    /// as it requires file system access.
-    #[cfg(feature = "native")]
-    async fn download_bulk_company_facts(&self, output_path: &str) -> Result<()>;
-
-    /// Extract ZIP files
-    ///
-    /// Note: This functionality is not available in Cloudflare Workers
-    /// as it requires file system access.
-    #[cfg(feature = "native")]
-    async fn extract_zip_files(&self, zip_path: &Path, output_dir: &Path) -> Result<()>;
-}
+    async fn get_daily_index(
+        &self,
+        kind: IndexKind,
+        date: &str,
+    ) -> Result<ApiResponse<Vec<IndexEntry>>>;
 
-/// The `EdgarApi` trait defines methods for accessing the SEC EDGAR API endpoints (Cloudflare Workers).
-///
-/// This trait provides a common interface for accessing the various SEC EDGAR API
-/// endpoints, including submission history, company concepts, company facts, and XBRL frames.
-#[async_trait(?Send)]
-#[cfg(feature = "cloudflare-workers")]
-pub trait EdgarApi {
-    /// Get company's submissions history
+    /// Get the full index of filings for a calendar quarter
     ///
-    /// Endpoint: https://data.sec.gov/submissions/CIK##########.json
+    /// Endpoint: https://www.sec.gov/Archives/edgar/full-index/{year}/QTR{n}/{kind}.idx
     ///
     /// # Parameters
-    /// * `cik` - 10-digit Central Index Key, including leading zeros
+    /// * `kind` - Index layout to fetch; all kinds list the same filings
+    /// * `year` - Calendar year (1993 or later)
+    /// * `quarter` - Calendar quarter (1-4)
     ///
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{EdgarApi, EdgarClient, Config, IndexKind};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let submissions = edgar_api.get_submissions_history("0000320193").await?;
-    /// println!("Company name: {}", submissions.data.name);
+    /// let index = edgar_api.get_quarterly_index(IndexKind::Form, 2024, 1).await?;
+    /// let ten_ks = index.data.iter().filter(|e| e.form_type == "10-K").count();
+    /// println!("{} 10-K filings in Q1 2024", ten_ks);
     /// # Ok(())
     /// # }
     /// ```
-    async fn get_submissions_history(&self, cik: &str) -> Result<ApiResponse<SubmissionHistory>>;
+    async fn get_quarterly_index(
+        &self,
+        kind: IndexKind,
+        year: i32,
+        quarter: u8,
+    ) -> Result<ApiResponse<Vec<IndexEntry>>>;
 
-    /// Get additional submissions history file
+    /// Get the latest filings feed
     ///
-    /// Endpoint: https://data.sec.gov/submissions/{filename}
+    /// Endpoint: https://www.sec.gov/cgi-bin/browse-edgar?action=getcurrent&output=atom
     ///
-    /// This method fetches additional filing history files when there are more than 1000 filings.
-    /// The filenames are provided in the `files` field of the main submissions response.
+    /// Lists filings within minutes of acceptance, most recent first.
     ///
     /// # Parameters
-    /// * `filename` - Name of the additional submissions file (e.g., "CIK0001067983-submissions-001.json")
+    /// * `query` - Optional form type filter and paging
     ///
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{EdgarApi, EdgarClient, Config, CurrentFeedQuery};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let submissions = edgar_api.get_submissions_history("0001067983").await?;
-    /// if let Some(files) = &submissions.data.filings.files {
-    ///     for file in files {
-    ///         let additional = edgar_api.get_submissions_file(&file.name).await?;
-    ///         println!("Additional filings: {}", additional.data.accession_number.len());
-    ///     }
+    /// let feed = edgar_api
+    ///     .get_current_filings(&CurrentFeedQuery::new().with_form_type("8-K"))
+    ///     .await?;
+    /// for entry in &feed.data.entries {
+    ///     println!("{} {} {:?}", entry.updated, entry.company_name, entry.items);
     /// }
     /// # Ok(())
     /// # }
     /// ```
-    async fn get_submissions_file(&self, filename: &str) -> Result<ApiResponse<Recent>>;
+    async fn get_current_filings(
+        &self,
+        query: &CurrentFeedQuery,
+    ) -> Result<ApiResponse<CurrentFeed>>;
 
-    /// Get company data for a specific concept and taxonomy
+    /// Get company concept data for a specific taxonomy and tag
     ///
-    /// Endpoint: https://data.sec.gov/api/xbrl/companyconcept/CIK##########/taxonomy/tag.json
+    /// Endpoint: https://data.sec.gov/api/xbrl/companyconcept/CIK##########/{taxonomy}/{tag}.json
     ///
     /// # Parameters
-    /// * `cik` - 10-digit Central Index Key, including leading zeros
-    /// * `taxonomy` - Taxonomy to use (us-gaap, ifrs-full, dei, or srt)
-    /// * `tag` - The concept tag to retrieve
+    /// * `cik` - Central Index Key of the company
+    /// * `taxonomy` - XBRL taxonomy (e.g. "us-gaap", "dei")
+    /// * `tag` - XBRL tag identifier (e.g. "AccountsPayableCurrent")
     ///
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config, Taxonomy};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config, Taxonomy};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
     /// let concept = edgar_api.get_company_concept(
-    ///     "0000320193",
+    ///     Cik::new(320193),
     ///     Taxonomy::UsGaap,
     ///     "AccountsPayableCurrent"
     /// ).await?;
+    /// println!("Concept label: {}", concept.data.label);
     /// # Ok(())
     /// # }
     /// ```
     async fn get_company_concept(
         &self,
-        cik: &str,
+        cik: Cik,
         taxonomy: Taxonomy,
         tag: &str,
     ) -> Result<ApiResponse<CompanyConcept>>;
 
-    /// Get all company facts for a specific company
+    /// Get all company facts for a company
     ///
     /// Endpoint: https://data.sec.gov/api/xbrl/companyfacts/CIK##########.json
     ///
     /// # Parameters
-    /// * `cik` - 10-digit Central Index Key, including leading zeros
+    /// * `cik` - Central Index Key of the company
     ///
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let facts = edgar_api.get_company_facts("0000320193").await?;
+    /// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
+    /// println!("Company CIK: {}", facts.data.cik);
     /// # Ok(())
     /// # }
     /// ```
-    async fn get_company_facts(&self, cik: &str) -> Result<ApiResponse<CompanyFacts>>;
+    async fn get_company_facts(&self, cik: Cik) -> Result<ApiResponse<CompanyFacts>>;
 
     /// Get XBRL frames data for a specific taxonomy, tag, unit and period
     ///
@@ -404,4 +395,70 @@ pub trait EdgarApi {
     /// # }
     /// ```
     async fn get_company_tickers_mf(&self) -> Result<ApiResponse<CompanyTickersMf>>;
+
+    /// Download bulk submissions data
+    ///
+    /// The archive is streamed to a temporary file and extracted into `output_path`.
+    ///
+    /// Note: This functionality is not available in Cloudflare Workers
+    /// as it requires file system access.
+    #[cfg(feature = "native")]
+    async fn download_bulk_submissions(&self, output_path: &str) -> Result<()>;
+
+    /// Download bulk company facts data
+    ///
+    /// The archive is streamed to a temporary file and extracted into `output_path`.
+    ///
+    /// Note: This functionality is not available in Cloudflare Workers
+    /// as it requires file system access.
+    #[cfg(feature = "native")]
+    async fn download_bulk_company_facts(&self, output_path: &str) -> Result<()>;
+
+    /// Download a bulk archive without extracting it
+    ///
+    /// The response body is written to `zip_path` chunk by chunk, so memory use stays
+    /// constant regardless of the archive size. Returns the number of bytes written.
+    ///
+    /// # Parameters
+    /// * `dataset` - The bulk archive to download
+    /// * `zip_path` - Destination file, created or truncated
+    /// * `progress` - Optional callback invoked after every chunk
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use std::path::Path;
+    /// # use edgar_rs::{BulkDataset, DownloadProgress, EdgarApi, EdgarClient, Config};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let report = |p: DownloadProgress| {
+    ///     if let Some(fraction) = p.fraction() {
+    ///         println!("{:.1}%", fraction * 100.0);
+    ///     }
+    /// };
+    /// let bytes = edgar_api
+    ///     .download_bulk_archive(BulkDataset::CompanyFacts, Path::new("companyfacts.zip"), Some(&report))
+    ///     .await?;
+    /// println!("Downloaded {} bytes", bytes);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Note: This functionality is not available in Cloudflare Workers
+    /// as it requires file system access.
+    #[cfg(feature = "native")]
+    async fn download_bulk_archive(
+        &self,
+        dataset: BulkDataset,
+        zip_path: &Path,
+        progress: Option<&ProgressCallback<'_>>,
+    ) -> Result<u64>;
+
+    /// Extract ZIP files
+    ///
+    /// Note: This functionality is not available in Cloudflare Workers
+    /// as it requires file system access.
+    #[cfg(feature = "native")]
+    async fn extract_zip_files(&self, zip_path: &Path, output_dir: &Path) -> Result<()>;
 }
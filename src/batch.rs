@@ -0,0 +1,273 @@
+//! Batch requests for many companies.
+//!
+//! The batch methods on [`EdgarClient`] run one request per CIK with a bounded
+//! number in flight and yield each result as soon as it completes. Every request
+//! still waits for the client's rate limiter and follows its retry policy, so the
+//! concurrency limit only caps how many requests are queued or in flight at once.
+
+use std::future::Future;
+
+use futures::stream::{self, BoxStream, StreamExt};
+
+use crate::api::EdgarApi;
+use crate::client::EdgarClient;
+use crate::error::Result;
+use crate::http::HttpClient;
+use crate::identifiers::Cik;
+use crate::models::{
+    company_concept::CompanyConcept, company_facts::CompanyFacts, submission::SubmissionHistory,
+};
+use crate::types::{ApiResponse, Taxonomy};
+
+/// Default number of requests a batch keeps in flight, matching SEC's limit of
+/// 10 requests per second.
+pub const DEFAULT_BATCH_CONCURRENCY: usize = 10;
+
+impl<H: HttpClient> EdgarClient<H> {
+    /// Runs `request` for every CIK, keeping at most `concurrency` requests in
+    /// flight, and yields `(cik, result)` pairs in the order they complete.
+    ///
+    /// A failed request only affects its own result. A `concurrency` of zero is
+    /// treated as one.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// use edgar_rs::{Cik, Config, EdgarApi, EdgarClient, Taxonomy, DEFAULT_BATCH_CONCURRENCY};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = EdgarClient::new(Config::new("Your Company Name your.email@example.com"))?;
+    ///     let ciks = [Cik::new(320193), Cik::new(789019)];
+    ///     let mut results = client.batch(ciks, DEFAULT_BATCH_CONCURRENCY, |cik| {
+    ///         client.get_company_concept(cik, Taxonomy::UsGaap, "Revenues")
+    ///     });
+    ///     while let Some((cik, result)) = results.next().await {
+    ///         println!("{}: {}", cik, result.is_ok());
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn batch<'a, I, T, F, Fut>(
+        &'a self,
+        ciks: I,
+        concurrency: usize,
+        mut request: F,
+    ) -> BoxStream<'a, (Cik, Result<T>)>
+    where
+        I: IntoIterator<Item = Cik>,
+        I::IntoIter: Send + 'a,
+        T: Send + 'a,
+        F: FnMut(Cik) -> Fut + Send + 'a,
+        Fut: Future<Output = Result<T>> + Send + 'a,
+    {
+        stream::iter(ciks)
+            .map(move |cik| {
+                let response = request(cik);
+                async move { (cik, response.await) }
+            })
+            .buffer_unordered(concurrency.max(1))
+            .boxed()
+    }
+
+    /// Fetches the company facts of every CIK, keeping at most `concurrency`
+    /// requests in flight. See [`EdgarClient::batch`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use futures::StreamExt;
+    /// use edgar_rs::{Cik, Config, EdgarClient, DEFAULT_BATCH_CONCURRENCY};
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    ///     let client = EdgarClient::new(Config::new("Your Company Name your.email@example.com"))?;
+    ///     let ciks = vec![Cik::new(320193), Cik::new(789019), Cik::new(1652044)];
+    ///     let mut results = client.get_company_facts_batch(ciks, DEFAULT_BATCH_CONCURRENCY);
+    ///     while let Some((cik, result)) = results.next().await {
+    ///         match result {
+    ///             Ok(facts) => println!("{}: {}", cik, facts.data.entity_name),
+    ///             Err(e) => eprintln!("{}: {}", cik, e),
+    ///         }
+    ///     }
+    ///     Ok(())
+    /// }
+    /// ```
+    pub fn get_company_facts_batch<'a, I>(
+        &'a self,
+        ciks: I,
+        concurrency: usize,
+    ) -> BoxStream<'a, (Cik, Result<ApiResponse<CompanyFacts>>)>
+    where
+        I: IntoIterator<Item = Cik>,
+        I::IntoIter: Send + 'a,
+    {
+        self.batch(ciks, concurrency, move |cik| self.get_company_facts(cik))
+    }
+
+    /// Fetches the submission history of every CIK, keeping at most
+    /// `concurrency` requests in flight. See [`EdgarClient::batch`].
+    pub fn get_submissions_history_batch<'a, I>(
+        &'a self,
+        ciks: I,
+        concurrency: usize,
+    ) -> BoxStream<'a, (Cik, Result<ApiResponse<SubmissionHistory>>)>
+    where
+        I: IntoIterator<Item = Cik>,
+        I::IntoIter: Send + 'a,
+    {
+        self.batch(ciks, concurrency, move |cik| {
+            self.get_submissions_history(cik)
+        })
+    }
+
+    /// Fetches one concept for every CIK, keeping at most `concurrency` requests
+    /// in flight. See [`EdgarClient::batch`].
+    pub fn get_company_concept_batch<'a, I>(
+        &'a self,
+        ciks: I,
+        taxonomy: Taxonomy,
+        tag: &'a str,
+        concurrency: usize,
+    ) -> BoxStream<'a, (Cik, Result<ApiResponse<CompanyConcept>>)>
+    where
+        I: IntoIterator<Item = Cik>,
+        I::IntoIter: Send + 'a,
+    {
+        self.batch(ciks, concurrency, move |cik| {
+            self.get_company_concept(cik, taxonomy, tag)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::Config;
+    use crate::error::EdgarApiError;
+    use crate::http::HttpResponse;
+    use crate::retry::RetryPolicy;
+    use async_trait::async_trait;
+    use std::collections::HashMap;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    /// HTTP client that serves company facts named after the requested CIK,
+    /// except a 404 for CIK 2, a connection error for CIK 4 and invalid JSON for
+    /// CIK 5, and records the highest number of concurrent requests.
+    #[derive(Default)]
+    struct FactsClient {
+        in_flight: AtomicUsize,
+        max_in_flight: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl HttpClient for FactsClient {
+        async fn get(&self, url: &str, _headers: &[(&str, &str)]) -> Result<HttpResponse> {
+            let in_flight = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(in_flight, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+
+            let cik: u64 = url
+                .rsplit("CIK")
+                .next()
+                .and_then(|file| file.strip_suffix(".json"))
+                .and_then(|digits| digits.parse().ok())
+                .unwrap();
+            let (status, body) = match cik {
+                2 => (404, String::new()),
+                4 => return Err(EdgarApiError::request("connection reset")),
+                5 => (200, "{".to_string()),
+                _ => (
+                    200,
+                    format!(
+                        r#"{{"cik": {}, "entityName": "Company {}", "facts": {{}}}}"#,
+                        cik, cik
+                    ),
+                ),
+            };
+            Ok(HttpResponse {
+                status,
+                headers: HashMap::new(),
+                body: body.into_bytes(),
+            })
+        }
+    }
+
+    fn client() -> (EdgarClient<FactsClient>, Arc<AtomicUsize>) {
+        let config = Config::new("edgar-rs tests test@example.com")
+            .without_rate_limit()
+            .with_retry_policy(RetryPolicy::none());
+        let http_client = FactsClient::default();
+        let max_in_flight = http_client.max_in_flight.clone();
+        (EdgarClient::with_client(http_client, config), max_in_flight)
+    }
+
+    #[tokio::test]
+    async fn test_company_facts_batch() {
+        let (client, max_in_flight) = client();
+
+        let ciks: Vec<Cik> = (1..=6).map(Cik::new).collect();
+        let mut results: Vec<(Cik, Result<ApiResponse<CompanyFacts>>)> =
+            client.get_company_facts_batch(ciks, 2).collect().await;
+        results.sort_by_key(|(cik, _)| *cik);
+
+        assert_eq!(results.len(), 6);
+        assert!(matches!(
+            results[1].1,
+            Err(EdgarApiError::ApiError { status: 404, .. })
+        ));
+        assert_eq!(results.iter().filter(|(_, r)| r.is_ok()).count(), 3);
+        assert_eq!(max_in_flight.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn test_batch_keeps_at_most_concurrency_requests_in_flight() {
+        for (concurrency, expected) in [(0, 1), (1, 1), (3, 3), (8, 8), (20, 12)] {
+            let (client, max_in_flight) = client();
+            let ciks: Vec<Cik> = (6..18).map(Cik::new).collect();
+            let results: Vec<_> = client
+                .get_company_facts_batch(ciks, concurrency)
+                .collect()
+                .await;
+
+            assert_eq!(results.len(), 12);
+            assert_eq!(
+                max_in_flight.load(Ordering::SeqCst),
+                expected,
+                "concurrency {}",
+                concurrency
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_failed_requests_do_not_affect_the_others() {
+        let (client, _) = client();
+
+        let ciks: Vec<Cik> = (1..=6).map(Cik::new).collect();
+        let results: HashMap<Cik, Result<ApiResponse<CompanyFacts>>> = client
+            .get_company_facts_batch(ciks, 6)
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect();
+
+        assert!(matches!(
+            results[&Cik::new(2)],
+            Err(EdgarApiError::ApiError { status: 404, .. })
+        ));
+        assert!(matches!(
+            results[&Cik::new(4)],
+            Err(EdgarApiError::RequestError(_))
+        ));
+        assert!(results[&Cik::new(5)].is_err());
+        for cik in [1, 3, 6] {
+            let facts = results[&Cik::new(cik)].as_ref().unwrap();
+            assert_eq!(facts.data.entity_name, format!("Company {}", cik));
+        }
+    }
+}
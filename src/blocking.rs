@@ -0,0 +1,356 @@
+//! A blocking facade over [`EdgarClient`] for callers without an async runtime.
+//!
+//! [`BlockingEdgarClient`] owns a single-threaded tokio runtime and drives each
+//! request of the wrapped async client to completion on the calling thread, so it
+//! returns the same models and errors as [`EdgarApi`].
+
+use std::future::Future;
+use std::path::Path;
+use std::sync::Arc;
+
+use tokio::runtime::{Builder, Runtime};
+
+use crate::api::EdgarApi;
+use crate::client::EdgarClient;
+use crate::config::Config;
+use crate::error::Result;
+use crate::http::{HttpClient, ProgressCallback, ReqwestClient};
+use crate::identifiers::{AccessionNumber, Cik};
+use crate::models::{
+    company_concept::CompanyConcept,
+    company_facts::CompanyFacts,
+    company_tickers::CompanyTickers,
+    company_tickers_mf::CompanyTickersMf,
+    current_feed::{CurrentFeed, CurrentFeedQuery},
+    edgar_index::{IndexEntry, IndexKind},
+    filing_index::FilingIndex,
+    frames::XbrlFrames,
+    full_text_search::{FullTextSearchQuery, FullTextSearchResults},
+    submission::{Recent, SubmissionHistory},
+};
+use crate::resolver::CikResolver;
+use crate::types::{ApiResponse, BulkDataset, Period, Taxonomy, Unit};
+
+/// A synchronous EDGAR client with the methods of [`EdgarApi`].
+///
+/// Every method blocks the calling thread until the request completes, honouring
+/// the rate limit and retry policy of the [`Config`]. Clones share the runtime,
+/// rate limiter and CIK resolver, and can be used from several threads at once.
+///
+/// The methods must not be called from within an async runtime, where they
+/// panic; use [`EdgarClient`] there instead.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use edgar_rs::{BlockingEdgarClient, Cik, Config};
+///
+/// fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let config = Config::new("Your Company Name your.email@example.com");
+///     let edgar_api = BlockingEdgarClient::new(config)?;
+///
+///     let submissions = edgar_api.get_submissions_history(Cik::new(320193))?;
+///     println!("Company name: {}", submissions.data.name);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone)]
+pub struct BlockingEdgarClient<H: HttpClient = ReqwestClient> {
+    inner: EdgarClient<H>,
+    runtime: Arc<Runtime>,
+}
+
+impl BlockingEdgarClient<ReqwestClient> {
+    /// Creates a blocking client with the default native HTTP client.
+    pub fn new(config: Config) -> Result<Self> {
+        Self::from_async(EdgarClient::new(config)?)
+    }
+}
+
+impl<H: HttpClient> BlockingEdgarClient<H> {
+    /// Creates a blocking client with a custom HTTP client and configuration.
+    pub fn with_client(http_client: H, config: Config) -> Result<Self> {
+        Self::from_async(EdgarClient::with_client(http_client, config))
+    }
+
+    /// Wraps an existing async client, sharing its rate limiter and CIK resolver.
+    pub fn from_async(inner: EdgarClient<H>) -> Result<Self> {
+        let runtime = Builder::new_current_thread().enable_all().build()?;
+        Ok(Self {
+            inner,
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Uses `resolver` for identifier lookups instead of fetching the ticker lists.
+    pub fn with_cik_resolver(self, resolver: CikResolver) -> Self {
+        Self {
+            inner: self.inner.with_cik_resolver(resolver),
+            runtime: self.runtime,
+        }
+    }
+
+    /// The wrapped async client.
+    pub fn as_async(&self) -> &EdgarClient<H> {
+        &self.inner
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+
+    /// See [`EdgarApi::get_submissions_history`].
+    pub fn get_submissions_history(&self, cik: Cik) -> Result<ApiResponse<SubmissionHistory>> {
+        self.block_on(self.inner.get_submissions_history(cik))
+    }
+
+    /// See [`EdgarApi::get_submissions_file`].
+    pub fn get_submissions_file(&self, filename: &str) -> Result<ApiResponse<Recent>> {
+        self.block_on(self.inner.get_submissions_file(filename))
+    }
+
+    /// See [`EdgarApi::search_full_text`].
+    pub fn search_full_text(
+        &self,
+        query: &FullTextSearchQuery,
+    ) -> Result<ApiResponse<FullTextSearchResults>> {
+        self.block_on(self.inner.search_full_text(query))
+    }
+
+    /// See [`EdgarApi::get_filing_index`].
+    pub fn get_filing_index(
+        &self,
+        cik: Cik,
+        accession_number: AccessionNumber,
+    ) -> Result<ApiResponse<FilingIndex>> {
+        self.block_on(self.inner.get_filing_index(cik, accession_number))
+    }
+
+    /// See [`EdgarApi::get_filing_document`].
+    pub fn get_filing_document(
+        &self,
+        cik: Cik,
+        accession_number: AccessionNumber,
+        document: &str,
+    ) -> Result<ApiResponse<Vec<u8>>> {
+        self.block_on(
+            self.inner
+                .get_filing_document(cik, accession_number, document),
+        )
+    }
+
+    /// See [`EdgarApi::get_daily_index`].
+    pub fn get_daily_index(
+        &self,
+        kind: IndexKind,
+        date: &str,
+    ) -> Result<ApiResponse<Vec<IndexEntry>>> {
+        self.block_on(self.inner.get_daily_index(kind, date))
+    }
+
+    /// See [`EdgarApi::get_quarterly_index`].
+    pub fn get_quarterly_index(
+        &self,
+        kind: IndexKind,
+        year: i32,
+        quarter: u8,
+    ) -> Result<ApiResponse<Vec<IndexEntry>>> {
+        self.block_on(self.inner.get_quarterly_index(kind, year, quarter))
+    }
+
+    /// See [`EdgarApi::get_current_filings`].
+    pub fn get_current_filings(
+        &self,
+        query: &CurrentFeedQuery,
+    ) -> Result<ApiResponse<CurrentFeed>> {
+        self.block_on(self.inner.get_current_filings(query))
+    }
+
+    /// See [`EdgarApi::get_company_concept`].
+    pub fn get_company_concept(
+        &self,
+        cik: Cik,
+        taxonomy: Taxonomy,
+        tag: &str,
+    ) -> Result<ApiResponse<CompanyConcept>> {
+        self.block_on(self.inner.get_company_concept(cik, taxonomy, tag))
+    }
+
+    /// See [`EdgarApi::get_company_facts`].
+    pub fn get_company_facts(&self, cik: Cik) -> Result<ApiResponse<CompanyFacts>> {
+        self.block_on(self.inner.get_company_facts(cik))
+    }
+
+    /// See [`EdgarApi::get_xbrl_frames`].
+    pub fn get_xbrl_frames(
+        &self,
+        taxonomy: Taxonomy,
+        tag: &str,
+        unit: Unit,
+        period: Period,
+    ) -> Result<ApiResponse<XbrlFrames>> {
+        self.block_on(self.inner.get_xbrl_frames(taxonomy, tag, unit, period))
+    }
+
+    /// See [`EdgarApi::get_company_tickers`].
+    pub fn get_company_tickers(&self) -> Result<ApiResponse<CompanyTickers>> {
+        self.block_on(self.inner.get_company_tickers())
+    }
+
+    /// See [`EdgarApi::get_company_tickers_mf`].
+    pub fn get_company_tickers_mf(&self) -> Result<ApiResponse<CompanyTickersMf>> {
+        self.block_on(self.inner.get_company_tickers_mf())
+    }
+
+    /// See [`EdgarApi::download_bulk_submissions`].
+    pub fn download_bulk_submissions(&self, output_path: &str) -> Result<()> {
+        self.block_on(self.inner.download_bulk_submissions(output_path))
+    }
+
+    /// See [`EdgarApi::download_bulk_company_facts`].
+    pub fn download_bulk_company_facts(&self, output_path: &str) -> Result<()> {
+        self.block_on(self.inner.download_bulk_company_facts(output_path))
+    }
+
+    /// See [`EdgarApi::download_bulk_archive`].
+    pub fn download_bulk_archive(
+        &self,
+        dataset: BulkDataset,
+        zip_path: &Path,
+        progress: Option<&ProgressCallback<'_>>,
+    ) -> Result<u64> {
+        self.block_on(
+            self.inner
+                .download_bulk_archive(dataset, zip_path, progress),
+        )
+    }
+
+    /// See [`EdgarApi::extract_zip_files`].
+    pub fn extract_zip_files(&self, zip_path: &Path, output_dir: &Path) -> Result<()> {
+        self.block_on(self.inner.extract_zip_files(zip_path, output_dir))
+    }
+
+    /// See [`EdgarClient::resolve_cik`].
+    pub fn resolve_cik(&self, identifier: &str) -> Result<Cik> {
+        self.block_on(self.inner.resolve_cik(identifier))
+    }
+
+    /// See [`EdgarClient::refresh_cik_resolver`].
+    pub fn refresh_cik_resolver(&self) -> Result<Arc<CikResolver>> {
+        self.block_on(self.inner.refresh_cik_resolver())
+    }
+
+    /// See [`EdgarClient::get_submissions_history_by_identifier`].
+    pub fn get_submissions_history_by_identifier(
+        &self,
+        identifier: &str,
+    ) -> Result<ApiResponse<SubmissionHistory>> {
+        self.block_on(self.inner.get_submissions_history_by_identifier(identifier))
+    }
+
+    /// See [`EdgarClient::get_filing_index_by_identifier`].
+    pub fn get_filing_index_by_identifier(
+        &self,
+        identifier: &str,
+        accession_number: AccessionNumber,
+    ) -> Result<ApiResponse<FilingIndex>> {
+        self.block_on(
+            self.inner
+                .get_filing_index_by_identifier(identifier, accession_number),
+        )
+    }
+
+    /// See [`EdgarClient::get_filing_document_by_identifier`].
+    pub fn get_filing_document_by_identifier(
+        &self,
+        identifier: &str,
+        accession_number: AccessionNumber,
+        document: &str,
+    ) -> Result<ApiResponse<Vec<u8>>> {
+        self.block_on(self.inner.get_filing_document_by_identifier(
+            identifier,
+            accession_number,
+            document,
+        ))
+    }
+
+    /// See [`EdgarClient::get_company_concept_by_identifier`].
+    pub fn get_company_concept_by_identifier(
+        &self,
+        identifier: &str,
+        taxonomy: Taxonomy,
+        tag: &str,
+    ) -> Result<ApiResponse<CompanyConcept>> {
+        self.block_on(
+            self.inner
+                .get_company_concept_by_identifier(identifier, taxonomy, tag),
+        )
+    }
+
+    /// See [`EdgarClient::get_company_facts_by_identifier`].
+    pub fn get_company_facts_by_identifier(
+        &self,
+        identifier: &str,
+    ) -> Result<ApiResponse<CompanyFacts>> {
+        self.block_on(self.inner.get_company_facts_by_identifier(identifier))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::EdgarApiError;
+    use crate::http::MockHttpClient;
+    use serde_json::json;
+
+    fn client() -> BlockingEdgarClient<MockHttpClient> {
+        let http_client = MockHttpClient::new()
+            .with_status("/companyfacts/", 404)
+            .with_json(
+                "/companyfacts/CIK0000320193.json",
+                json!({"cik": 320193, "entityName": "Apple Inc.", "facts": {}}),
+            )
+            .with_json(
+                "/company_tickers_exchange.json",
+                json!({
+                    "fields": ["cik", "name", "ticker", "exchange"],
+                    "data": [[320193, "Apple Inc.", "AAPL", "Nasdaq"]]
+                }),
+            )
+            .with_json(
+                "/company_tickers_mf.json",
+                json!({"fields": ["cik", "seriesId", "classId", "symbol"], "data": []}),
+            );
+        let config = Config::new("edgar-rs tests test@example.com").without_rate_limit();
+        BlockingEdgarClient::with_client(http_client, config).unwrap()
+    }
+
+    #[test]
+    fn test_blocking_requests() {
+        let client = client();
+
+        let facts = client.get_company_facts(Cik::new(320193)).unwrap();
+        assert_eq!(facts.data.entity_name, "Apple Inc.");
+        assert!(matches!(
+            client.get_company_facts(Cik::new(789019)),
+            Err(EdgarApiError::ApiError { status: 404, .. })
+        ));
+
+        let facts = client.get_company_facts_by_identifier("aapl").unwrap();
+        assert_eq!(facts.data.cik, Cik::new(320193));
+    }
+
+    #[test]
+    fn test_clones_are_usable_across_threads() {
+        let client = client();
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let client = client.clone();
+                std::thread::spawn(move || client.get_company_facts(Cik::new(320193)))
+            })
+            .collect();
+        for handle in handles {
+            assert_eq!(handle.join().unwrap().unwrap().data.cik, Cik::new(320193));
+        }
+    }
+}
@@ -0,0 +1,12 @@
+//! Offline access to SEC bulk data.
+//!
+//! This module works with the archives downloaded by
+//! [`EdgarApi::download_bulk_archive`](crate::EdgarApi::download_bulk_archive)
+//! and friends. It is only available with the native feature as it requires
+//! file system access.
+
+mod archive;
+mod store;
+
+pub use archive::{CompanyFactsArchive, CompanyFactsEntries};
+pub use store::LocalBulkStore;
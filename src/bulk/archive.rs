@@ -0,0 +1,211 @@
+//! Reading bulk ZIP archives without extracting them.
+
+use std::fs::File;
+use std::io::{BufReader, Read};
+use std::path::Path;
+
+use log::trace;
+use zip::ZipArchive;
+
+use crate::error::{EdgarApiError, Result};
+use crate::identifiers::Cik;
+use crate::models::company_facts::CompanyFacts;
+
+/// A downloaded `companyfacts.zip` archive.
+///
+/// Entries are decompressed and deserialized one at a time, so the whole corpus
+/// can be processed without extracting ~18k JSON files to disk.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use edgar_rs::CompanyFactsArchive;
+/// # fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let mut archive = CompanyFactsArchive::open("companyfacts.zip")?;
+/// println!("{} companies", archive.len());
+///
+/// for facts in archive.iter() {
+///     match facts {
+///         Ok(facts) => println!("{}: {} taxonomies", facts.entity_name, facts.facts.len()),
+///         Err(e) => eprintln!("Skipping entry: {}", e),
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub struct CompanyFactsArchive {
+    archive: ZipArchive<BufReader<File>>,
+}
+
+impl CompanyFactsArchive {
+    /// Opens a `companyfacts.zip` archive.
+    ///
+    /// # Parameters
+    ///
+    /// * `path` - The path to the ZIP file.
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let file = File::open(path)
+            .map_err(|e| EdgarApiError::request(format!("Failed to open ZIP file: {}", e)))?;
+
+        let archive = ZipArchive::new(BufReader::new(file))
+            .map_err(|e| EdgarApiError::zip(format!("Failed to read ZIP archive: {}", e)))?;
+        trace!("Opened {} with {} entries", path.display(), archive.len());
+
+        Ok(Self { archive })
+    }
+
+    /// Returns the number of entries in the archive.
+    pub fn len(&self) -> usize {
+        self.archive.len()
+    }
+
+    /// Returns true if the archive has no entries.
+    pub fn is_empty(&self) -> bool {
+        self.archive.is_empty()
+    }
+
+    /// Returns an iterator deserializing every company in the archive.
+    ///
+    /// An entry that fails to decompress or deserialize yields an error without
+    /// ending the iteration, so one malformed file does not abort a corpus-wide job.
+    pub fn iter(&mut self) -> CompanyFactsEntries<'_> {
+        CompanyFactsEntries {
+            archive: &mut self.archive,
+            index: 0,
+            buffer: Vec::new(),
+        }
+    }
+
+    /// Returns the facts for a single company, or `None` if it is not in the archive.
+    ///
+    /// # Parameters
+    ///
+    /// * `cik` - The CIK number of the company.
+    pub fn get(&mut self, cik: Cik) -> Result<Option<CompanyFacts>> {
+        let name = format!("CIK{}.json", cik);
+        let mut buffer = Vec::new();
+
+        match self.archive.by_name(&name) {
+            Ok(mut entry) => read_entry(&mut entry, &mut buffer).map(Some),
+            Err(zip::result::ZipError::FileNotFound) => Ok(None),
+            Err(e) => Err(EdgarApiError::zip(format!(
+                "Failed to access ZIP file entry {}: {}",
+                name, e
+            ))),
+        }
+    }
+}
+
+/// Iterator over the companies in a [`CompanyFactsArchive`].
+pub struct CompanyFactsEntries<'a> {
+    archive: &'a mut ZipArchive<BufReader<File>>,
+    index: usize,
+    buffer: Vec<u8>,
+}
+
+impl Iterator for CompanyFactsEntries<'_> {
+    type Item = Result<CompanyFacts>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.index < self.archive.len() {
+            let index = self.index;
+            self.index += 1;
+
+            let mut entry = match self.archive.by_index(index) {
+                Ok(entry) => entry,
+                Err(e) => {
+                    return Some(Err(EdgarApiError::zip(format!(
+                        "Failed to access ZIP file entry: {}",
+                        e
+                    ))))
+                }
+            };
+
+            if entry.is_dir() || !entry.name().ends_with(".json") {
+                continue;
+            }
+
+            return Some(read_entry(&mut entry, &mut self.buffer));
+        }
+
+        None
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, Some(self.archive.len() - self.index))
+    }
+}
+
+/// Decompresses a ZIP entry into `buffer` and deserializes it.
+fn read_entry(entry: &mut zip::read::ZipFile<'_>, buffer: &mut Vec<u8>) -> Result<CompanyFacts> {
+    buffer.clear();
+    entry
+        .read_to_end(buffer)
+        .map_err(|e| EdgarApiError::zip(format!("Failed to read {}: {}", entry.name(), e)))?;
+
+    serde_json::from_slice(buffer)
+        .map_err(|e| EdgarApiError::parse(format!("Failed to parse {}: {}", entry.name(), e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    use zip::write::FileOptions;
+
+    fn write_archive(path: &Path, entries: &[(&str, &str)]) {
+        let mut writer = zip::ZipWriter::new(File::create(path).unwrap());
+        for (name, contents) in entries {
+            writer.start_file(*name, FileOptions::default()).unwrap();
+            writer.write_all(contents.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    fn facts_json(cik: u64, name: &str) -> String {
+        format!(
+            r#"{{"cik": {}, "entityName": "{}", "facts": {{"dei": {{}}}}}}"#,
+            cik, name
+        )
+    }
+
+    #[test]
+    fn test_iterates_entries_and_reports_bad_ones() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("companyfacts.zip");
+        write_archive(
+            &path,
+            &[
+                ("CIK0000320193.json", &facts_json(320193, "Apple Inc.")),
+                ("CIK0000000001.json", "{not json"),
+                ("README.txt", "ignored"),
+                ("CIK0000789019.json", &facts_json(789019, "MICROSOFT CORP")),
+            ],
+        );
+
+        let mut archive = CompanyFactsArchive::open(&path).unwrap();
+        assert_eq!(archive.len(), 4);
+
+        let results: Vec<_> = archive.iter().collect();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0].as_ref().unwrap().entity_name, "Apple Inc.");
+        assert!(matches!(results[1], Err(EdgarApiError::ParseError(_))));
+        assert_eq!(results[2].as_ref().unwrap().cik, 789019);
+    }
+
+    #[test]
+    fn test_get_by_cik() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("companyfacts.zip");
+        write_archive(
+            &path,
+            &[("CIK0000320193.json", &facts_json(320193, "Apple Inc."))],
+        );
+
+        let mut archive = CompanyFactsArchive::open(&path).unwrap();
+        let facts = archive.get(Cik::new(320193)).unwrap().unwrap();
+        assert_eq!(facts.entity_name, "Apple Inc.");
+        assert!(archive.get(Cik::new(789019)).unwrap().is_none());
+    }
+}
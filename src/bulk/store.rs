@@ -0,0 +1,429 @@
+//! An `EdgarApi` implementation backed by extracted bulk data.
+
+use std::path::{Path, PathBuf};
+
+use async_trait::async_trait;
+use log::trace;
+use serde::de::DeserializeOwned;
+
+use crate::api::EdgarApi;
+use crate::error::{EdgarApiError, Result};
+use crate::http::ProgressCallback;
+use crate::identifiers::{AccessionNumber, Cik};
+use crate::models::{
+    company_concept::CompanyConcept,
+    company_facts::CompanyFacts,
+    company_tickers::CompanyTickers,
+    company_tickers_mf::CompanyTickersMf,
+    current_feed::{CurrentFeed, CurrentFeedQuery},
+    edgar_index::{IndexEntry, IndexKind},
+    filing_index::FilingIndex,
+    frames::XbrlFrames,
+    full_text_search::{FullTextSearchQuery, FullTextSearchResults},
+    submission::{Recent, SubmissionHistory},
+};
+use crate::types::{ApiResponse, BulkDataset, Period, Taxonomy, Unit};
+use crate::utils::download::extract_zip;
+
+/// Serves EDGAR data from the directories produced by
+/// [`download_bulk_submissions`](EdgarApi::download_bulk_submissions) and
+/// [`download_bulk_company_facts`](EdgarApi::download_bulk_company_facts).
+///
+/// Code written against [`EdgarApi`] runs fully offline and reproducibly against
+/// a frozen snapshot. Files missing from the snapshot produce the same `404`
+/// [`EdgarApiError::ApiError`] the live API would return. Endpoints that are not
+/// part of the bulk data (full-text search, filing documents, indexes, the
+/// latest filings feed, frames and ticker lists) return [`EdgarApiError::Unsupported`].
+///
+/// # Example
+///
+/// ```rust,no_run
+/// # use edgar_rs::{Cik, EdgarApi, LocalBulkStore, Taxonomy};
+/// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+/// let store = LocalBulkStore::new()
+///     .with_submissions_dir("snapshots/2024-06-30/submissions")
+///     .with_company_facts_dir("snapshots/2024-06-30/companyfacts");
+///
+/// let submissions = store.get_submissions_history(Cik::new(320193)).await?;
+/// let revenue = store
+///     .get_company_concept(Cik::new(320193), Taxonomy::UsGaap, "Revenues")
+///     .await?;
+/// println!("{}: {} units", submissions.data.name, revenue.data.units.len());
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct LocalBulkStore {
+    submissions_dir: Option<PathBuf>,
+    company_facts_dir: Option<PathBuf>,
+}
+
+impl LocalBulkStore {
+    /// Creates a store without any data directories.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the directory containing the extracted `submissions.zip`.
+    pub fn with_submissions_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.submissions_dir = Some(dir.into());
+        self
+    }
+
+    /// Sets the directory containing the extracted `companyfacts.zip`.
+    pub fn with_company_facts_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.company_facts_dir = Some(dir.into());
+        self
+    }
+
+    /// Reads and deserializes `file_name` from `dir`.
+    fn read<T>(
+        &self,
+        dir: Option<&Path>,
+        dataset: BulkDataset,
+        file_name: &str,
+    ) -> Result<ApiResponse<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let dir = dir.ok_or_else(|| {
+            EdgarApiError::unsupported(format!("No directory configured for {:?} data", dataset))
+        })?;
+        let path = dir.join(file_name);
+        trace!("Reading {}", path.display());
+
+        let bytes = match std::fs::read(&path) {
+            Ok(bytes) => bytes,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+                return Err(EdgarApiError::api(
+                    404,
+                    format!("{} not found in local bulk store", path.display()),
+                ))
+            }
+            Err(e) => return Err(e.into()),
+        };
+
+        let data = serde_json::from_slice(&bytes).map_err(|e| {
+            EdgarApiError::parse(format!("Failed to parse {}: {}", path.display(), e))
+        })?;
+
+        Ok(ApiResponse { status: 200, data })
+    }
+
+    fn read_company_facts(&self, cik: Cik) -> Result<ApiResponse<CompanyFacts>> {
+        self.read(
+            self.company_facts_dir.as_deref(),
+            BulkDataset::CompanyFacts,
+            &format!("CIK{}.json", cik),
+        )
+    }
+}
+
+#[async_trait]
+impl EdgarApi for LocalBulkStore {
+    async fn get_submissions_history(&self, cik: Cik) -> Result<ApiResponse<SubmissionHistory>> {
+        self.read(
+            self.submissions_dir.as_deref(),
+            BulkDataset::Submissions,
+            &format!("CIK{}.json", cik),
+        )
+    }
+
+    async fn get_submissions_file(&self, filename: &str) -> Result<ApiResponse<Recent>> {
+        // Only plain file names, so a crafted name cannot escape the directory
+        if filename.is_empty() || filename.contains(['/', '\\']) || filename.starts_with('.') {
+            return Err(EdgarApiError::request(format!(
+                "Invalid submissions file name: {}",
+                filename
+            )));
+        }
+
+        self.read(
+            self.submissions_dir.as_deref(),
+            BulkDataset::Submissions,
+            filename,
+        )
+    }
+
+    async fn search_full_text(
+        &self,
+        _query: &FullTextSearchQuery,
+    ) -> Result<ApiResponse<FullTextSearchResults>> {
+        Err(EdgarApiError::unsupported(
+            "Full-text search is not part of the bulk data",
+        ))
+    }
+
+    async fn get_filing_index(
+        &self,
+        _cik: Cik,
+        _accession_number: AccessionNumber,
+    ) -> Result<ApiResponse<FilingIndex>> {
+        Err(EdgarApiError::unsupported(
+            "Filing documents are not part of the bulk data",
+        ))
+    }
+
+    async fn get_filing_document(
+        &self,
+        _cik: Cik,
+        _accession_number: AccessionNumber,
+        _document: &str,
+    ) -> Result<ApiResponse<Vec<u8>>> {
+        Err(EdgarApiError::unsupported(
+            "Filing documents are not part of the bulk data",
+        ))
+    }
+
+    async fn get_daily_index(
+        &self,
+        _kind: IndexKind,
+        _date: &str,
+    ) -> Result<ApiResponse<Vec<IndexEntry>>> {
+        Err(EdgarApiError::unsupported(
+            "EDGAR indexes are not part of the bulk data",
+        ))
+    }
+
+    async fn get_quarterly_index(
+        &self,
+        _kind: IndexKind,
+        _year: i32,
+        _quarter: u8,
+    ) -> Result<ApiResponse<Vec<IndexEntry>>> {
+        Err(EdgarApiError::unsupported(
+            "EDGAR indexes are not part of the bulk data",
+        ))
+    }
+
+    async fn get_current_filings(
+        &self,
+        _query: &CurrentFeedQuery,
+    ) -> Result<ApiResponse<CurrentFeed>> {
+        Err(EdgarApiError::unsupported(
+            "The latest filings feed is not part of the bulk data",
+        ))
+    }
+
+    async fn get_company_concept(
+        &self,
+        cik: Cik,
+        taxonomy: Taxonomy,
+        tag: &str,
+    ) -> Result<ApiResponse<CompanyConcept>> {
+        let facts = self.read_company_facts(cik)?;
+        let concept = facts
+            .data
+            .to_company_concept(taxonomy.as_str(), tag)
+            .ok_or_else(|| {
+                EdgarApiError::api(
+                    404,
+                    format!(
+                        "{}:{} not found for CIK {} in local bulk store",
+                        taxonomy.as_str(),
+                        tag,
+                        cik
+                    ),
+                )
+            })?;
+
+        Ok(ApiResponse {
+            status: facts.status,
+            data: concept,
+        })
+    }
+
+    async fn get_company_facts(&self, cik: Cik) -> Result<ApiResponse<CompanyFacts>> {
+        self.read_company_facts(cik)
+    }
+
+    async fn get_xbrl_frames(
+        &self,
+        _taxonomy: Taxonomy,
+        _tag: &str,
+        _unit: Unit,
+        _period: Period,
+    ) -> Result<ApiResponse<XbrlFrames>> {
+        Err(EdgarApiError::unsupported(
+            "XBRL frames are not part of the bulk data",
+        ))
+    }
+
+    async fn get_company_tickers(&self) -> Result<ApiResponse<CompanyTickers>> {
+        Err(EdgarApiError::unsupported(
+            "Company tickers are not part of the bulk data",
+        ))
+    }
+
+    async fn get_company_tickers_mf(&self) -> Result<ApiResponse<CompanyTickersMf>> {
+        Err(EdgarApiError::unsupported(
+            "Mutual fund tickers are not part of the bulk data",
+        ))
+    }
+
+    async fn download_bulk_submissions(&self, _output_path: &str) -> Result<()> {
+        Err(EdgarApiError::unsupported(
+            "LocalBulkStore does not download data",
+        ))
+    }
+
+    async fn download_bulk_company_facts(&self, _output_path: &str) -> Result<()> {
+        Err(EdgarApiError::unsupported(
+            "LocalBulkStore does not download data",
+        ))
+    }
+
+    async fn download_bulk_archive(
+        &self,
+        _dataset: BulkDataset,
+        _zip_path: &Path,
+        _progress: Option<&ProgressCallback<'_>>,
+    ) -> Result<u64> {
+        Err(EdgarApiError::unsupported(
+            "LocalBulkStore does not download data",
+        ))
+    }
+
+    async fn extract_zip_files(&self, zip_path: &Path, output_dir: &Path) -> Result<()> {
+        extract_zip(zip_path, output_dir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn create_store() -> (tempfile::TempDir, LocalBulkStore) {
+        let dir = tempfile::tempdir().unwrap();
+        let submissions_dir = dir.path().join("submissions");
+        let facts_dir = dir.path().join("companyfacts");
+        std::fs::create_dir_all(&submissions_dir).unwrap();
+        std::fs::create_dir_all(&facts_dir).unwrap();
+
+        let submissions = json!({
+            "cik": "320193",
+            "name": "Apple Inc.",
+            "filings": {
+                "recent": {
+                    "accessionNumber": ["0000320193-23-000106"],
+                    "filingDate": ["2023-11-03"],
+                    "form": ["10-K"]
+                },
+                "files": [{
+                    "name": "CIK0000320193-submissions-001.json",
+                    "filingCount": 1,
+                    "filingFrom": "1994-01-26",
+                    "filingTo": "2002-12-19"
+                }]
+            }
+        });
+        let older = json!({
+            "accessionNumber": ["0000320193-02-000001"],
+            "filingDate": ["2002-12-19"],
+            "form": ["10-K"]
+        });
+        let facts = json!({
+            "cik": 320193,
+            "entityName": "Apple Inc.",
+            "facts": {
+                "us-gaap": {
+                    "Revenues": {
+                        "label": "Revenues",
+                        "description": "Revenue",
+                        "units": {
+                            "USD": [{
+                                "start": "2022-09-25",
+                                "end": "2023-09-30",
+                                "val": 383285000000u64,
+                                "accn": "0000320193-23-000106",
+                                "fy": 2023,
+                                "fp": "FY",
+                                "form": "10-K",
+                                "filed": "2023-11-03"
+                            }]
+                        }
+                    }
+                }
+            }
+        });
+
+        std::fs::write(
+            submissions_dir.join("CIK0000320193.json"),
+            submissions.to_string(),
+        )
+        .unwrap();
+        std::fs::write(
+            submissions_dir.join("CIK0000320193-submissions-001.json"),
+            older.to_string(),
+        )
+        .unwrap();
+        std::fs::write(facts_dir.join("CIK0000320193.json"), facts.to_string()).unwrap();
+
+        let store = LocalBulkStore::new()
+            .with_submissions_dir(submissions_dir)
+            .with_company_facts_dir(facts_dir);
+        (dir, store)
+    }
+
+    #[tokio::test]
+    async fn test_serves_submissions_including_paginated_files() {
+        let (_dir, store) = create_store();
+
+        let submissions = store.get_submissions_history(Cik::new(320193)).await.unwrap();
+        assert_eq!(submissions.status, 200);
+        assert_eq!(submissions.data.name, "Apple Inc.");
+
+        let all_filings = submissions.data.get_all_filings(&store).await.unwrap();
+        assert_eq!(all_filings.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_serves_company_facts_and_concepts() {
+        let (_dir, store) = create_store();
+
+        let facts = store.get_company_facts(Cik::new(320193)).await.unwrap();
+        assert_eq!(facts.data.cik, 320193);
+
+        let concept = store
+            .get_company_concept(Cik::new(320193), Taxonomy::UsGaap, "Revenues")
+            .await
+            .unwrap();
+        assert_eq!(concept.data.label, "Revenues");
+        assert_eq!(concept.data.units["USD"][0].val, 383285000000.0);
+    }
+
+    #[tokio::test]
+    async fn test_missing_data_is_not_found() {
+        let (_dir, store) = create_store();
+
+        let error = store.get_company_facts(Cik::new(789019)).await.unwrap_err();
+        assert!(matches!(error, EdgarApiError::ApiError { status: 404, .. }));
+
+        let error = store
+            .get_company_concept(Cik::new(320193), Taxonomy::UsGaap, "NoSuchTag")
+            .await
+            .unwrap_err();
+        assert!(matches!(error, EdgarApiError::ApiError { status: 404, .. }));
+    }
+
+    #[tokio::test]
+    async fn test_rejects_unsupported_and_invalid_requests() {
+        let (_dir, store) = create_store();
+
+        let error = store.get_company_tickers().await.unwrap_err();
+        assert!(matches!(error, EdgarApiError::Unsupported(_)));
+
+        let error = store
+            .get_submissions_file("../secret.json")
+            .await
+            .unwrap_err();
+        assert!(matches!(error, EdgarApiError::RequestError(_)));
+
+        let error = LocalBulkStore::new()
+            .get_company_facts(Cik::new(320193))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, EdgarApiError::Unsupported(_)));
+    }
+}
@@ -7,23 +7,40 @@ use crate::api::EdgarApi;
 use crate::config::Config;
 use crate::error::{EdgarApiError, Result};
 #[cfg(feature = "native")]
-use crate::http::HttpClient;
-#[cfg(feature = "cloudflare-workers")]
-use crate::http::HttpClient;
+use crate::http::ProgressCallback;
+use crate::http::{HttpClient, HttpResponse};
+use crate::identifiers::{AccessionNumber, Cik};
 use crate::models::{
     company_concept::CompanyConcept,
     company_facts::CompanyFacts,
     company_tickers::CompanyTickers,
     company_tickers_mf::CompanyTickersMf,
+    current_feed::{CurrentFeed, CurrentFeedQuery},
+    edgar_index::{IndexEntry, IndexKind},
+    filing_index::FilingIndex,
     frames::XbrlFrames,
+    full_text_search::{FullTextSearchQuery, FullTextSearchResults},
     submission::{Recent, SubmissionHistory},
 };
+use crate::rate_limit::RateLimiter;
+use crate::resolver::CikResolver;
+use crate::runtime;
+#[cfg(feature = "native")]
+use crate::types::BulkDataset;
 use crate::types::{ApiResponse, Period, Taxonomy, Unit};
-use crate::utils::cik::format_cik;
+use crate::utils::date::parse_ymd;
 #[cfg(feature = "native")]
-use crate::utils::download::{extract_zip, write_temp_file};
+use crate::utils::download::{create_temp_file, extract_zip};
 use async_trait::async_trait;
-use log::{error, trace};
+use futures::lock::Mutex;
+use log::{debug, error, trace, warn};
+use std::future::Future;
+use std::sync::Arc;
+use std::time::Duration;
+#[cfg(feature = "native")]
+use std::fs::File;
+#[cfg(feature = "native")]
+use std::io::{BufWriter, Write};
 #[cfg(feature = "native")]
 use std::path::Path;
 
@@ -35,20 +52,43 @@ use std::path::Path;
 /// # Example
 ///
 /// ```rust,no_run
-/// use edgar_rs::{EdgarApi, EdgarClient, Config};
+/// use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<(), Box<dyn std::error::Error>> {
 ///     let config = Config::new("Your Company Name your.email@example.com");
 ///     let edgar_api = EdgarClient::new(config)?;
-///     let submissions = edgar_api.get_submissions_history("0000320193").await?;
+///     let submissions = edgar_api.get_submissions_history(Cik::new(320193)).await?;
 ///     println!("Company name: {}", submissions.data.name);
 ///     Ok(())
 /// }
 /// ```
+///
+/// Requests are throttled according to [`Config::rate_limit`]. Clones of a client
+/// share the same rate limiter, so fan-out jobs stay within SEC's limit as a whole.
+/// They also share the [`CikResolver`] built by [`EdgarClient::resolve_cik`].
+#[derive(Clone)]
 pub struct EdgarClient<H: HttpClient> {
     http_client: H,
     config: Config,
+    rate_limiter: RateLimiter,
+    cik_resolver: Arc<Mutex<Option<CachedResolver>>>,
+}
+
+/// A resolver and when its ticker lists were fetched, `None` for one passed to
+/// [`EdgarClient::with_cik_resolver`], which never expires.
+struct CachedResolver {
+    resolver: Arc<CikResolver>,
+    fetched_at: Option<Duration>,
+}
+
+impl CachedResolver {
+    fn is_fresh(&self, ttl: Duration) -> bool {
+        match self.fetched_at {
+            Some(fetched_at) => runtime::now().saturating_sub(fetched_at) < ttl,
+            None => true,
+        }
+    }
 }
 
 impl<H: HttpClient> EdgarClient<H> {
@@ -61,11 +101,25 @@ impl<H: HttpClient> EdgarClient<H> {
     pub fn with_client(http_client: H, config: Config) -> Self {
         Self {
             http_client,
+            rate_limiter: RateLimiter::new(config.rate_limit),
             config,
+            cik_resolver: Arc::new(Mutex::new(None)),
         }
     }
 
-    /// Makes a GET request to the specified URL.
+    /// Uses `resolver` for identifier lookups instead of fetching the ticker lists.
+    ///
+    /// The resolver doesn't expire; [`EdgarClient::refresh_cik_resolver`] replaces
+    /// it with the current lists.
+    pub fn with_cik_resolver(mut self, resolver: CikResolver) -> Self {
+        self.cik_resolver = Arc::new(Mutex::new(Some(CachedResolver {
+            resolver: Arc::new(resolver),
+            fetched_at: None,
+        })));
+        self
+    }
+
+    /// Makes a GET request to the specified URL and parses the JSON response.
     async fn get<T>(&self, url: &str) -> Result<ApiResponse<T>>
     where
         T: serde::de::DeserializeOwned,
@@ -75,32 +129,9 @@ impl<H: HttpClient> EdgarClient<H> {
 
         let headers = [("User-Agent", self.config.user_agent.as_str())];
 
-        let response = self.http_client.get(&final_url, &headers).await?;
+        let response = self.send(&final_url, &headers).await?;
         let status = response.status;
 
-        // Handle rate limiting
-        if status == 429 {
-            let retry_after = response
-                .headers
-                .get("retry-after")
-                .and_then(|s| s.parse::<u64>().ok());
-
-            error!(
-                "Rate limited by API (status 429). Retry-After: {:?}",
-                retry_after
-            );
-            return Err(EdgarApiError::rate_limit(retry_after));
-        }
-
-        // Handle other errors
-        if !response.is_success() {
-            error!("Request to {} failed with status {}", final_url, status);
-            return Err(EdgarApiError::api(
-                status,
-                format!("Request to {} failed with status {}", final_url, status),
-            ));
-        }
-
         // Parse response
         trace!("Parsing JSON response from {}", final_url);
         let data = response.json::<T>()?;
@@ -108,6 +139,320 @@ impl<H: HttpClient> EdgarClient<H> {
         trace!("Successfully parsed response from {}", final_url);
         Ok(ApiResponse { status, data })
     }
+
+    /// Makes a GET request to the specified URL and returns the raw response body.
+    async fn get_bytes(&self, url: &str) -> Result<ApiResponse<Vec<u8>>> {
+        let final_url = self.config.build_url(url);
+        trace!("Starting download of {}", final_url);
+
+        let headers = [("User-Agent", self.config.user_agent.as_str())];
+
+        let response = self.send(&final_url, &headers).await?;
+        Ok(ApiResponse {
+            status: response.status,
+            data: response.body,
+        })
+    }
+
+    /// Fetches an EDGAR index file and parses its entries.
+    async fn get_index(&self, url: &str, kind: IndexKind) -> Result<ApiResponse<Vec<IndexEntry>>> {
+        let response = self.get_bytes(url).await?;
+
+        let entries = kind.parse_bytes(&response.data)?;
+        trace!("Parsed {} index entries from {}", entries.len(), url);
+
+        Ok(ApiResponse {
+            status: response.status,
+            data: entries,
+        })
+    }
+
+    /// Sends a GET request, applying the rate limit and retry policy.
+    ///
+    /// Non-success statuses are turned into errors so that transient ones can be retried.
+    /// Responses the HTTP client serves from its cache don't wait for the rate limiter.
+    async fn send(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+        self.retrying(url, || async {
+            if let Some(response) = self.http_client.get_cached(url) {
+                return check_status(url, response);
+            }
+            self.rate_limiter.acquire().await;
+            let response = self.http_client.get(url, headers).await?;
+            check_status(url, response)
+        })
+        .await
+    }
+
+    /// Runs `operation` until it succeeds, fails permanently, or the retry policy gives up.
+    ///
+    /// Each attempt must wait for the rate limiter itself before it sends a request.
+    /// Failed attempts that are retried are logged as warnings and only the final
+    /// failure as an error.
+    async fn retrying<T, F, Fut>(&self, url: &str, mut operation: F) -> Result<T>
+    where
+        F: FnMut() -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let policy = &self.config.retry_policy;
+        let mut attempt = 1;
+
+        loop {
+            match operation().await {
+                Err(e) if policy.should_retry(attempt, &e) => {
+                    let delay = policy.delay_for(attempt, &e);
+                    warn!(
+                        "Attempt {}/{} for {} failed: {}. Retrying in {:?}",
+                        attempt, policy.max_attempts, url, e, delay
+                    );
+                    runtime::sleep(delay).await;
+                    attempt += 1;
+                }
+                Err(e) => {
+                    error!(
+                        "Request to {} failed after {} attempt(s): {}",
+                        url, attempt, e
+                    );
+                    return Err(e);
+                }
+                result => return result,
+            }
+        }
+    }
+}
+
+impl<H: HttpClient> EdgarClient<H> {
+    /// Returns the resolver used for identifier lookups.
+    ///
+    /// The exchange and mutual fund ticker lists are fetched on first use, shared
+    /// by the client and its clones, and fetched again once they are older than
+    /// [`Config::cik_resolver_ttl`]. Concurrent lookups wait for a single download.
+    /// If fetching fresh lists fails, the expired resolver keeps being used.
+    pub async fn cik_resolver(&self) -> Result<Arc<CikResolver>> {
+        // The lock is held while fetching so that only one task downloads the lists
+        let mut cached = self.cik_resolver.lock().await;
+        let stale = match cached.as_ref() {
+            Some(entry) if entry.is_fresh(self.config.cik_resolver_ttl) => {
+                return Ok(entry.resolver.clone());
+            }
+            entry => entry.map(|entry| entry.resolver.clone()),
+        };
+
+        match self.fetch_cik_resolver().await {
+            Ok(entry) => {
+                let resolver = entry.resolver.clone();
+                *cached = Some(entry);
+                Ok(resolver)
+            }
+            Err(e) => match stale {
+                Some(resolver) => {
+                    warn!(
+                        "Failed to refresh the CIK resolver, keeping the expired one: {}",
+                        e
+                    );
+                    Ok(resolver)
+                }
+                None => Err(e),
+            },
+        }
+    }
+
+    /// Fetches the ticker lists again and replaces the resolver used for
+    /// identifier lookups, including one passed to [`EdgarClient::with_cik_resolver`].
+    pub async fn refresh_cik_resolver(&self) -> Result<Arc<CikResolver>> {
+        let mut cached = self.cik_resolver.lock().await;
+        let entry = self.fetch_cik_resolver().await?;
+        let resolver = entry.resolver.clone();
+        *cached = Some(entry);
+        Ok(resolver)
+    }
+
+    async fn fetch_cik_resolver(&self) -> Result<CachedResolver> {
+        trace!("Building CIK resolver from the company ticker lists");
+        Ok(CachedResolver {
+            resolver: Arc::new(CikResolver::fetch(self).await?),
+            fetched_at: Some(runtime::now()),
+        })
+    }
+
+    /// Resolves a CIK, ticker, mutual fund symbol or company name to a CIK.
+    ///
+    /// See [`CikResolver`] for the accepted identifiers.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{EdgarClient, Config};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let cik = edgar_api.resolve_cik("BRK.B").await?;
+    /// println!("Berkshire Hathaway has CIK {}", cik);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn resolve_cik(&self, identifier: &str) -> Result<Cik> {
+        // Plain CIKs don't need the ticker lists
+        if let Ok(cik) = identifier.parse() {
+            return Ok(cik);
+        }
+        self.cik_resolver().await?.resolve(identifier)
+    }
+
+    /// Like [`EdgarApi::get_submissions_history`], accepting any identifier
+    /// understood by [`EdgarClient::resolve_cik`].
+    pub async fn get_submissions_history_by_identifier(
+        &self,
+        identifier: &str,
+    ) -> Result<ApiResponse<SubmissionHistory>> {
+        let cik = self.resolve_cik(identifier).await?;
+        self.get_submissions_history(cik).await
+    }
+
+    /// Like [`EdgarApi::get_filing_index`], accepting any identifier
+    /// understood by [`EdgarClient::resolve_cik`].
+    pub async fn get_filing_index_by_identifier(
+        &self,
+        identifier: &str,
+        accession_number: AccessionNumber,
+    ) -> Result<ApiResponse<FilingIndex>> {
+        let cik = self.resolve_cik(identifier).await?;
+        self.get_filing_index(cik, accession_number)
+            .await
+    }
+
+    /// Like [`EdgarApi::get_filing_document`], accepting any identifier
+    /// understood by [`EdgarClient::resolve_cik`].
+    pub async fn get_filing_document_by_identifier(
+        &self,
+        identifier: &str,
+        accession_number: AccessionNumber,
+        document: &str,
+    ) -> Result<ApiResponse<Vec<u8>>> {
+        let cik = self.resolve_cik(identifier).await?;
+        self.get_filing_document(cik, accession_number, document)
+            .await
+    }
+
+    /// Like [`EdgarApi::get_company_concept`], accepting any identifier
+    /// understood by [`EdgarClient::resolve_cik`].
+    pub async fn get_company_concept_by_identifier(
+        &self,
+        identifier: &str,
+        taxonomy: Taxonomy,
+        tag: &str,
+    ) -> Result<ApiResponse<CompanyConcept>> {
+        let cik = self.resolve_cik(identifier).await?;
+        self.get_company_concept(cik, taxonomy, tag)
+            .await
+    }
+
+    /// Like [`EdgarApi::get_company_facts`], accepting any identifier
+    /// understood by [`EdgarClient::resolve_cik`].
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{EdgarClient, Config};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let facts = edgar_api.get_company_facts_by_identifier("AAPL").await?;
+    /// println!("Entity name: {}", facts.data.entity_name);
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn get_company_facts_by_identifier(
+        &self,
+        identifier: &str,
+    ) -> Result<ApiResponse<CompanyFacts>> {
+        let cik = self.resolve_cik(identifier).await?;
+        self.get_company_facts(cik).await
+    }
+}
+
+/// Converts rate limiting and other non-success responses into errors.
+fn check_status(url: &str, response: HttpResponse) -> Result<HttpResponse> {
+    let status = response.status;
+
+    // Handle rate limiting
+    if status == 429 {
+        let retry_after = response
+            .headers
+            .get("retry-after")
+            .and_then(|s| s.parse::<u64>().ok());
+
+        debug!(
+            "Rate limited by API (status 429). Retry-After: {:?}",
+            retry_after
+        );
+        return Err(EdgarApiError::rate_limit(retry_after));
+    }
+
+    // Handle other errors
+    if !response.is_success() {
+        debug!("Request to {} failed with status {}", url, status);
+        return Err(EdgarApiError::api(
+            status,
+            format!("Request to {} failed with status {}", url, status),
+        ));
+    }
+
+    Ok(response)
+}
+
+/// Builds the URL of the daily index for `date` (YYYY-MM-DD).
+fn daily_index_url(kind: IndexKind, date: &str) -> Result<String> {
+    let (year, month, day) = parse_ymd(date).ok_or_else(|| {
+        EdgarApiError::request(format!("Invalid index date, expected YYYY-MM-DD: {}", date))
+    })?;
+    Ok(format!(
+        "https://www.sec.gov/Archives/edgar/daily-index/{}/QTR{}/{}.{:04}{:02}{:02}.idx",
+        year,
+        (month - 1) / 3 + 1,
+        kind.as_str(),
+        year,
+        month,
+        day
+    ))
+}
+
+/// Builds the URL of the full index for a calendar quarter.
+fn quarterly_index_url(kind: IndexKind, year: i32, quarter: u8) -> Result<String> {
+    if !(1..=4).contains(&quarter) {
+        return Err(EdgarApiError::request(format!(
+            "Invalid quarter {}, expected 1-4",
+            quarter
+        )));
+    }
+    Ok(format!(
+        "https://www.sec.gov/Archives/edgar/full-index/{}/QTR{}/{}.idx",
+        year,
+        quarter,
+        kind.as_str()
+    ))
+}
+
+/// Builds the Archives URL of a document within a filing.
+fn filing_document_url(
+    cik: Cik,
+    accession_number: AccessionNumber,
+    document: &str,
+) -> Result<String> {
+    // Documents live in the filing directory; don't let a crafted name leave it
+    if document.is_empty()
+        || document.starts_with('/')
+        || document.split('/').any(|part| part == "..")
+    {
+        return Err(EdgarApiError::request(format!(
+            "Invalid filing document name: {}",
+            document
+        )));
+    }
+    Ok(format!(
+        "{}{}",
+        accession_number.directory_url(cik),
+        document
+    ))
 }
 
 // Native specific implementations
@@ -134,10 +479,24 @@ impl EdgarClient<crate::http::ReqwestClient> {
         use crate::http::ReqwestClient;
 
         let http_client = ReqwestClient::new()?;
-        Ok(Self {
-            http_client,
-            config,
-        })
+        Ok(Self::with_client(http_client, config))
+    }
+}
+
+#[cfg(feature = "native")]
+impl<H: HttpClient> EdgarClient<H> {
+    /// Streams a bulk archive to a temporary file and extracts it into `output_dir`.
+    async fn download_and_extract(&self, dataset: BulkDataset, output_dir: &Path) -> Result<()> {
+        let temp_file = create_temp_file()?;
+        let result = match self.download_bulk_archive(dataset, &temp_file, None).await {
+            Ok(_) => extract_zip(&temp_file, output_dir),
+            Err(e) => Err(e),
+        };
+
+        if let Err(e) = std::fs::remove_file(&temp_file) {
+            warn!("Failed to remove temp file {}: {}", temp_file.display(), e);
+        }
+        result
     }
 }
 
@@ -153,20 +512,35 @@ impl EdgarClient<crate::http::WorkerClient> {
         use crate::http::WorkerClient;
 
         let http_client = WorkerClient::new();
-        Self {
-            http_client,
-            config,
-        }
+        Self::with_client(http_client, config)
     }
 }
 
-#[async_trait]
-#[cfg(feature = "native")]
+// Browser specific implementations
+#[cfg(all(feature = "wasm-browser", not(feature = "native")))]
+impl EdgarClient<crate::http::BrowserClient> {
+    /// Creates a new `EdgarClient` instance for browsers.
+    ///
+    /// SEC doesn't allow cross-origin requests, so set `config.base_url` to a CORS
+    /// proxy; see [`BrowserClient`](crate::BrowserClient).
+    ///
+    /// # Parameters
+    ///
+    /// * `config` - Configuration including user agent and base URL settings.
+    pub fn new_browser(config: Config) -> Self {
+        use crate::http::BrowserClient;
+
+        let http_client = BrowserClient::new();
+        Self::with_client(http_client, config)
+    }
+}
+
+#[cfg_attr(feature = "native", async_trait)]
+#[cfg_attr(not(feature = "native"), async_trait(?Send))]
 impl<H: HttpClient> EdgarApi for EdgarClient<H> {
-    async fn get_submissions_history(&self, cik: &str) -> Result<ApiResponse<SubmissionHistory>> {
-        let formatted_cik = format_cik(cik)?;
-        let url = format!("https://data.sec.gov/submissions/CIK{}.json", formatted_cik);
-        trace!("Fetching submissions history for CIK: {}", formatted_cik);
+    async fn get_submissions_history(&self, cik: Cik) -> Result<ApiResponse<SubmissionHistory>> {
+        let url = format!("https://data.sec.gov/submissions/CIK{}.json", cik);
+        trace!("Fetching submissions history for CIK: {}", cik);
 
         self.get(&url).await
     }
@@ -178,22 +552,90 @@ impl<H: HttpClient> EdgarApi for EdgarClient<H> {
         self.get(&url).await
     }
 
+    async fn search_full_text(
+        &self,
+        query: &FullTextSearchQuery,
+    ) -> Result<ApiResponse<FullTextSearchResults>> {
+        let url = query.url()?;
+        trace!("Searching full text: {}", query.query);
+
+        self.get(&url).await
+    }
+
+    async fn get_filing_index(
+        &self,
+        cik: Cik,
+        accession_number: AccessionNumber,
+    ) -> Result<ApiResponse<FilingIndex>> {
+        let url = format!("{}index.json", accession_number.directory_url(cik));
+        trace!("Fetching filing index for accession number: {}", accession_number);
+
+        self.get(&url).await
+    }
+
+    async fn get_filing_document(
+        &self,
+        cik: Cik,
+        accession_number: AccessionNumber,
+        document: &str,
+    ) -> Result<ApiResponse<Vec<u8>>> {
+        let url = filing_document_url(cik, accession_number, document)?;
+        trace!("Fetching filing document: {}", url);
+
+        self.get_bytes(&url).await
+    }
+
+    async fn get_daily_index(
+        &self,
+        kind: IndexKind,
+        date: &str,
+    ) -> Result<ApiResponse<Vec<IndexEntry>>> {
+        let url = daily_index_url(kind, date)?;
+        trace!("Fetching daily {} index for {}", kind, date);
+
+        self.get_index(&url, kind).await
+    }
+
+    async fn get_quarterly_index(
+        &self,
+        kind: IndexKind,
+        year: i32,
+        quarter: u8,
+    ) -> Result<ApiResponse<Vec<IndexEntry>>> {
+        let url = quarterly_index_url(kind, year, quarter)?;
+        trace!("Fetching {} index for {} Q{}", kind, year, quarter);
+
+        self.get_index(&url, kind).await
+    }
+
+    async fn get_current_filings(
+        &self,
+        query: &CurrentFeedQuery,
+    ) -> Result<ApiResponse<CurrentFeed>> {
+        let response = self.get_bytes(&query.url()).await?;
+        trace!("Parsing latest filings feed");
+
+        Ok(ApiResponse {
+            status: response.status,
+            data: CurrentFeed::parse_bytes(&response.data)?,
+        })
+    }
+
     async fn get_company_concept(
         &self,
-        cik: &str,
+        cik: Cik,
         taxonomy: Taxonomy,
         tag: &str,
     ) -> Result<ApiResponse<CompanyConcept>> {
-        let formatted_cik = format_cik(cik)?;
         let url = format!(
             "https://data.sec.gov/api/xbrl/companyconcept/CIK{}/{}/{}.json",
-            formatted_cik,
+            cik,
             taxonomy.as_str(),
             tag
         );
         trace!(
             "Fetching company concept for CIK: {}, taxonomy: {}, tag: {}",
-            formatted_cik,
+            cik,
             taxonomy.as_str(),
             tag
         );
@@ -201,13 +643,12 @@ impl<H: HttpClient> EdgarApi for EdgarClient<H> {
         self.get(&url).await
     }
 
-    async fn get_company_facts(&self, cik: &str) -> Result<ApiResponse<CompanyFacts>> {
-        let formatted_cik = format_cik(cik)?;
+    async fn get_company_facts(&self, cik: Cik) -> Result<ApiResponse<CompanyFacts>> {
         let url = format!(
             "https://data.sec.gov/api/xbrl/companyfacts/CIK{}.json",
-            formatted_cik
+            cik
         );
-        trace!("Fetching company facts for CIK: {}", formatted_cik);
+        trace!("Fetching company facts for CIK: {}", cik);
 
         self.get(&url).await
     }
@@ -251,164 +692,419 @@ impl<H: HttpClient> EdgarApi for EdgarClient<H> {
         self.get(url).await
     }
 
+    #[cfg(feature = "native")]
     async fn download_bulk_submissions(&self, output_path: &str) -> Result<()> {
-        let url = "https://www.sec.gov/Archives/edgar/daily-index/bulkdata/submissions.zip";
-        let final_url = self.config.build_url(url);
+        self.download_and_extract(BulkDataset::Submissions, Path::new(output_path))
+            .await?;
+        trace!("Extracted bulk submissions to: {}", output_path);
 
-        trace!("Downloading bulk submissions from: {}", final_url);
+        Ok(())
+    }
+
+    #[cfg(feature = "native")]
+    async fn download_bulk_company_facts(&self, output_path: &str) -> Result<()> {
+        self.download_and_extract(BulkDataset::CompanyFacts, Path::new(output_path))
+            .await?;
+        trace!("Extracted bulk company facts to: {}", output_path);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "native")]
+    async fn download_bulk_archive(
+        &self,
+        dataset: BulkDataset,
+        zip_path: &Path,
+        progress: Option<&ProgressCallback<'_>>,
+    ) -> Result<u64> {
+        let final_url = self.config.build_url(dataset.url());
+        trace!("Downloading {:?} archive from: {}", dataset, final_url);
 
         let headers = [
             ("User-Agent", self.config.user_agent.as_str()),
             ("Accept", "application/zip"),
         ];
 
-        // Download the ZIP file
-        let data = self.http_client.get_bytes(&final_url, &headers).await?;
-        trace!("Downloaded bulk submissions: {} bytes", data.len());
+        let downloaded = self
+            .retrying(&final_url, || async {
+                self.rate_limiter.acquire().await;
+                // Start from an empty file on every attempt
+                let mut writer = BufWriter::new(File::create(zip_path)?);
+                let downloaded = self
+                    .http_client
+                    .download(&final_url, &headers, &mut writer, progress)
+                    .await?;
+                writer.flush()?;
+                Ok(downloaded)
+            })
+            .await?;
 
-        // Write to temporary file
-        let temp_file = write_temp_file(&data)?;
         trace!(
-            "Wrote bulk submissions to temp file: {}",
-            temp_file.display()
+            "Downloaded {:?} archive to {}: {} bytes",
+            dataset,
+            zip_path.display(),
+            downloaded
         );
+        Ok(downloaded)
+    }
 
-        // Extract the ZIP file
-        extract_zip(&temp_file, Path::new(output_path))?;
-        trace!("Extracted bulk submissions to: {}", output_path);
+    #[cfg(feature = "native")]
+    async fn extract_zip_files(&self, zip_path: &Path, output_dir: &Path) -> Result<()> {
+        extract_zip(zip_path, output_dir)
+    }
+}
 
-        Ok(())
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use super::*;
+    use crate::http::{CachePolicy, CachingClient, DownloadProgress};
+    use crate::rate_limit::RateLimit;
+    use crate::retry::RetryPolicy;
+    use std::collections::{HashMap, VecDeque};
+    use std::sync::Mutex;
+    use std::time::{Duration, Instant};
+
+    /// HTTP client that replays a fixed sequence of results.
+    struct ScriptedClient {
+        results: Mutex<VecDeque<Result<HttpResponse>>>,
+        calls: Mutex<u32>,
     }
 
-    async fn download_bulk_company_facts(&self, output_path: &str) -> Result<()> {
-        let url = "https://www.sec.gov/Archives/edgar/daily-index/bulkdata/companyfacts.zip";
-        let final_url = self.config.build_url(url);
+    impl ScriptedClient {
+        fn new(results: Vec<Result<HttpResponse>>) -> Self {
+            Self {
+                results: Mutex::new(results.into()),
+                calls: Mutex::new(0),
+            }
+        }
+    }
 
-        trace!("Downloading bulk company facts from: {}", final_url);
+    #[async_trait]
+    impl HttpClient for ScriptedClient {
+        async fn get(&self, _url: &str, _headers: &[(&str, &str)]) -> Result<HttpResponse> {
+            *self.calls.lock().unwrap() += 1;
+            self.results
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("unexpected request")
+        }
+    }
 
-        let headers = [
-            ("User-Agent", self.config.user_agent.as_str()),
-            ("Accept", "application/zip"),
-        ];
+    fn response(status: u16, body: &str) -> Result<HttpResponse> {
+        Ok(HttpResponse {
+            status,
+            headers: HashMap::new(),
+            body: body.as_bytes().to_vec(),
+        })
+    }
+
+    fn test_config(max_attempts: u32) -> Config {
+        Config::new("edgar-rs tests test@example.com")
+            .without_rate_limit()
+            .with_retry_policy(
+                RetryPolicy::new(max_attempts)
+                    .with_base_delay(Duration::from_millis(1))
+                    .with_jitter(false),
+            )
+    }
 
-        // Download the ZIP file
-        let data = self.http_client.get_bytes(&final_url, &headers).await?;
-        trace!("Downloaded bulk company facts: {} bytes", data.len());
+    #[tokio::test]
+    async fn test_get_retries_transient_errors() {
+        let http_client = ScriptedClient::new(vec![
+            Err(EdgarApiError::network("connection reset")),
+            response(503, ""),
+            response(200, r#"{"fields": [], "data": []}"#),
+        ]);
+        let client = EdgarClient::with_client(http_client, test_config(3));
 
-        // Write to temporary file
-        let temp_file = write_temp_file(&data)?;
-        trace!(
-            "Wrote bulk company facts to temp file: {}",
-            temp_file.display()
-        );
+        let tickers = client.get_company_tickers().await.unwrap();
+        assert_eq!(tickers.status, 200);
+        assert_eq!(*client.http_client.calls.lock().unwrap(), 3);
+    }
 
-        // Extract the ZIP file
-        extract_zip(&temp_file, Path::new(output_path))?;
-        trace!("Extracted bulk company facts to: {}", output_path);
+    #[tokio::test]
+    async fn test_get_gives_up_after_max_attempts() {
+        let http_client = ScriptedClient::new(vec![response(500, ""), response(500, "")]);
+        let client = EdgarClient::with_client(http_client, test_config(2));
 
-        Ok(())
+        let error = client.get_company_tickers().await.unwrap_err();
+        assert!(matches!(error, EdgarApiError::ApiError { status: 500, .. }));
+        assert_eq!(*client.http_client.calls.lock().unwrap(), 2);
     }
 
-    async fn extract_zip_files(&self, zip_path: &Path, output_dir: &Path) -> Result<()> {
-        extract_zip(zip_path, output_dir)
+    #[tokio::test]
+    async fn test_get_does_not_retry_permanent_errors() {
+        let http_client = ScriptedClient::new(vec![response(404, "")]);
+        let client = EdgarClient::with_client(http_client, test_config(3));
+
+        let error = client.get_company_tickers().await.unwrap_err();
+        assert!(matches!(error, EdgarApiError::ApiError { status: 404, .. }));
+        assert_eq!(*client.http_client.calls.lock().unwrap(), 1);
     }
-}
 
-#[async_trait(?Send)]
-#[cfg(feature = "cloudflare-workers")]
-impl<H: HttpClient> EdgarApi for EdgarClient<H> {
-    async fn get_submissions_history(&self, cik: &str) -> Result<ApiResponse<SubmissionHistory>> {
-        let formatted_cik = format_cik(cik).map_err(|_| EdgarApiError::invalid_cik(cik))?;
-        let url = format!("https://data.sec.gov/submissions/CIK{}.json", formatted_cik);
-        self.get(&url).await
+    #[tokio::test]
+    async fn test_get_retries_rate_limited_responses() {
+        let mut headers = HashMap::new();
+        headers.insert("retry-after".to_string(), "0".to_string());
+        let http_client = ScriptedClient::new(vec![
+            Ok(HttpResponse {
+                status: 429,
+                headers,
+                body: Vec::new(),
+            }),
+            response(200, r#"{"fields": [], "data": []}"#),
+        ]);
+        let client = EdgarClient::with_client(http_client, test_config(2));
+
+        assert!(client.get_company_tickers().await.is_ok());
+        assert_eq!(*client.http_client.calls.lock().unwrap(), 2);
     }
 
-    async fn get_submissions_file(&self, filename: &str) -> Result<ApiResponse<Recent>> {
-        let url = format!("https://data.sec.gov/submissions/{}", filename);
-        self.get(&url).await
+    fn throttled_cache(
+        results: Vec<Result<HttpResponse>>,
+        policy: CachePolicy,
+    ) -> EdgarClient<CachingClient<ScriptedClient>> {
+        let http_client =
+            CachingClient::in_memory(ScriptedClient::new(results)).with_policy(policy);
+        let config = test_config(1).with_rate_limit(RateLimit::new(1).with_burst(1));
+        EdgarClient::with_client(http_client, config)
     }
 
-    async fn get_company_concept(
-        &self,
-        cik: &str,
-        taxonomy: Taxonomy,
-        tag: &str,
-    ) -> Result<ApiResponse<CompanyConcept>> {
-        let formatted_cik = format_cik(cik).map_err(|_| EdgarApiError::invalid_cik(cik))?;
-        let url = format!(
-            "https://data.sec.gov/api/xbrl/companyconcept/CIK{}/{}/{}.json",
-            formatted_cik,
-            taxonomy.as_str(),
-            tag
+    #[tokio::test]
+    async fn test_fresh_cache_hits_skip_the_rate_limiter() {
+        let client = throttled_cache(
+            vec![response(200, r#"{"fields": [], "data": []}"#)],
+            CachePolicy::default(),
         );
-        self.get(&url).await
+        client.get_company_tickers().await.unwrap();
+
+        // The only token went to the first request, so any wait would take a second
+        let start = Instant::now();
+        for _ in 0..3 {
+            client.get_company_tickers().await.unwrap();
+        }
+        assert!(start.elapsed() < Duration::from_millis(500));
+        assert_eq!(*client.http_client.inner().calls.lock().unwrap(), 1);
     }
 
-    async fn get_company_facts(&self, cik: &str) -> Result<ApiResponse<CompanyFacts>> {
-        let formatted_cik = format_cik(cik).map_err(|_| EdgarApiError::invalid_cik(cik))?;
-        let url = format!(
-            "https://data.sec.gov/api/xbrl/companyfacts/CIK{}.json",
-            formatted_cik
+    #[tokio::test]
+    async fn test_cache_revalidation_waits_for_the_rate_limiter() {
+        let client = throttled_cache(
+            vec![
+                response(200, r#"{"fields": [], "data": []}"#),
+                response(304, ""),
+            ],
+            CachePolicy::new(Some(Duration::ZERO)),
         );
-        self.get(&url).await
+        client.get_company_tickers().await.unwrap();
+
+        let start = Instant::now();
+        client.get_company_tickers().await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(500));
+        assert_eq!(*client.http_client.inner().calls.lock().unwrap(), 2);
     }
 
-    async fn get_xbrl_frames(
-        &self,
-        taxonomy: Taxonomy,
-        concept: &str,
-        unit: Unit,
-        period: Period,
-    ) -> Result<ApiResponse<XbrlFrames>> {
-        let url = format!(
-            "https://data.sec.gov/api/xbrl/frames/{}/{}/{}/{}.json",
-            taxonomy.as_str(),
-            concept,
-            unit.as_str(),
-            period.as_str()
+    #[tokio::test]
+    async fn test_download_bulk_archive_writes_file_and_reports_progress() {
+        let http_client = ScriptedClient::new(vec![
+            Err(EdgarApiError::network("connection reset")),
+            response(200, "PK archive bytes"),
+        ]);
+        let client = EdgarClient::with_client(http_client, test_config(2));
+
+        let dir = tempfile::tempdir().unwrap();
+        let zip_path = dir.path().join("companyfacts.zip");
+        let reports = Mutex::new(Vec::new());
+        let progress = |p: DownloadProgress| reports.lock().unwrap().push(p);
+
+        let downloaded = client
+            .download_bulk_archive(BulkDataset::CompanyFacts, &zip_path, Some(&progress))
+            .await
+            .unwrap();
+
+        assert_eq!(downloaded, 16);
+        assert_eq!(std::fs::read(&zip_path).unwrap(), b"PK archive bytes");
+        let reports = reports.into_inner().unwrap();
+        assert_eq!(reports.last().unwrap().fraction(), Some(1.0));
+    }
+
+    #[test]
+    fn test_filing_urls() {
+        let cik = Cik::new(320193);
+        let accession_number: AccessionNumber = "0000320193-23-000106".parse().unwrap();
+        assert_eq!(
+            filing_document_url(cik, accession_number, "aapl-20230930.htm").unwrap(),
+            "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/aapl-20230930.htm"
         );
-        self.get(&url).await
+
+        assert!(matches!(
+            filing_document_url(cik, accession_number, "../../index.json"),
+            Err(EdgarApiError::RequestError(_))
+        ));
     }
 
-    async fn get_company_tickers(&self) -> Result<ApiResponse<CompanyTickers>> {
-        let url = "https://www.sec.gov/files/company_tickers_exchange.json";
-        self.get(url).await
+    #[tokio::test]
+    async fn test_get_filing_document_returns_raw_bytes() {
+        let http_client = ScriptedClient::new(vec![response(200, "<html>10-K</html>")]);
+        let client = EdgarClient::with_client(http_client, test_config(1));
+
+        let document = client
+            .get_filing_document(
+                Cik::new(320193),
+                "0000320193-23-000106".parse().unwrap(),
+                "aapl-20230930.htm",
+            )
+            .await
+            .unwrap();
+        assert_eq!(document.status, 200);
+        assert_eq!(document.data, b"<html>10-K</html>");
     }
 
-    async fn get_company_tickers_mf(&self) -> Result<ApiResponse<CompanyTickersMf>> {
-        let url = "https://www.sec.gov/files/company_tickers_mf.json";
-        self.get(url).await
+    #[test]
+    fn test_index_urls() {
+        assert_eq!(
+            daily_index_url(IndexKind::Master, "2024-05-01").unwrap(),
+            "https://www.sec.gov/Archives/edgar/daily-index/2024/QTR2/master.20240501.idx"
+        );
+        assert_eq!(
+            quarterly_index_url(IndexKind::Company, 2023, 4).unwrap(),
+            "https://www.sec.gov/Archives/edgar/full-index/2023/QTR4/company.idx"
+        );
+        assert!(daily_index_url(IndexKind::Form, "20240501").is_err());
+        assert!(quarterly_index_url(IndexKind::Form, 2023, 5).is_err());
     }
 
-    #[cfg(feature = "native")]
-    async fn download_bulk_submissions(&self, output_path: &Path) -> Result<()> {
-        let url = "https://www.sec.gov/Archives/edgar/daily-index/bulkdata/submissions.zip";
-        let final_url = self.config.build_url(url);
-        let headers = [("User-Agent", self.config.user_agent.as_str())];
+    #[tokio::test]
+    async fn test_resolve_cik_fetches_ticker_lists_once() {
+        let http_client = ScriptedClient::new(vec![
+            response(
+                200,
+                r#"{"fields": ["cik", "name", "ticker", "exchange"],
+                    "data": [[1067983, "BERKSHIRE HATHAWAY INC", "BRK-B", "NYSE"]]}"#,
+            ),
+            response(
+                200,
+                r#"{"fields": ["cik", "seriesId", "classId", "symbol"],
+                    "data": [[36405, "S000002839", "C000007773", "VFIAX"]]}"#,
+            ),
+        ]);
+        let client = EdgarClient::with_client(http_client, test_config(1));
 
-        // Download the zip file
-        let bytes = self.http_client.get_bytes(&final_url, &headers).await?;
+        assert_eq!(client.resolve_cik("0000320193").await.unwrap(), 320193);
+        assert_eq!(*client.http_client.calls.lock().unwrap(), 0);
 
-        // Write to temp file and extract
-        let temp_path = write_temp_file(&bytes)?;
-        extract_zip(&temp_path, output_path)?;
+        assert_eq!(client.resolve_cik("BRK.B").await.unwrap(), 1067983);
+        assert_eq!(client.resolve_cik("vfiax").await.unwrap(), 36405);
+        assert_eq!(
+            client.resolve_cik("Berkshire Hathaway").await.unwrap(),
+            1067983
+        );
+        assert_eq!(*client.http_client.calls.lock().unwrap(), 2);
+    }
 
-        Ok(())
+    /// HTTP client that serves the ticker lists after a short delay, or fails
+    /// once `fail` is set, and counts the requests.
+    #[derive(Default)]
+    struct TickerClient {
+        calls: std::sync::atomic::AtomicUsize,
+        fail: std::sync::atomic::AtomicBool,
     }
 
-    #[cfg(feature = "native")]
-    async fn download_bulk_company_facts(&self, output_path: &Path) -> Result<()> {
-        let url = "https://www.sec.gov/Archives/edgar/daily-index/xbrl/companyfacts.zip";
-        let final_url = self.config.build_url(url);
-        let headers = [("User-Agent", self.config.user_agent.as_str())];
+    #[async_trait]
+    impl HttpClient for TickerClient {
+        async fn get(&self, url: &str, _headers: &[(&str, &str)]) -> Result<HttpResponse> {
+            use std::sync::atomic::Ordering;
 
-        // Download the zip file
-        let bytes = self.http_client.get_bytes(&final_url, &headers).await?;
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            tokio::time::sleep(Duration::from_millis(10)).await;
+            if self.fail.load(Ordering::SeqCst) {
+                return Err(EdgarApiError::network("connection reset"));
+            }
+            if url.ends_with("company_tickers_exchange.json") {
+                response(
+                    200,
+                    r#"{"fields": ["cik", "name", "ticker", "exchange"],
+                        "data": [[320193, "Apple Inc.", "AAPL", "Nasdaq"]]}"#,
+                )
+            } else {
+                response(200, r#"{"fields": ["cik", "seriesId", "classId", "symbol"], "data": []}"#)
+            }
+        }
+    }
 
-        // Write to temp file and extract
-        let temp_path = write_temp_file(&bytes)?;
-        extract_zip(&temp_path, output_path)?;
+    fn ticker_calls(client: &EdgarClient<TickerClient>) -> usize {
+        client
+            .http_client
+            .calls
+            .load(std::sync::atomic::Ordering::SeqCst)
+    }
 
-        Ok(())
+    #[tokio::test]
+    async fn test_concurrent_lookups_fetch_ticker_lists_once() {
+        let client = EdgarClient::with_client(TickerClient::default(), test_config(1));
+
+        let lookups = (0..4).map(|_| client.resolve_cik("AAPL"));
+        for cik in futures::future::join_all(lookups).await {
+            assert_eq!(cik.unwrap(), 320193);
+        }
+        assert_eq!(ticker_calls(&client), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cik_resolver_expires_after_ttl() {
+        let client = EdgarClient::with_client(TickerClient::default(), test_config(1));
+        client.resolve_cik("AAPL").await.unwrap();
+        client.resolve_cik("AAPL").await.unwrap();
+        assert_eq!(ticker_calls(&client), 2);
+
+        client.refresh_cik_resolver().await.unwrap();
+        assert_eq!(ticker_calls(&client), 4);
+
+        let config = test_config(1).with_cik_resolver_ttl(Duration::ZERO);
+        let client = EdgarClient::with_client(TickerClient::default(), config);
+        client.resolve_cik("AAPL").await.unwrap();
+        client.resolve_cik("AAPL").await.unwrap();
+        assert_eq!(ticker_calls(&client), 4);
+    }
+
+    #[tokio::test]
+    async fn test_expired_cik_resolver_is_kept_when_refreshing_fails() {
+        let config = test_config(1).with_cik_resolver_ttl(Duration::ZERO);
+        let client = EdgarClient::with_client(TickerClient::default(), config);
+        client.resolve_cik("AAPL").await.unwrap();
+
+        client
+            .http_client
+            .fail
+            .store(true, std::sync::atomic::Ordering::SeqCst);
+        assert_eq!(client.resolve_cik("AAPL").await.unwrap(), 320193);
+        assert!(client.refresh_cik_resolver().await.is_err());
+        assert_eq!(ticker_calls(&client), 4);
+    }
+
+    #[tokio::test]
+    async fn test_supplied_cik_resolver_never_expires() {
+        let tickers: CompanyTickers = serde_json::from_value(serde_json::json!({
+            "fields": ["cik", "name", "ticker", "exchange"],
+            "data": [[1, "Example Corp", "EXMP", "NYSE"]]
+        }))
+        .unwrap();
+        let funds: CompanyTickersMf = serde_json::from_value(serde_json::json!({
+            "fields": ["cik", "seriesId", "classId", "symbol"],
+            "data": []
+        }))
+        .unwrap();
+        let resolver = CikResolver::new(&tickers, &funds).unwrap();
+
+        let config = test_config(1).with_cik_resolver_ttl(Duration::ZERO);
+        let client = EdgarClient::with_client(TickerClient::default(), config)
+            .with_cik_resolver(resolver);
+        assert_eq!(client.resolve_cik("EXMP").await.unwrap(), 1);
+        assert_eq!(ticker_calls(&client), 0);
+
+        client.refresh_cik_resolver().await.unwrap();
+        assert_eq!(client.resolve_cik("AAPL").await.unwrap(), 320193);
+        assert!(client.resolve_cik("EXMP").await.is_err());
     }
 }
@@ -1,8 +1,16 @@
 //! Configuration module for EDGAR API client
 
+use std::time::Duration;
+
+use crate::rate_limit::RateLimit;
+use crate::retry::RetryPolicy;
+
 /// Default base URL for EDGAR API endpoints
 pub const DEFAULT_BASE_URL: &str = "https://";
 
+/// How long the ticker lists used to resolve identifiers are kept by default.
+pub const DEFAULT_CIK_RESOLVER_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
 /// Configuration for EDGAR API client
 #[derive(Debug, Clone)]
 pub struct Config {
@@ -11,6 +19,14 @@ pub struct Config {
     pub base_url: String,
     /// User agent string for requests (required by SEC)
     pub user_agent: String,
+    /// Client-side rate limit shared by all clones of a client (default: 10 requests/second).
+    /// `None` disables throttling.
+    pub rate_limit: Option<RateLimit>,
+    /// Retry policy for transient failures (default: 3 attempts with exponential backoff)
+    pub retry_policy: RetryPolicy,
+    /// How long the ticker lists behind `EdgarClient::resolve_cik` are used before
+    /// they are fetched again (default: one day)
+    pub cik_resolver_ttl: Duration,
 }
 
 impl Default for Config {
@@ -18,6 +34,9 @@ impl Default for Config {
         Self {
             base_url: DEFAULT_BASE_URL.to_string(),
             user_agent: "edgar-rs/0.1.0".to_string(),
+            rate_limit: Some(RateLimit::default()),
+            retry_policy: RetryPolicy::default(),
+            cik_resolver_ttl: DEFAULT_CIK_RESOLVER_TTL,
         }
     }
 }
@@ -38,9 +57,74 @@ impl Config {
         Self {
             base_url: DEFAULT_BASE_URL.to_string(),
             user_agent: user_agent.to_string(),
+            rate_limit: Some(RateLimit::default()),
+            retry_policy: RetryPolicy::default(),
+            cik_resolver_ttl: DEFAULT_CIK_RESOLVER_TTL,
         }
     }
 
+    /// Set the client-side rate limit
+    ///
+    /// # Parameters
+    /// * `rate_limit` - Maximum request rate and burst size
+    ///
+    /// # Example
+    /// ```
+    /// use edgar_rs::{Config, RateLimit};
+    ///
+    /// let config = Config::new("Company contact@example.com").with_rate_limit(RateLimit::new(5));
+    /// assert_eq!(config.rate_limit, Some(RateLimit::new(5)));
+    /// ```
+    pub fn with_rate_limit(mut self, rate_limit: RateLimit) -> Self {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Disable client-side rate limiting
+    ///
+    /// Only use this when requests are throttled elsewhere, e.g. by a proxy
+    /// configured through `base_url`.
+    pub fn without_rate_limit(mut self) -> Self {
+        self.rate_limit = None;
+        self
+    }
+
+    /// Set the retry policy for transient failures
+    ///
+    /// # Parameters
+    /// * `retry_policy` - Attempts, backoff and `Retry-After` handling
+    ///
+    /// # Example
+    /// ```
+    /// use edgar_rs::{Config, RetryPolicy};
+    ///
+    /// let config = Config::new("Company contact@example.com").with_retry_policy(RetryPolicy::none());
+    /// assert_eq!(config.retry_policy.max_attempts, 1);
+    /// ```
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Set how long the ticker lists used to resolve identifiers are kept
+    ///
+    /// # Parameters
+    /// * `ttl` - Time after which `EdgarClient::resolve_cik` fetches the lists again
+    ///
+    /// # Example
+    /// ```
+    /// use std::time::Duration;
+    /// use edgar_rs::Config;
+    ///
+    /// let config = Config::new("Company contact@example.com")
+    ///     .with_cik_resolver_ttl(Duration::from_secs(60 * 60));
+    /// assert_eq!(config.cik_resolver_ttl, Duration::from_secs(3600));
+    /// ```
+    pub fn with_cik_resolver_ttl(mut self, ttl: Duration) -> Self {
+        self.cik_resolver_ttl = ttl;
+        self
+    }
+
     /// Build a complete URL by replacing "https://" with the configured base URL
     ///
     /// # Parameters
@@ -59,10 +143,9 @@ impl Config {
     /// assert_eq!(url, "https://proxy.example.com/www.sec.gov/files/data.json");
     /// ```
     pub fn build_url(&self, url: &str) -> String {
-        if url.starts_with("https://") {
-            format!("{}{}", self.base_url, &url[8..])
-        } else {
-            url.to_string()
+        match url.strip_prefix("https://") {
+            Some(rest) => format!("{}{}", self.base_url, rest),
+            None => url.to_string(),
         }
     }
 }
@@ -76,6 +159,8 @@ mod tests {
         let config = Config::default();
         assert_eq!(config.base_url, "https://");
         assert_eq!(config.user_agent, "edgar-rs/0.1.0");
+        assert_eq!(config.rate_limit, Some(RateLimit::default()));
+        assert_eq!(config.retry_policy, RetryPolicy::default());
     }
 
     #[test]
@@ -85,6 +170,21 @@ mod tests {
         assert_eq!(config.user_agent, "Company contact@example.com");
     }
 
+    #[test]
+    fn test_rate_limit_settings() {
+        let config = Config::new("Company contact@example.com").with_rate_limit(RateLimit::new(2));
+        assert_eq!(config.rate_limit, Some(RateLimit::new(2)));
+
+        let config = config.without_rate_limit();
+        assert_eq!(config.rate_limit, None);
+    }
+
+    #[test]
+    fn test_retry_policy_settings() {
+        let config = Config::new("Company contact@example.com").with_retry_policy(RetryPolicy::new(5));
+        assert_eq!(config.retry_policy.max_attempts, 5);
+    }
+
     #[test]
     fn test_build_url_default() {
         let config = Config::new("Company contact@example.com");
@@ -44,6 +44,18 @@ pub enum EdgarApiError {
     #[error("Invalid CIK format: {0}")]
     InvalidCik(String),
 
+    /// Invalid accession number format.
+    #[error("Invalid accession number: {0}")]
+    InvalidAccessionNumber(String),
+
+    /// A date or timestamp that could not be parsed.
+    #[error("Invalid date: {0}")]
+    InvalidDate(String),
+
+    /// A ticker or company name that does not identify a single company.
+    #[error("Could not resolve identifier to a CIK: {0}")]
+    UnresolvedIdentifier(String),
+
     /// I/O errors when writing files.
     #[error("I/O error: {0}")]
     IoError(#[from] std::io::Error),
@@ -52,18 +64,16 @@ pub enum EdgarApiError {
     #[error("Zip extraction error: {0}")]
     ZipError(String),
 
+    /// Operation not supported by this `EdgarApi` implementation.
+    #[error("Unsupported operation: {0}")]
+    Unsupported(String),
+
     /// HTTP client errors from reqwest.
     #[cfg(feature = "native")]
     #[error("HTTP client error: {0}")]
     HttpError(#[from] reqwest::Error),
 }
 
-impl From<&str> for EdgarApiError {
-    fn from(err: &str) -> Self {
-        EdgarApiError::ParseError(err.to_string())
-    }
-}
-
 impl EdgarApiError {
     /// Returns true if the error is a transient error that may succeed if retried.
     pub fn is_transient(&self) -> bool {
@@ -116,8 +126,28 @@ impl EdgarApiError {
         EdgarApiError::InvalidCik(cik.to_string())
     }
 
+    /// Creates a new invalid accession number error.
+    pub fn invalid_accession_number(accession: impl fmt::Display) -> Self {
+        EdgarApiError::InvalidAccessionNumber(accession.to_string())
+    }
+
+    /// Creates a new invalid date error.
+    pub fn invalid_date(date: impl fmt::Display) -> Self {
+        EdgarApiError::InvalidDate(date.to_string())
+    }
+
+    /// Creates a new unresolved identifier error.
+    pub fn unresolved_identifier(identifier: impl fmt::Display) -> Self {
+        EdgarApiError::UnresolvedIdentifier(identifier.to_string())
+    }
+
     /// Creates a new zip error.
     pub fn zip(msg: impl fmt::Display) -> Self {
         EdgarApiError::ZipError(msg.to_string())
     }
+
+    /// Creates a new unsupported operation error.
+    pub fn unsupported(msg: impl fmt::Display) -> Self {
+        EdgarApiError::Unsupported(msg.to_string())
+    }
 }
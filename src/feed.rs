@@ -0,0 +1,490 @@
+//! Polling stream over the latest filings feed.
+//!
+//! [`CurrentFilingsPoller`] repeatedly fetches the "getcurrent" feed through an
+//! [`EdgarApi`] implementation and yields every new filing once. Requests go
+//! through the client, so its User-Agent, rate limit and retry policy apply.
+
+use std::collections::{HashSet, VecDeque};
+use std::time::Duration;
+
+use futures::stream::{self, BoxStream, StreamExt};
+use log::{trace, warn};
+
+use crate::api::EdgarApi;
+use crate::error::Result;
+use crate::identifiers::{AccessionNumber, Cik};
+use crate::models::current_feed::{CurrentFeedEntry, CurrentFeedQuery, CURRENT_FEED_MAX_COUNT};
+use crate::runtime;
+
+/// Default time between polls.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Number of accession numbers remembered for de-duplication.
+const SEEN_CAPACITY: usize = 10_000;
+
+/// Maximum number of feed pages fetched per poll while catching up.
+const MAX_PAGES_PER_POLL: u32 = 20;
+
+/// Builds a stream of newly accepted filings.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use std::time::Duration;
+/// use futures::StreamExt;
+/// use edgar_rs::{Config, CurrentFilingsPoller, EdgarClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = EdgarClient::new(Config::new("Your Company Name your.email@example.com"))?;
+///     let mut filings = CurrentFilingsPoller::new()
+///         .with_form_type("8-K")
+///         .with_interval(Duration::from_secs(30))
+///         .stream(&client);
+///
+///     while let Some(filing) = filings.next().await {
+///         match filing {
+///             Ok(filing) => println!("{} filed {:?}", filing.company_name, filing.items),
+///             Err(e) => eprintln!("Polling failed: {}", e),
+///         }
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CurrentFilingsPoller {
+    form_types: HashSet<String>,
+    ciks: HashSet<Cik>,
+    interval: Duration,
+    skip_existing: bool,
+}
+
+impl Default for CurrentFilingsPoller {
+    fn default() -> Self {
+        Self {
+            form_types: HashSet::new(),
+            ciks: HashSet::new(),
+            interval: DEFAULT_POLL_INTERVAL,
+            skip_existing: false,
+        }
+    }
+}
+
+impl CurrentFilingsPoller {
+    /// Creates a poller for all filings, polling every minute.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only yields filings of the given form type (exact match). Can be called repeatedly.
+    pub fn with_form_type(mut self, form_type: impl Into<String>) -> Self {
+        self.form_types.insert(form_type.into());
+        self
+    }
+
+    /// Only yields filings listing the given CIK as a party. Can be called repeatedly.
+    pub fn with_cik(mut self, cik: Cik) -> Self {
+        self.ciks.insert(cik);
+        self
+    }
+
+    /// Only yields filings listing one of the given CIKs as a party.
+    pub fn with_ciks(mut self, ciks: impl IntoIterator<Item = Cik>) -> Self {
+        self.ciks.extend(ciks);
+        self
+    }
+
+    /// Sets the time between polls.
+    pub fn with_interval(mut self, interval: Duration) -> Self {
+        self.interval = interval;
+        self
+    }
+
+    /// Skips the filings already in the feed when the stream starts, yielding only
+    /// filings accepted afterwards.
+    pub fn with_skip_existing(mut self, skip_existing: bool) -> Self {
+        self.skip_existing = skip_existing;
+        self
+    }
+
+    /// Returns true if `entry` passes the form type and CIK filters.
+    pub fn matches(&self, entry: &CurrentFeedEntry) -> bool {
+        (self.form_types.is_empty() || self.form_types.contains(&entry.form_type))
+            && (self.ciks.is_empty() || self.ciks.contains(&entry.cik))
+    }
+
+    /// Starts polling `api`.
+    ///
+    /// Filings are yielded oldest first, once per accession number. Each poll pages
+    /// back through the feed until it reaches a filing seen before, so bursts of
+    /// more than a page of filings between polls aren't lost. A failed poll
+    /// yields the error and polling continues after the interval; drop the stream
+    /// to stop. The stream is boxed, so `StreamExt::next` works on it directly.
+    pub fn stream<'a, A>(self, api: &'a A) -> BoxStream<'a, Result<CurrentFeedEntry>>
+    where
+        A: EdgarApi + Sync + ?Sized,
+    {
+        let mut query = CurrentFeedQuery::new().with_count(CURRENT_FEED_MAX_COUNT);
+        // The feed's own filter matches prefixes, so it can only narrow a single form type
+        if self.form_types.len() == 1 {
+            query.form_type = self.form_types.iter().next().cloned();
+        }
+
+        let state = PollState {
+            api,
+            query,
+            poller: self,
+            seen: SeenSet::default(),
+            emitted: SeenSet::default(),
+            pending: VecDeque::new(),
+            polls: 0,
+            primed: false,
+        };
+
+        stream::unfold(state, |mut state| async move {
+            loop {
+                if let Some(entry) = state.pending.pop_front() {
+                    return Some((Ok(entry), state));
+                }
+
+                if state.polls > 0 {
+                    runtime::sleep(state.poller.interval).await;
+                }
+                state.polls += 1;
+
+                match state.fetch_new_entries().await {
+                    Ok(entries) => state.enqueue(entries),
+                    Err(e) => {
+                        warn!("Failed to poll the latest filings feed: {}", e);
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+        })
+        .boxed()
+    }
+}
+
+struct PollState<'a, A: ?Sized> {
+    api: &'a A,
+    query: CurrentFeedQuery,
+    poller: CurrentFilingsPoller,
+    /// Every accession number listed in the feed, to know where paging can stop.
+    seen: SeenSet,
+    /// Accession numbers already yielded, or skipped as existing.
+    emitted: SeenSet,
+    pending: VecDeque<CurrentFeedEntry>,
+    polls: u64,
+    /// Whether a poll has succeeded, after which existing filings are no longer skipped.
+    primed: bool,
+}
+
+impl<A: EdgarApi + Sync + ?Sized> PollState<'_, A> {
+    /// Fetches the feed pages listed since the previous poll, newest first.
+    ///
+    /// The first poll only reads one page. Later polls keep paging until a page
+    /// contains a filing seen before or the feed runs out. Nothing is recorded as
+    /// seen until every page has been fetched, so a failed poll is retried in full.
+    async fn fetch_new_entries(&self) -> Result<Vec<CurrentFeedEntry>> {
+        let mut query = self.query.clone();
+        let mut entries = Vec::new();
+
+        for page in 1..=MAX_PAGES_PER_POLL {
+            let feed = self.api.get_current_filings(&query).await?.data;
+            let caught_up = self.seen.is_empty()
+                || feed.entries.len() < query.count as usize
+                || feed
+                    .entries
+                    .iter()
+                    .any(|entry| self.seen.contains(&entry.accession_number));
+            entries.extend(feed.entries);
+
+            if caught_up {
+                return Ok(entries);
+            }
+            if page == MAX_PAGES_PER_POLL {
+                warn!(
+                    "Stopped paging the latest filings feed after {} pages; some filings may have been missed",
+                    MAX_PAGES_PER_POLL
+                );
+                break;
+            }
+            query.start += query.count;
+        }
+        Ok(entries)
+    }
+}
+
+impl<A: ?Sized> PollState<'_, A> {
+    /// Queues the new entries of the feed, which lists the newest filings first.
+    fn enqueue(&mut self, entries: Vec<CurrentFeedEntry>) {
+        let initial = !self.primed && self.poller.skip_existing;
+        self.primed = true;
+
+        for entry in entries.into_iter().rev() {
+            // Filtered-out rows are remembered too, so paging can stop at them. A
+            // filing is listed once per party, so only a matching row emits it.
+            self.seen.insert(entry.accession_number);
+            if !self.poller.matches(&entry) || !self.emitted.insert(entry.accession_number) {
+                continue;
+            }
+            if !initial {
+                self.pending.push_back(entry);
+            }
+        }
+        trace!("Found {} new filings in the feed", self.pending.len());
+    }
+}
+
+/// Bounded set of recently seen accession numbers.
+#[derive(Default)]
+struct SeenSet {
+    set: HashSet<AccessionNumber>,
+    order: VecDeque<AccessionNumber>,
+}
+
+impl SeenSet {
+    fn is_empty(&self) -> bool {
+        self.set.is_empty()
+    }
+
+    fn contains(&self, accession_number: &AccessionNumber) -> bool {
+        self.set.contains(accession_number)
+    }
+
+    /// Records `accession_number` and returns true if it was not seen before.
+    fn insert(&mut self, accession_number: AccessionNumber) -> bool {
+        if !self.set.insert(accession_number) {
+            return false;
+        }
+        self.order.push_back(accession_number);
+        if self.order.len() > SEEN_CAPACITY {
+            if let Some(oldest) = self.order.pop_front() {
+                self.set.remove(&oldest);
+            }
+        }
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::EdgarApiError;
+    use crate::http::{HttpClient, HttpResponse};
+    use crate::{Config, EdgarClient, RetryPolicy};
+    use std::sync::{Arc, Mutex};
+
+    /// Serves scripted feed pages and records the requested URLs.
+    struct ScriptedFeed {
+        pages: Mutex<VecDeque<Result<String>>>,
+        urls: Arc<Mutex<Vec<String>>>,
+    }
+
+    #[async_trait::async_trait]
+    impl HttpClient for ScriptedFeed {
+        async fn get(&self, url: &str, _headers: &[(&str, &str)]) -> Result<HttpResponse> {
+            self.urls.lock().unwrap().push(url.to_string());
+            let body = self
+                .pages
+                .lock()
+                .unwrap()
+                .pop_front()
+                .unwrap_or_else(|| Ok(feed(&[])))?;
+            Ok(HttpResponse {
+                status: 200,
+                headers: Default::default(),
+                body: body.into_bytes(),
+            })
+        }
+    }
+
+    /// Builds a feed page from (form type, CIK, accession number) triples, newest first.
+    fn feed(entries: &[(&str, u64, &str)]) -> String {
+        let entries: String = entries
+            .iter()
+            .map(|(form_type, cik, accession)| {
+                format!(
+                    "<entry><title>{} - Company ({:010}) (Filer)</title>\
+                     <category term=\"{}\"/>\
+                     <id>urn:tag:sec.gov,2008:accession-number={}</id></entry>",
+                    form_type, cik, form_type, accession
+                )
+            })
+            .collect();
+        format!(
+            "<feed xmlns=\"http://www.w3.org/2005/Atom\">{}</feed>",
+            entries
+        )
+    }
+
+    /// Returns a client serving `pages` and the list of URLs it requests.
+    fn client(pages: Vec<Result<String>>) -> (EdgarClient<ScriptedFeed>, Arc<Mutex<Vec<String>>>) {
+        let urls = Arc::new(Mutex::new(Vec::new()));
+        let http_client = ScriptedFeed {
+            pages: Mutex::new(pages.into()),
+            urls: urls.clone(),
+        };
+        let config = Config::new("edgar-rs tests test@example.com")
+            .without_rate_limit()
+            .with_retry_policy(RetryPolicy::none());
+        (EdgarClient::with_client(http_client, config), urls)
+    }
+
+    fn accession_numbers(results: &[Result<CurrentFeedEntry>]) -> Vec<Option<String>> {
+        results
+            .iter()
+            .map(|r| r.as_ref().ok().map(|e| e.accession_number.to_string()))
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_stream_deduplicates_and_continues_after_errors() {
+        let (client, _) = client(vec![
+            // A Form 4 is listed once per party
+            Ok(feed(&[
+                ("4", 1, "0000000001-24-000002"),
+                ("8-K", 320193, "0000320193-24-000001"),
+                ("4", 2, "0000000001-24-000002"),
+            ])),
+            Err(EdgarApiError::network("connection reset")),
+            Ok(feed(&[
+                ("8-K", 789019, "0000789019-24-000001"),
+                ("4", 1, "0000000001-24-000002"),
+                ("8-K", 320193, "0000320193-24-000001"),
+            ])),
+        ]);
+        let poller = CurrentFilingsPoller::new().with_interval(Duration::ZERO);
+
+        let results: Vec<_> = poller.stream(&client).take(4).collect().await;
+        assert_eq!(
+            accession_numbers(&results),
+            vec![
+                Some("0000000001-24-000002".to_string()),
+                Some("0000320193-24-000001".to_string()),
+                None,
+                Some("0000789019-24-000001".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_filters_by_form_type_and_cik() {
+        let (client, urls) = client(vec![Ok(feed(&[
+            ("8-K", 789019, "0000789019-24-000001"),
+            ("8-K/A", 320193, "0000320193-24-000002"),
+            ("8-K", 320193, "0000320193-24-000001"),
+        ]))]);
+        let poller = CurrentFilingsPoller::new()
+            .with_form_type("8-K")
+            .with_cik(Cik::new(320193))
+            .with_interval(Duration::ZERO);
+
+        let results: Vec<_> = poller.stream(&client).take(1).collect().await;
+        assert_eq!(
+            accession_numbers(&results),
+            vec![Some("0000320193-24-000001".to_string())]
+        );
+
+        let urls = urls.lock().unwrap();
+        assert!(urls[0].contains("type=8-K&"));
+        assert!(urls[0].contains("count=100"));
+    }
+
+    #[tokio::test]
+    async fn test_stream_yields_multi_party_filings_for_any_listed_party() {
+        let (client, _) = client(vec![Ok(feed(&[
+            ("4", 2, "0000000001-24-000002"),
+            ("4", 1, "0000000001-24-000002"),
+            ("4", 1, "0000000001-24-000001"),
+            ("4", 2, "0000000001-24-000001"),
+        ]))]);
+        let poller = CurrentFilingsPoller::new()
+            .with_cik(Cik::new(1))
+            .with_interval(Duration::ZERO);
+
+        // Whichever party row comes first, each filing is yielded once
+        let results: Vec<_> = tokio::time::timeout(
+            Duration::from_secs(5),
+            poller.stream(&client).take(2).collect(),
+        )
+        .await
+        .expect("a filing listing CIK 1 was dropped");
+        assert_eq!(
+            accession_numbers(&results),
+            vec![
+                Some("0000000001-24-000001".to_string()),
+                Some("0000000001-24-000002".to_string()),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_pages_back_to_the_last_seen_filing() {
+        let accessions: Vec<String> = (1..=121)
+            .map(|i| format!("0000000001-24-{:06}", i))
+            .collect();
+        // Newest first: 120 filings arrive between the first and second poll
+        let entries: Vec<_> = accessions
+            .iter()
+            .rev()
+            .map(|a| ("4", 1, a.as_str()))
+            .collect();
+        let (client, urls) = client(vec![
+            Ok(feed(&entries[120..])),
+            Ok(feed(&entries[..100])),
+            Ok(feed(&entries[100..])),
+        ]);
+        let poller = CurrentFilingsPoller::new().with_interval(Duration::ZERO);
+
+        let results: Vec<_> = poller.stream(&client).take(121).collect().await;
+        let expected: Vec<_> = accessions.iter().cloned().map(Some).collect();
+        assert_eq!(accession_numbers(&results), expected);
+
+        let urls = urls.lock().unwrap();
+        assert_eq!(urls.len(), 3);
+        assert!(urls[1].contains("start=0&"));
+        assert!(urls[2].contains("start=100&"));
+    }
+
+    #[tokio::test]
+    async fn test_stream_can_skip_existing_filings() {
+        let (client, _) = client(vec![
+            Ok(feed(&[("8-K", 320193, "0000320193-24-000001")])),
+            Ok(feed(&[
+                ("8-K", 320193, "0000320193-24-000002"),
+                ("8-K", 320193, "0000320193-24-000001"),
+            ])),
+        ]);
+        let poller = CurrentFilingsPoller::new()
+            .with_skip_existing(true)
+            .with_interval(Duration::ZERO);
+
+        let results: Vec<_> = poller.stream(&client).take(1).collect().await;
+        assert_eq!(
+            accession_numbers(&results),
+            vec![Some("0000320193-24-000002".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stream_skips_existing_filings_after_a_failed_first_poll() {
+        let (client, _) = client(vec![
+            Err(EdgarApiError::network("connection reset")),
+            Ok(feed(&[("8-K", 320193, "0000320193-24-000001")])),
+            Ok(feed(&[
+                ("8-K", 320193, "0000320193-24-000002"),
+                ("8-K", 320193, "0000320193-24-000001"),
+            ])),
+        ]);
+        let poller = CurrentFilingsPoller::new()
+            .with_skip_existing(true)
+            .with_interval(Duration::ZERO);
+
+        let results: Vec<_> = poller.stream(&client).take(2).collect().await;
+        assert_eq!(
+            accession_numbers(&results),
+            vec![None, Some("0000320193-24-000002".to_string())]
+        );
+    }
+}
@@ -0,0 +1,297 @@
+//! SEC form types.
+//!
+//! This module contains the [`FormType`] enum, a typed view of the form type
+//! strings found in submissions, company facts and indexes.
+
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// Suffix EDGAR appends to the form type of an amendment.
+const AMENDMENT_SUFFIX: &str = "/A";
+
+/// An SEC form type such as "10-K" or "SC 13G/A".
+///
+/// Common forms have their own variants; anything else is kept verbatim in
+/// [`FormType::Other`]. Amendments wrap the amended form, so "10-K/A" parses to
+/// `Amended(TenK)` and [`base_form`](FormType::base_form) groups it with "10-K".
+/// Parsing never fails and displays the form the way EDGAR writes it.
+///
+/// # Example
+///
+/// ```
+/// use edgar_rs::FormType;
+///
+/// let form: FormType = "10-K/A".into();
+/// assert!(form.is_amendment());
+/// assert_eq!(form.base_form(), &FormType::TenK);
+/// assert!(form.is_periodic_report());
+/// assert_eq!(form.to_string(), "10-K/A");
+///
+/// assert_eq!(FormType::from("S-1"), FormType::S1);
+/// assert_eq!(FormType::from("N-CSR"), FormType::Other("N-CSR".to_string()));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum FormType {
+    /// Annual report ("10-K").
+    TenK,
+    /// Quarterly report ("10-Q").
+    TenQ,
+    /// Current report ("8-K").
+    EightK,
+    /// Current report of a foreign private issuer ("6-K").
+    SixK,
+    /// Annual report of a foreign private issuer ("20-F").
+    TwentyF,
+    /// Annual report of a Canadian issuer ("40-F").
+    FortyF,
+    /// Registration statement ("S-1").
+    S1,
+    /// Short-form registration statement ("S-3").
+    S3,
+    /// Registration statement for business combinations ("S-4").
+    S4,
+    /// Registration statement for employee benefit plans ("S-8").
+    S8,
+    /// Registration statement of a foreign private issuer ("F-1").
+    F1,
+    /// Definitive proxy statement ("DEF 14A").
+    Def14A,
+    /// Institutional investment manager holdings report ("13F-HR").
+    ThirteenFHr,
+    /// Initial statement of beneficial ownership ("3").
+    Form3,
+    /// Statement of changes in beneficial ownership ("4").
+    Form4,
+    /// Annual statement of beneficial ownership ("5").
+    Form5,
+    /// Notice of proposed sale of securities ("144").
+    Form144,
+    /// Beneficial ownership report of an active investor ("SC 13D").
+    Sc13D,
+    /// Beneficial ownership report of a passive investor ("SC 13G").
+    Sc13G,
+    /// An amendment of the wrapped form, e.g. "10-K/A".
+    Amended(Box<FormType>),
+    /// Any other form type, as written by EDGAR.
+    Other(String),
+}
+
+impl FormType {
+    /// Returns true if the form amends an earlier filing.
+    pub fn is_amendment(&self) -> bool {
+        matches!(self, FormType::Amended(_))
+    }
+
+    /// Returns the form being amended, or the form itself if it is not an amendment.
+    pub fn base_form(&self) -> &FormType {
+        match self {
+            FormType::Amended(form) => form.base_form(),
+            form => form,
+        }
+    }
+
+    /// Returns true if the form, or the form it amends, is an annual report:
+    /// 10-K, 20-F or 40-F.
+    pub fn is_annual_report(&self) -> bool {
+        matches!(
+            self.base_form(),
+            FormType::TenK | FormType::TwentyF | FormType::FortyF
+        )
+    }
+
+    /// Returns true if the form, or the form it amends, is a periodic report:
+    /// an annual report or a 10-Q.
+    pub fn is_periodic_report(&self) -> bool {
+        self.is_annual_report() || matches!(self.base_form(), FormType::TenQ)
+    }
+
+    /// Returns true if `self` and `other` have the same base form, so that
+    /// "10-K" and "10-K/A" match each other.
+    pub fn same_base_form(&self, other: &FormType) -> bool {
+        self.base_form() == other.base_form()
+    }
+
+    /// Parses a form type without an amendment suffix.
+    fn parse_base(form: &str) -> FormType {
+        match form.to_ascii_uppercase().as_str() {
+            "10-K" => FormType::TenK,
+            "10-Q" => FormType::TenQ,
+            "8-K" => FormType::EightK,
+            "6-K" => FormType::SixK,
+            "20-F" => FormType::TwentyF,
+            "40-F" => FormType::FortyF,
+            "S-1" => FormType::S1,
+            "S-3" => FormType::S3,
+            "S-4" => FormType::S4,
+            "S-8" => FormType::S8,
+            "F-1" => FormType::F1,
+            "DEF 14A" => FormType::Def14A,
+            "13F-HR" => FormType::ThirteenFHr,
+            "3" => FormType::Form3,
+            "4" => FormType::Form4,
+            "5" => FormType::Form5,
+            "144" => FormType::Form144,
+            // EDGAR renamed the beneficial ownership schedules in December 2024
+            "SC 13D" | "SCHEDULE 13D" => FormType::Sc13D,
+            "SC 13G" | "SCHEDULE 13G" => FormType::Sc13G,
+            _ => FormType::Other(form.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for FormType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let form = match self {
+            FormType::TenK => "10-K",
+            FormType::TenQ => "10-Q",
+            FormType::EightK => "8-K",
+            FormType::SixK => "6-K",
+            FormType::TwentyF => "20-F",
+            FormType::FortyF => "40-F",
+            FormType::S1 => "S-1",
+            FormType::S3 => "S-3",
+            FormType::S4 => "S-4",
+            FormType::S8 => "S-8",
+            FormType::F1 => "F-1",
+            FormType::Def14A => "DEF 14A",
+            FormType::ThirteenFHr => "13F-HR",
+            FormType::Form3 => "3",
+            FormType::Form4 => "4",
+            FormType::Form5 => "5",
+            FormType::Form144 => "144",
+            FormType::Sc13D => "SC 13D",
+            FormType::Sc13G => "SC 13G",
+            FormType::Amended(form) => return write!(f, "{}{}", form, AMENDMENT_SUFFIX),
+            FormType::Other(form) => form,
+        };
+        f.write_str(form)
+    }
+}
+
+impl FromStr for FormType {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(FormType::from(s))
+    }
+}
+
+impl From<&str> for FormType {
+    fn from(form: &str) -> Self {
+        let form = form.trim();
+        // Base forms match case-insensitively, so the suffix must too
+        let split = form.len().saturating_sub(AMENDMENT_SUFFIX.len());
+        let amended = form
+            .get(split..)
+            .filter(|suffix| suffix.eq_ignore_ascii_case(AMENDMENT_SUFFIX))
+            .map(|_| &form[..split]);
+        match amended {
+            Some(amended) if !amended.is_empty() => {
+                FormType::Amended(Box::new(FormType::parse_base(amended)))
+            }
+            _ => FormType::parse_base(form),
+        }
+    }
+}
+
+impl From<&String> for FormType {
+    fn from(form: &String) -> Self {
+        FormType::from(form.as_str())
+    }
+}
+
+impl From<String> for FormType {
+    fn from(form: String) -> Self {
+        FormType::from(form.as_str())
+    }
+}
+
+impl From<&FormType> for FormType {
+    fn from(form: &FormType) -> Self {
+        form.clone()
+    }
+}
+
+impl PartialEq<&str> for FormType {
+    fn eq(&self, other: &&str) -> bool {
+        let other = FormType::from(*other);
+        *self == other
+    }
+}
+
+impl Serialize for FormType {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for FormType {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(FormType::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_and_display_form_types() {
+        let test_cases = [
+            ("10-K", FormType::TenK),
+            ("10-Q", FormType::TenQ),
+            ("8-K", FormType::EightK),
+            ("def 14a", FormType::Def14A),
+            ("4", FormType::Form4),
+            ("SC 13G", FormType::Sc13G),
+            ("8-K/A", FormType::Amended(Box::new(FormType::EightK))),
+            ("10-k/a", FormType::Amended(Box::new(FormType::TenK))),
+            ("N-PX", FormType::Other("N-PX".to_string())),
+            (
+                "N-PX/A",
+                FormType::Amended(Box::new(FormType::Other("N-PX".to_string()))),
+            ),
+        ];
+
+        for (input, expected) in test_cases {
+            let form = FormType::from(input);
+            assert_eq!(form, expected, "Failed for input: {}", input);
+            assert!(form.to_string().eq_ignore_ascii_case(input));
+        }
+
+        assert_eq!(FormType::from("SCHEDULE 13D/A").to_string(), "SC 13D/A");
+        assert_eq!(FormType::from("/A"), FormType::Other("/A".to_string()));
+        assert_eq!(FormType::from("/a"), FormType::Other("/a".to_string()));
+        assert_eq!(FormType::from("é"), FormType::Other("é".to_string()));
+    }
+
+    #[test]
+    fn test_amendments_and_categories() {
+        let amended = FormType::from("10-K/A");
+        assert!(amended.is_amendment());
+        assert!(!FormType::TenK.is_amendment());
+        assert_eq!(amended.base_form(), &FormType::TenK);
+        assert!(amended.same_base_form(&FormType::TenK));
+        assert!(!amended.same_base_form(&FormType::TenQ));
+
+        assert!(FormType::TwentyF.is_annual_report());
+        assert!(FormType::TenQ.is_periodic_report());
+        assert!(!FormType::TenQ.is_annual_report());
+        assert!(!FormType::EightK.is_periodic_report());
+        assert!(!FormType::from("10-KT").is_periodic_report());
+    }
+
+    #[test]
+    fn test_form_type_serde() {
+        let forms: Vec<FormType> = serde_json::from_str(r#"["10-Q", "SC 13D/A"]"#).unwrap();
+        assert_eq!(forms[0], FormType::TenQ);
+        assert_eq!(forms[1], "SC 13D/A");
+        assert_eq!(
+            serde_json::to_string(&forms).unwrap(),
+            r#"["10-Q","SC 13D/A"]"#
+        );
+    }
+}
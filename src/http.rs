@@ -5,11 +5,13 @@
 
 use async_trait::async_trait;
 use serde::de::DeserializeOwned;
+#[cfg(feature = "native")]
+use std::io::Write;
 
 use crate::error::{EdgarApiError, Result};
 
 /// HTTP response wrapper
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct HttpResponse {
     pub status: u16,
     pub headers: std::collections::HashMap<String, String>,
@@ -22,7 +24,7 @@ impl HttpResponse {
     where
         T: DeserializeOwned,
     {
-        serde_json::from_slice(&self.body).map_err(|e| EdgarApiError::parse(e))
+        serde_json::from_slice(&self.body).map_err(EdgarApiError::parse)
     }
 
     /// Get response body as bytes
@@ -36,35 +38,72 @@ impl HttpResponse {
     }
 }
 
-
-/// HTTP client trait for making requests
-#[async_trait]
+/// Progress of a streaming download
 #[cfg(feature = "native")]
-pub trait HttpClient: Send + Sync {
-    /// Make a GET request to the specified URL
-    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse>;
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DownloadProgress {
+    /// Number of bytes written so far
+    pub downloaded: u64,
+    /// Total size of the response body, if the server sent a `Content-Length`
+    pub total: Option<u64>,
+}
 
-    /// Make a GET request and return the response body as bytes
-    async fn get_bytes(&self, url: &str, headers: &[(&str, &str)]) -> Result<Vec<u8>> {
-        let response = self.get(url, headers).await?;
-        if !response.is_success() {
-            return Err(EdgarApiError::api(
-                response.status,
-                format!("Request to {} failed with status {}", url, response.status),
-            ));
+#[cfg(feature = "native")]
+impl DownloadProgress {
+    /// Fraction of the download completed, between 0.0 and 1.0, if the total size is known
+    pub fn fraction(&self) -> Option<f64> {
+        match self.total {
+            Some(0) => Some(1.0),
+            Some(total) => Some(self.downloaded as f64 / total as f64),
+            None => None,
         }
-        Ok(response.body)
     }
-
 }
 
-/// HTTP client trait for making requests (Cloudflare Workers)
-#[async_trait(?Send)]
-#[cfg(feature = "cloudflare-workers")]
-pub trait HttpClient {
+/// Callback invoked as a streaming download makes progress
+#[cfg(feature = "native")]
+pub type ProgressCallback<'a> = dyn Fn(DownloadProgress) + Send + Sync + 'a;
+
+/// Marker for types that can be shared across threads where the runtime needs it.
+///
+/// This is `Send + Sync` with the `native` feature, whose tokio runtime may move
+/// requests between threads, and implemented for every type otherwise, since
+/// Cloudflare Workers run on a single thread. It is implemented automatically.
+#[cfg(feature = "native")]
+pub trait MaybeSendSync: Send + Sync {}
+
+#[cfg(feature = "native")]
+impl<T: Send + Sync + ?Sized> MaybeSendSync for T {}
+
+/// Marker for types that can be shared across threads; implemented for every type without `native`.
+#[cfg(not(feature = "native"))]
+pub trait MaybeSendSync {}
+
+#[cfg(not(feature = "native"))]
+impl<T: ?Sized> MaybeSendSync for T {}
+
+/// HTTP client trait for making requests
+///
+/// The same trait serves every runtime: with the `native` feature implementations
+/// and their futures must be `Send`, otherwise they needn't be. Implement it with
+/// `#[async_trait]` on native and `#[async_trait(?Send)]` on Workers, or with both
+/// behind `cfg_attr` for a client that works on either.
+#[cfg_attr(feature = "native", async_trait)]
+#[cfg_attr(not(feature = "native"), async_trait(?Send))]
+pub trait HttpClient: MaybeSendSync {
     /// Make a GET request to the specified URL
     async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse>;
 
+    /// Returns the response to a GET request to `url` if it can be served without going to the network
+    ///
+    /// [`EdgarClient`](crate::EdgarClient) tries this before waiting for its rate
+    /// limiter and only calls [`HttpClient::get`] when it returns `None`. The default
+    /// returns `None`; caching clients override it.
+    fn get_cached(&self, url: &str) -> Option<HttpResponse> {
+        let _ = url;
+        None
+    }
+
     /// Make a GET request and return the response body as bytes
     async fn get_bytes(&self, url: &str, headers: &[(&str, &str)]) -> Result<Vec<u8>> {
         let response = self.get(url, headers).await?;
@@ -77,16 +116,55 @@ pub trait HttpClient {
         Ok(response.body)
     }
 
+    /// Make a GET request and write the response body to `writer`
+    ///
+    /// Returns the number of bytes written. The default implementation buffers the
+    /// whole body in memory; implementations should override it to write the body
+    /// chunk by chunk so that multi-gigabyte archives never have to fit in memory.
+    #[cfg(feature = "native")]
+    async fn download(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        writer: &mut (dyn Write + Send),
+        progress: Option<&ProgressCallback<'_>>,
+    ) -> Result<u64> {
+        let body = self.get_bytes(url, headers).await?;
+        writer.write_all(&body)?;
+
+        let downloaded = body.len() as u64;
+        if let Some(progress) = progress {
+            progress(DownloadProgress {
+                downloaded,
+                total: Some(downloaded),
+            });
+        }
+        Ok(downloaded)
+    }
 }
 
+mod cache;
+mod mock;
+
 #[cfg(feature = "native")]
 mod native;
 
 #[cfg(feature = "cloudflare-workers")]
 mod workers;
 
+#[cfg(all(feature = "wasm-browser", not(feature = "native")))]
+mod browser;
+
+pub use cache::{CacheEntry, CachePolicy, CacheStore, CachingClient, MemoryCache};
+#[cfg(feature = "native")]
+pub use cache::DiskCache;
+pub use mock::MockHttpClient;
+
 #[cfg(feature = "native")]
 pub use native::ReqwestClient;
 
 #[cfg(feature = "cloudflare-workers")]
-pub use workers::WorkerClient;
\ No newline at end of file
+pub use workers::WorkerClient;
+
+#[cfg(all(feature = "wasm-browser", not(feature = "native")))]
+pub use browser::BrowserClient;
@@ -0,0 +1,105 @@
+//! Browser HTTP client implementation using the fetch API.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+
+use js_sys::{Array, Promise, Uint8Array};
+use wasm_bindgen::prelude::wasm_bindgen;
+use wasm_bindgen::{JsCast, JsValue};
+use wasm_bindgen_futures::JsFuture;
+use web_sys::{Headers, Request, RequestInit, Response};
+
+use crate::error::{EdgarApiError, Result};
+
+use super::{HttpClient, HttpResponse};
+
+/// HTTP client implementation for browsers, using the global `fetch`
+///
+/// Works on the main thread and in Web Workers of a `wasm32-unknown-unknown`
+/// build, or in any other JavaScript host with a global `fetch`. SEC endpoints
+/// don't send CORS headers, so point [`Config::base_url`] at a CORS proxy.
+/// Browsers don't let pages set `User-Agent`, so the proxy should also add the
+/// User-Agent SEC requires.
+///
+/// Only available without the `native` feature.
+///
+/// [`Config::base_url`]: crate::Config::base_url
+#[derive(Debug, Clone, Default)]
+pub struct BrowserClient;
+
+impl BrowserClient {
+    /// Create a new BrowserClient
+    pub fn new() -> Self {
+        Self
+    }
+}
+
+// JavaScript values are tied to the thread that created them, so the futures
+// aren't `Send`; the module is only built without `native` for that reason.
+#[async_trait(?Send)]
+impl HttpClient for BrowserClient {
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+        fetch(url, headers).await
+    }
+}
+
+#[wasm_bindgen]
+extern "C" {
+    /// The global `fetch` of windows, Web Workers and other JavaScript hosts.
+    #[wasm_bindgen(js_name = fetch)]
+    fn global_fetch(request: &Request) -> Promise;
+}
+
+/// Makes a GET request with the fetch API.
+async fn fetch(url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+    let request_headers = Headers::new().map_err(|e| js_error("create headers", e))?;
+    for (name, value) in headers {
+        // Forbidden headers such as User-Agent are dropped by the browser
+        request_headers
+            .set(name, value)
+            .map_err(|e| js_error("set header", e))?;
+    }
+
+    let init = RequestInit::new();
+    init.set_method("GET");
+    init.set_headers(&request_headers);
+    let request =
+        Request::new_with_str_and_init(url, &init).map_err(|e| js_error("create request", e))?;
+
+    let promise = global_fetch(&request);
+    let response: Response = JsFuture::from(promise)
+        .await
+        .map_err(|e| EdgarApiError::network(format!("Fetch error: {:?}", e)))?
+        .unchecked_into();
+
+    let status = response.status();
+
+    // Only CORS-safelisted headers and those the proxy exposes are visible
+    let mut response_headers = HashMap::new();
+    if let Ok(Some(entries)) = js_sys::try_iter(response.headers().as_ref()) {
+        for entry in entries.flatten() {
+            let entry: Array = entry.unchecked_into();
+            if let (Some(name), Some(value)) = (entry.get(0).as_string(), entry.get(1).as_string())
+            {
+                response_headers.insert(name, value);
+            }
+        }
+    }
+
+    let buffer = response
+        .array_buffer()
+        .map_err(|e| js_error("read response body", e))?;
+    let body = JsFuture::from(buffer)
+        .await
+        .map_err(|e| js_error("read response body", e))?;
+
+    Ok(HttpResponse {
+        status,
+        headers: response_headers,
+        body: Uint8Array::new(&body).to_vec(),
+    })
+}
+
+fn js_error(action: &str, error: JsValue) -> EdgarApiError {
+    EdgarApiError::request(format!("Failed to {}: {:?}", action, error))
+}
@@ -0,0 +1,806 @@
+//! Response caching for HTTP clients.
+//!
+//! [`CachingClient`] wraps any [`HttpClient`] and stores successful responses keyed
+//! by URL. Entries younger than the TTL chosen by the [`CachePolicy`] are served
+//! without touching the network; older entries are revalidated with
+//! `If-None-Match` / `If-Modified-Since`, so an unchanged document costs a `304`
+//! instead of a full download.
+
+use async_trait::async_trait;
+use log::{trace, warn};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use crate::error::Result;
+use crate::runtime;
+
+#[cfg(feature = "native")]
+use super::ProgressCallback;
+use super::{HttpClient, HttpResponse};
+
+/// One hour.
+const HOUR: Duration = Duration::from_secs(60 * 60);
+
+/// One day.
+const DAY: Duration = Duration::from_secs(24 * 60 * 60);
+
+/// A cached response.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CacheEntry {
+    /// HTTP status of the cached response
+    pub status: u16,
+    /// Response headers, with lowercase names
+    pub headers: HashMap<String, String>,
+    /// Response body
+    #[serde(skip)]
+    pub body: Vec<u8>,
+    /// When the response was stored or last revalidated, as time since the Unix epoch
+    pub stored_at: Duration,
+}
+
+impl CacheEntry {
+    /// Creates an entry for `response`, stored at `stored_at`.
+    pub fn new(response: &HttpResponse, stored_at: Duration) -> Self {
+        Self {
+            status: response.status,
+            headers: response
+                .headers
+                .iter()
+                .map(|(name, value)| (name.to_ascii_lowercase(), value.clone()))
+                .collect(),
+            body: response.body.clone(),
+            stored_at,
+        }
+    }
+
+    /// The `ETag` validator, if the server sent one.
+    pub fn etag(&self) -> Option<&str> {
+        self.headers.get("etag").map(String::as_str)
+    }
+
+    /// The `Last-Modified` validator, if the server sent one.
+    pub fn last_modified(&self) -> Option<&str> {
+        self.headers.get("last-modified").map(String::as_str)
+    }
+
+    /// Time elapsed between storing the entry and `now`.
+    pub fn age(&self, now: Duration) -> Duration {
+        now.saturating_sub(self.stored_at)
+    }
+
+    fn to_response(&self) -> HttpResponse {
+        HttpResponse {
+            status: self.status,
+            headers: self.headers.clone(),
+            body: self.body.clone(),
+        }
+    }
+}
+
+/// Storage backend for [`CachingClient`].
+///
+/// Backends are synchronous and shared between concurrent requests. Errors are
+/// logged by the client and treated as cache misses, so a broken cache never
+/// fails a request.
+pub trait CacheStore: Send + Sync {
+    /// Returns the entry stored under `key`, if any.
+    fn get(&self, key: &str) -> Result<Option<CacheEntry>>;
+
+    /// Returns the entry stored under `key` with an empty body, if any.
+    ///
+    /// The client checks freshness with it before reading a possibly large body.
+    /// The default reads the whole entry; backends that can read the status,
+    /// headers and storage time alone should override it.
+    fn get_metadata(&self, key: &str) -> Result<Option<CacheEntry>> {
+        Ok(self.get(key)?.map(|mut entry| {
+            entry.body = Vec::new();
+            entry
+        }))
+    }
+
+    /// Stores `entry` under `key`, replacing any previous entry.
+    fn put(&self, key: &str, entry: &CacheEntry) -> Result<()>;
+
+    /// Removes the entry stored under `key`, if any.
+    fn remove(&self, key: &str) -> Result<()>;
+}
+
+impl<S: CacheStore + ?Sized> CacheStore for Arc<S> {
+    fn get(&self, key: &str) -> Result<Option<CacheEntry>> {
+        (**self).get(key)
+    }
+
+    fn get_metadata(&self, key: &str) -> Result<Option<CacheEntry>> {
+        (**self).get_metadata(key)
+    }
+
+    fn put(&self, key: &str, entry: &CacheEntry) -> Result<()> {
+        (**self).put(key, entry)
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+        (**self).remove(key)
+    }
+}
+
+/// An in-memory cache backend.
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    entries: Mutex<HashMap<String, CacheEntry>>,
+}
+
+impl MemoryCache {
+    /// Creates an empty cache.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of cached entries.
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Returns true if the cache holds no entries.
+    pub fn is_empty(&self) -> bool {
+        self.lock().is_empty()
+    }
+
+    /// Removes all entries.
+    pub fn clear(&self) {
+        self.lock().clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, CacheEntry>> {
+        self.entries
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl CacheStore for MemoryCache {
+    fn get(&self, key: &str) -> Result<Option<CacheEntry>> {
+        Ok(self.lock().get(key).cloned())
+    }
+
+    fn get_metadata(&self, key: &str) -> Result<Option<CacheEntry>> {
+        Ok(self.lock().get(key).map(|entry| CacheEntry {
+            status: entry.status,
+            headers: entry.headers.clone(),
+            body: Vec::new(),
+            stored_at: entry.stored_at,
+        }))
+    }
+
+    fn put(&self, key: &str, entry: &CacheEntry) -> Result<()> {
+        self.lock().insert(key.to_string(), entry.clone());
+        Ok(())
+    }
+
+    fn remove(&self, key: &str) -> Result<()> {
+        self.lock().remove(key);
+        Ok(())
+    }
+}
+
+/// TTL rules deciding how long responses are served from the cache.
+///
+/// A rule matches when its pattern occurs anywhere in the request URL. Rules added
+/// later take precedence over earlier ones, and URLs matching no rule use the
+/// default TTL. A TTL of zero still caches the response but revalidates it on
+/// every request.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use edgar_rs::CachePolicy;
+///
+/// let policy = CachePolicy::default()
+///     .with_ttl("/submissions/", Duration::from_secs(60))
+///     .with_no_store("/api/xbrl/frames/");
+///
+/// assert_eq!(
+///     policy.ttl_for("https://data.sec.gov/submissions/CIK0000320193.json"),
+///     Some(Duration::from_secs(60))
+/// );
+/// assert_eq!(
+///     policy.ttl_for("https://data.sec.gov/api/xbrl/frames/us-gaap/Revenues/USD/CY2023.json"),
+///     None
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CachePolicy {
+    rules: Vec<(String, Option<Duration>)>,
+    default_ttl: Option<Duration>,
+}
+
+impl CachePolicy {
+    /// Creates a policy without rules; `None` disables caching of unmatched URLs.
+    pub fn new(default_ttl: Option<Duration>) -> Self {
+        Self {
+            rules: Vec::new(),
+            default_ttl,
+        }
+    }
+
+    /// Serves URLs containing `pattern` from the cache for `ttl` before revalidating.
+    pub fn with_ttl(mut self, pattern: impl Into<String>, ttl: Duration) -> Self {
+        self.rules.push((pattern.into(), Some(ttl)));
+        self
+    }
+
+    /// Never caches URLs containing `pattern`.
+    pub fn with_no_store(mut self, pattern: impl Into<String>) -> Self {
+        self.rules.push((pattern.into(), None));
+        self
+    }
+
+    /// Returns the TTL for `url`, or `None` if its responses must not be cached.
+    pub fn ttl_for(&self, url: &str) -> Option<Duration> {
+        self.rules
+            .iter()
+            .rev()
+            .find(|(pattern, _)| url.contains(pattern.as_str()))
+            .map_or(self.default_ttl, |(_, ttl)| *ttl)
+    }
+}
+
+impl Default for CachePolicy {
+    /// TTLs matching how often SEC updates each endpoint.
+    ///
+    /// Ticker lists are cached for a day, submissions for ten minutes, and XBRL
+    /// company facts, concepts and frames for an hour. Everything else is cached
+    /// but revalidated on every request.
+    fn default() -> Self {
+        Self::new(Some(Duration::ZERO))
+            .with_ttl("/submissions/", Duration::from_secs(10 * 60))
+            .with_ttl("/api/xbrl/", HOUR)
+            .with_ttl("company_tickers", DAY)
+    }
+}
+
+/// An [`HttpClient`] decorator that caches responses.
+///
+/// Only `200` responses are stored, and never when the server sends
+/// `Cache-Control: no-store`. Streaming downloads bypass the cache.
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use edgar_rs::{Cik, CachingClient, Config, DiskCache, EdgarApi, EdgarClient, ReqwestClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let http_client = CachingClient::new(ReqwestClient::new()?, DiskCache::new(".edgar-cache")?);
+///     let config = Config::new("Your Company Name your.email@example.com");
+///     let edgar_api = EdgarClient::with_client(http_client, config);
+///
+///     // Only the first call within the hour downloads the document
+///     for _ in 0..3 {
+///         let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
+///         println!("{}", facts.data.entity_name);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct CachingClient<H, S = MemoryCache> {
+    inner: H,
+    store: S,
+    policy: CachePolicy,
+}
+
+impl<H: HttpClient> CachingClient<H, MemoryCache> {
+    /// Wraps `inner` with an in-memory cache and the default policy.
+    pub fn in_memory(inner: H) -> Self {
+        Self::new(inner, MemoryCache::new())
+    }
+}
+
+impl<H: HttpClient, S: CacheStore> CachingClient<H, S> {
+    /// Wraps `inner`, storing responses in `store` according to the default policy.
+    pub fn new(inner: H, store: S) -> Self {
+        Self {
+            inner,
+            store,
+            policy: CachePolicy::default(),
+        }
+    }
+
+    /// Sets the TTL policy.
+    pub fn with_policy(mut self, policy: CachePolicy) -> Self {
+        self.policy = policy;
+        self
+    }
+
+    /// The wrapped client.
+    pub fn inner(&self) -> &H {
+        &self.inner
+    }
+
+    /// The cache backend.
+    pub fn store(&self) -> &S {
+        &self.store
+    }
+
+    fn load(&self, url: &str) -> Option<CacheEntry> {
+        self.store.get(url).unwrap_or_else(|e| {
+            warn!("Failed to read cached response for {}: {}", url, e);
+            None
+        })
+    }
+
+    fn load_metadata(&self, url: &str) -> Option<CacheEntry> {
+        self.store.get_metadata(url).unwrap_or_else(|e| {
+            warn!("Failed to read cached response for {}: {}", url, e);
+            None
+        })
+    }
+
+    fn save(&self, url: &str, entry: &CacheEntry) {
+        if let Err(e) = self.store.put(url, entry) {
+            warn!("Failed to cache response for {}: {}", url, e);
+        }
+    }
+
+    /// Returns true if `entry` can be served for `url` without revalidating it.
+    fn is_fresh(&self, url: &str, entry: &CacheEntry, now: Duration) -> bool {
+        self.policy
+            .ttl_for(url)
+            .is_some_and(|ttl| entry.age(now) < ttl)
+    }
+
+    /// Returns the cached response for `url` if it is fresh.
+    ///
+    /// Freshness is checked on the entry's metadata first, so a stale entry's body
+    /// is only read once, when it is revalidated.
+    fn fresh_response(&self, url: &str) -> Option<HttpResponse> {
+        let now = runtime::unix_time();
+        if !self
+            .load_metadata(url)
+            .is_some_and(|entry| self.is_fresh(url, &entry, now))
+        {
+            return None;
+        }
+
+        let entry = self
+            .load(url)
+            .filter(|entry| self.is_fresh(url, entry, now))?;
+        trace!("Serving {} from cache", url);
+        Some(entry.to_response())
+    }
+
+    async fn fetch(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+        if self.policy.ttl_for(url).is_none() {
+            return self.inner.get(url, headers).await;
+        }
+        if let Some(response) = self.fresh_response(url) {
+            return Ok(response);
+        }
+
+        let now = runtime::unix_time();
+        let cached = self.load(url);
+
+        // Revalidate the stale entry with its validators
+        let mut request_headers = headers.to_vec();
+        if let Some(entry) = &cached {
+            if let Some(etag) = entry.etag() {
+                request_headers.push(("If-None-Match", etag));
+            }
+            if let Some(last_modified) = entry.last_modified() {
+                request_headers.push(("If-Modified-Since", last_modified));
+            }
+        }
+
+        let response = self.inner.get(url, &request_headers).await?;
+
+        match cached {
+            Some(mut entry) if response.status == 304 => {
+                trace!("Cached response for {} is still valid", url);
+                // A 304 may carry updated validators
+                for (name, value) in &response.headers {
+                    let name = name.to_ascii_lowercase();
+                    if name == "etag" || name == "last-modified" || name == "cache-control" {
+                        entry.headers.insert(name, value.clone());
+                    }
+                }
+                entry.stored_at = now;
+                self.save(url, &entry);
+                Ok(entry.to_response())
+            }
+            _ => {
+                if response.status == 200 && !is_no_store(&response) {
+                    self.save(url, &CacheEntry::new(&response, now));
+                }
+                Ok(response)
+            }
+        }
+    }
+}
+
+/// Returns true if the response forbids storing it.
+fn is_no_store(response: &HttpResponse) -> bool {
+    response.headers.iter().any(|(name, value)| {
+        name.eq_ignore_ascii_case("cache-control")
+            && value
+                .split(',')
+                .any(|directive| directive.trim().eq_ignore_ascii_case("no-store"))
+    })
+}
+
+#[cfg_attr(feature = "native", async_trait)]
+#[cfg_attr(not(feature = "native"), async_trait(?Send))]
+impl<H: HttpClient, S: CacheStore> HttpClient for CachingClient<H, S> {
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+        self.fetch(url, headers).await
+    }
+
+    fn get_cached(&self, url: &str) -> Option<HttpResponse> {
+        self.policy.ttl_for(url)?;
+        self.fresh_response(url)
+    }
+
+    #[cfg(feature = "native")]
+    async fn download(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        writer: &mut (dyn std::io::Write + Send),
+        progress: Option<&ProgressCallback<'_>>,
+    ) -> Result<u64> {
+        self.inner.download(url, headers, writer, progress).await
+    }
+}
+
+#[cfg(feature = "native")]
+pub use disk::DiskCache;
+#[cfg(feature = "native")]
+pub(super) use disk::fnv1a;
+
+#[cfg(feature = "native")]
+mod disk {
+    use std::fs;
+    use std::io::{BufRead, BufReader, Write};
+    use std::path::{Path, PathBuf};
+
+    use serde::{Deserialize, Serialize};
+
+    use super::{CacheEntry, CacheStore};
+    use crate::error::{EdgarApiError, Result};
+
+    /// A cache backend storing one file per URL in a directory.
+    ///
+    /// Each file holds a JSON header line followed by the raw body. Files are
+    /// written to a temporary name and renamed into place, so concurrent readers
+    /// never see a partial entry and the cache survives process restarts.
+    #[derive(Debug, Clone)]
+    pub struct DiskCache {
+        dir: PathBuf,
+    }
+
+    /// Header line of a cache file.
+    #[derive(Serialize, Deserialize)]
+    struct Header {
+        url: String,
+        #[serde(flatten)]
+        entry: CacheEntry,
+    }
+
+    impl DiskCache {
+        /// Opens the cache in `dir`, creating the directory if needed.
+        pub fn new(dir: impl Into<PathBuf>) -> Result<Self> {
+            let dir = dir.into();
+            fs::create_dir_all(&dir)?;
+            Ok(Self { dir })
+        }
+
+        /// The cache directory.
+        pub fn dir(&self) -> &Path {
+            &self.dir
+        }
+
+        fn path_for(&self, key: &str) -> PathBuf {
+            self.dir.join(format!("{:016x}.cache", fnv1a(key)))
+        }
+    }
+
+    /// Parses the header line of the cache file for `key`, which must end with its newline.
+    fn parse_header(line: &[u8], key: &str) -> Result<Option<CacheEntry>> {
+        let line = line
+            .strip_suffix(b"\n")
+            .ok_or_else(|| EdgarApiError::parse("Cache file is missing its header"))?;
+        let header: Header = serde_json::from_slice(line).map_err(EdgarApiError::parse)?;
+
+        // Different URLs may hash to the same file
+        Ok((header.url == key).then_some(header.entry))
+    }
+
+    impl CacheStore for DiskCache {
+        fn get(&self, key: &str) -> Result<Option<CacheEntry>> {
+            let contents = match fs::read(self.path_for(key)) {
+                Ok(contents) => contents,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                Err(e) => return Err(e.into()),
+            };
+
+            let header_len = contents
+                .iter()
+                .position(|&b| b == b'\n')
+                .map_or(contents.len(), |newline| newline + 1);
+            let entry = parse_header(&contents[..header_len], key)?;
+            Ok(entry.map(|mut entry| {
+                entry.body = contents[header_len..].to_vec();
+                entry
+            }))
+        }
+
+        fn get_metadata(&self, key: &str) -> Result<Option<CacheEntry>> {
+            let file = match fs::File::open(self.path_for(key)) {
+                Ok(file) => file,
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+                Err(e) => return Err(e.into()),
+            };
+
+            let mut line = Vec::new();
+            BufReader::new(file).read_until(b'\n', &mut line)?;
+            parse_header(&line, key)
+        }
+
+        fn put(&self, key: &str, entry: &CacheEntry) -> Result<()> {
+            let header = Header {
+                url: key.to_string(),
+                entry: entry.clone(),
+            };
+            let header = serde_json::to_vec(&header).map_err(EdgarApiError::parse)?;
+
+            let path = self.path_for(key);
+            let mut file = tempfile::NamedTempFile::new_in(&self.dir)?;
+            file.write_all(&header)?;
+            file.write_all(b"\n")?;
+            file.write_all(&entry.body)?;
+            file.persist(&path).map_err(|e| e.error)?;
+            Ok(())
+        }
+
+        fn remove(&self, key: &str) -> Result<()> {
+            match fs::remove_file(self.path_for(key)) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e.into()),
+                _ => Ok(()),
+            }
+        }
+    }
+
+    /// 64-bit FNV-1a, which unlike `DefaultHasher` is stable across Rust releases.
+    pub(crate) fn fnv1a(key: &str) -> u64 {
+        key.bytes().fold(0xcbf29ce484222325, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+        })
+    }
+}
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use super::*;
+    use std::collections::VecDeque;
+
+    /// Replays canned responses and records the headers of every request.
+    #[derive(Default)]
+    struct RecordingClient {
+        responses: Mutex<VecDeque<HttpResponse>>,
+        requests: Mutex<Vec<Vec<(String, String)>>>,
+    }
+
+    impl RecordingClient {
+        fn new(responses: Vec<HttpResponse>) -> Self {
+            Self {
+                responses: Mutex::new(responses.into()),
+                requests: Mutex::default(),
+            }
+        }
+
+        fn requests(&self) -> Vec<Vec<(String, String)>> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    #[async_trait]
+    impl HttpClient for RecordingClient {
+        async fn get(&self, _url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+            self.requests.lock().unwrap().push(
+                headers
+                    .iter()
+                    .map(|(k, v)| (k.to_string(), v.to_string()))
+                    .collect(),
+            );
+            Ok(self
+                .responses
+                .lock()
+                .unwrap()
+                .pop_front()
+                .expect("unexpected request"))
+        }
+    }
+
+    fn response(status: u16, headers: &[(&str, &str)], body: &str) -> HttpResponse {
+        HttpResponse {
+            status,
+            headers: headers
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+            body: body.as_bytes().to_vec(),
+        }
+    }
+
+    const FACTS_URL: &str = "https://data.sec.gov/api/xbrl/companyfacts/CIK0000320193.json";
+
+    #[test]
+    fn test_default_policy_ttls() {
+        let policy = CachePolicy::default();
+
+        assert_eq!(
+            policy.ttl_for("https://www.sec.gov/files/company_tickers_exchange.json"),
+            Some(DAY)
+        );
+        assert_eq!(policy.ttl_for(FACTS_URL), Some(HOUR));
+        assert_eq!(
+            policy.ttl_for("https://www.sec.gov/Archives/edgar/data/320193/index.json"),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(CachePolicy::new(None).ttl_for(FACTS_URL), None);
+    }
+
+    #[tokio::test]
+    async fn test_fresh_entries_are_served_from_cache() {
+        let inner = RecordingClient::new(vec![response(200, &[], "facts")]);
+        let client = CachingClient::in_memory(inner);
+
+        for _ in 0..3 {
+            let response = client.get(FACTS_URL, &[]).await.unwrap();
+            assert_eq!(response.body, b"facts");
+        }
+        assert_eq!(client.inner().requests().len(), 1);
+        assert!(client.get_cached(FACTS_URL).is_some());
+    }
+
+    #[tokio::test]
+    async fn test_stale_entries_are_revalidated() {
+        let inner = RecordingClient::new(vec![
+            response(
+                200,
+                &[
+                    ("etag", "\"v1\""),
+                    ("last-modified", "Mon, 01 Jan 2024 00:00:00 GMT"),
+                ],
+                "facts",
+            ),
+            response(304, &[("etag", "\"v1\"")], ""),
+            response(200, &[("etag", "\"v2\"")], "new facts"),
+        ]);
+        let client =
+            CachingClient::in_memory(inner).with_policy(CachePolicy::new(Some(Duration::ZERO)));
+        let headers = [("User-Agent", "test")];
+        assert!(client.get_cached(FACTS_URL).is_none());
+
+        assert_eq!(
+            client.get(FACTS_URL, &headers).await.unwrap().body,
+            b"facts"
+        );
+        assert!(client.get_cached(FACTS_URL).is_none());
+
+        // Not modified: the cached body is returned as a 200
+        let response = client.get(FACTS_URL, &headers).await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, b"facts");
+
+        // Modified: the new body replaces the cached one
+        assert_eq!(
+            client.get(FACTS_URL, &headers).await.unwrap().body,
+            b"new facts"
+        );
+        assert_eq!(
+            client.store().get(FACTS_URL).unwrap().unwrap().etag(),
+            Some("\"v2\"")
+        );
+
+        let requests = client.inner().requests();
+        assert_eq!(requests[0], vec![("User-Agent".into(), "test".into())]);
+        assert!(requests[1].contains(&("If-None-Match".into(), "\"v1\"".into())));
+        assert!(requests[1].contains(&(
+            "If-Modified-Since".into(),
+            "Mon, 01 Jan 2024 00:00:00 GMT".into()
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_uncacheable_responses_are_not_stored() {
+        let inner = RecordingClient::new(vec![
+            response(404, &[], "not found"),
+            response(200, &[("Cache-Control", "private, no-store")], "secret"),
+        ]);
+        let client = CachingClient::in_memory(inner);
+
+        assert_eq!(client.get(FACTS_URL, &[]).await.unwrap().status, 404);
+        assert_eq!(client.get(FACTS_URL, &[]).await.unwrap().body, b"secret");
+        assert!(client.store().is_empty());
+    }
+
+    /// Counts the full reads of a [`MemoryCache`].
+    #[derive(Default)]
+    struct CountingStore {
+        cache: MemoryCache,
+        reads: std::sync::atomic::AtomicUsize,
+    }
+
+    impl CacheStore for CountingStore {
+        fn get(&self, key: &str) -> Result<Option<CacheEntry>> {
+            self.reads.fetch_add(1, std::sync::atomic::Ordering::SeqCst);
+            self.cache.get(key)
+        }
+
+        fn get_metadata(&self, key: &str) -> Result<Option<CacheEntry>> {
+            self.cache.get_metadata(key)
+        }
+
+        fn put(&self, key: &str, entry: &CacheEntry) -> Result<()> {
+            self.cache.put(key, entry)
+        }
+
+        fn remove(&self, key: &str) -> Result<()> {
+            self.cache.remove(key)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_bodies_are_read_once_per_request() {
+        let inner = RecordingClient::new(vec![
+            response(200, &[("etag", "\"v1\"")], "facts"),
+            response(304, &[], ""),
+        ]);
+        let store = Arc::new(CountingStore::default());
+        let reads = || store.reads.load(std::sync::atomic::Ordering::SeqCst);
+        let client = CachingClient::new(inner, store.clone());
+
+        client.get(FACTS_URL, &[]).await.unwrap();
+        assert_eq!(reads(), 1);
+        assert_eq!(client.get_cached(FACTS_URL).unwrap().body, b"facts");
+        assert_eq!(reads(), 2);
+
+        // A stale entry's body is only read to revalidate it
+        let client = client.with_policy(CachePolicy::new(Some(Duration::ZERO)));
+        assert!(client.get_cached(FACTS_URL).is_none());
+        assert_eq!(client.get(FACTS_URL, &[]).await.unwrap().body, b"facts");
+        assert_eq!(reads(), 3);
+    }
+
+    #[test]
+    fn test_disk_cache_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::new(dir.path().join("cache")).unwrap();
+        let entry = CacheEntry::new(
+            &response(200, &[("ETag", "\"v1\"")], "line 1\nline 2"),
+            Duration::from_secs(1_700_000_000),
+        );
+
+        assert_eq!(cache.get(FACTS_URL).unwrap(), None);
+        cache.put(FACTS_URL, &entry).unwrap();
+
+        // A second handle on the same directory sees the entry
+        let reopened = DiskCache::new(cache.dir()).unwrap();
+        let cached = reopened.get(FACTS_URL).unwrap().unwrap();
+        assert_eq!(cached, entry);
+        assert_eq!(cached.etag(), Some("\"v1\""));
+
+        // Metadata is read without the body
+        let metadata = reopened.get_metadata(FACTS_URL).unwrap().unwrap();
+        assert_eq!(metadata.stored_at, entry.stored_at);
+        assert_eq!(metadata.etag(), Some("\"v1\""));
+        assert!(metadata.body.is_empty());
+
+        reopened.remove(FACTS_URL).unwrap();
+        assert_eq!(cache.get(FACTS_URL).unwrap(), None);
+        assert_eq!(cache.get_metadata(FACTS_URL).unwrap(), None);
+        reopened.remove(FACTS_URL).unwrap();
+    }
+}
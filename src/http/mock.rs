@@ -0,0 +1,535 @@
+//! A mock HTTP client for testing code built on [`EdgarClient`](crate::EdgarClient)
+//! without network access.
+//!
+//! [`MockHttpClient`] serves canned responses matched by URL. On native it can also
+//! record the responses of a real client to fixture files and replay them later,
+//! so tests exercise genuine SEC documents while running offline.
+
+use async_trait::async_trait;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use crate::error::{EdgarApiError, Result};
+
+use super::{HttpClient, HttpResponse};
+
+/// What a route answers with.
+#[derive(Debug, Clone)]
+enum MockResponse {
+    Response(HttpResponse),
+    NetworkError(String),
+}
+
+/// An [`HttpClient`] that answers from canned responses instead of the network.
+///
+/// Each response is registered for a URL pattern and served for every URL
+/// containing it, with later patterns taking precedence, as in
+/// [`CachePolicy`](crate::CachePolicy). Recorded fixtures are served only for the
+/// exact URL they were recorded from, ahead of any pattern, so a fixture for the
+/// first page of a search never answers for the second. A request nothing matches
+/// fails with a non-retryable [`EdgarApiError::RequestError`]. Clones share the log
+/// of requested URLs and the fixtures, so a clone kept before handing the client
+/// to an [`EdgarClient`](crate::EdgarClient) can inspect what was fetched.
+///
+/// # Example
+///
+/// ```rust
+/// use edgar_rs::{Cik, Config, EdgarApi, EdgarClient, MockHttpClient};
+/// use serde_json::json;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let http_client = MockHttpClient::new()
+///         .with_status("/companyfacts/", 404)
+///         .with_json(
+///             "/companyfacts/CIK0000320193.json",
+///             json!({"cik": 320193, "entityName": "Apple Inc.", "facts": {}}),
+///         );
+///     let requests = http_client.clone();
+///
+///     let config = Config::new("Your Company Name your.email@example.com").without_rate_limit();
+///     let edgar_api = EdgarClient::with_client(http_client, config);
+///
+///     let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
+///     assert_eq!(facts.data.entity_name, "Apple Inc.");
+///     assert!(edgar_api.get_company_facts(Cik::new(789019)).await.is_err());
+///     assert_eq!(requests.requests().len(), 2);
+///     Ok(())
+/// }
+/// ```
+#[derive(Clone, Default)]
+pub struct MockHttpClient {
+    routes: Vec<(String, MockResponse)>,
+    fixtures: Arc<Mutex<HashMap<String, HttpResponse>>>,
+    requests: Arc<Mutex<Vec<String>>>,
+    #[cfg(feature = "native")]
+    recorder: Option<fixtures::Recorder>,
+}
+
+impl MockHttpClient {
+    /// Creates a client without any responses.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serves `response` for URLs containing `pattern`.
+    pub fn with_response(mut self, pattern: impl Into<String>, response: HttpResponse) -> Self {
+        self.routes
+            .push((pattern.into(), MockResponse::Response(response)));
+        self
+    }
+
+    /// Serves a `200 OK` with `body` for URLs containing `pattern`.
+    pub fn with_body(self, pattern: impl Into<String>, body: impl Into<Vec<u8>>) -> Self {
+        self.with_response(
+            pattern,
+            HttpResponse {
+                status: 200,
+                headers: HashMap::new(),
+                body: body.into(),
+            },
+        )
+    }
+
+    /// Serves a `200 OK` with `value` as a JSON body for URLs containing `pattern`.
+    pub fn with_json(self, pattern: impl Into<String>, value: serde_json::Value) -> Self {
+        let mut response = HttpResponse {
+            status: 200,
+            headers: HashMap::new(),
+            body: value.to_string().into_bytes(),
+        };
+        response
+            .headers
+            .insert("content-type".to_string(), "application/json".to_string());
+        self.with_response(pattern, response)
+    }
+
+    /// Serves an empty response with `status` for URLs containing `pattern`.
+    pub fn with_status(self, pattern: impl Into<String>, status: u16) -> Self {
+        self.with_response(
+            pattern,
+            HttpResponse {
+                status,
+                headers: HashMap::new(),
+                body: Vec::new(),
+            },
+        )
+    }
+
+    /// Fails requests for URLs containing `pattern` with
+    /// [`EdgarApiError::NetworkError`], which the client's retry policy retries.
+    pub fn with_network_error(
+        mut self,
+        pattern: impl Into<String>,
+        message: impl Into<String>,
+    ) -> Self {
+        self.routes
+            .push((pattern.into(), MockResponse::NetworkError(message.into())));
+        self
+    }
+
+    /// The URLs requested so far, in order, including those of clones.
+    pub fn requests(&self) -> Vec<String> {
+        self.lock_requests().clone()
+    }
+
+    fn lock_requests(&self) -> std::sync::MutexGuard<'_, Vec<String>> {
+        // A panic while holding the lock can't leave the log inconsistent
+        self.requests.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn lock_fixtures(&self) -> std::sync::MutexGuard<'_, HashMap<String, HttpResponse>> {
+        self.fixtures.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn find(&self, url: &str) -> Option<&MockResponse> {
+        self.routes
+            .iter()
+            .rev()
+            .find(|(pattern, _)| url.contains(pattern.as_str()))
+            .map(|(_, response)| response)
+    }
+}
+
+impl std::fmt::Debug for MockHttpClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut debug = f.debug_struct("MockHttpClient");
+        debug.field(
+            "patterns",
+            &self
+                .routes
+                .iter()
+                .map(|(pattern, _)| pattern)
+                .collect::<Vec<_>>(),
+        );
+        debug.field("fixtures", &self.lock_fixtures().len());
+        #[cfg(feature = "native")]
+        debug.field(
+            "recording_to",
+            &self.recorder.as_ref().map(|recorder| &recorder.dir),
+        );
+        debug.finish()
+    }
+}
+
+#[cfg_attr(feature = "native", async_trait)]
+#[cfg_attr(not(feature = "native"), async_trait(?Send))]
+impl HttpClient for MockHttpClient {
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+        self.lock_requests().push(url.to_string());
+
+        if let Some(response) = self.lock_fixtures().get(url) {
+            return Ok(response.clone());
+        }
+        match self.find(url) {
+            Some(MockResponse::Response(response)) => return Ok(response.clone()),
+            Some(MockResponse::NetworkError(message)) => {
+                return Err(EdgarApiError::network(message))
+            }
+            None => {}
+        }
+
+        #[cfg(feature = "native")]
+        if let Some(recorder) = &self.recorder {
+            let response = recorder.fetch(url, headers).await?;
+            self.lock_fixtures()
+                .insert(url.to_string(), response.clone());
+            return Ok(response);
+        }
+        #[cfg(not(feature = "native"))]
+        let _ = headers;
+
+        Err(EdgarApiError::request(format!(
+            "No mock response for {}",
+            url
+        )))
+    }
+}
+
+#[cfg(feature = "native")]
+mod fixtures {
+    use std::collections::HashMap;
+    use std::fs;
+    use std::io::Write;
+    use std::path::{Path, PathBuf};
+    use std::sync::Arc;
+
+    use log::trace;
+    use serde::{Deserialize, Serialize};
+
+    use super::MockHttpClient;
+    use crate::error::{EdgarApiError, Result};
+    use crate::http::cache::fnv1a;
+    use crate::http::{HttpClient, HttpResponse};
+
+    /// Extension of fixture files.
+    const EXTENSION: &str = "fixture";
+
+    /// Longest file name stem derived from a URL before it is shortened with a hash.
+    const MAX_STEM_LEN: usize = 120;
+
+    /// Header line of a fixture file.
+    #[derive(Serialize, Deserialize)]
+    struct Header {
+        url: String,
+        status: u16,
+        #[serde(default)]
+        headers: HashMap<String, String>,
+    }
+
+    /// Forwards unmatched requests to a real client and saves the responses.
+    #[derive(Clone)]
+    pub(super) struct Recorder {
+        inner: Arc<dyn HttpClient>,
+        pub(super) dir: PathBuf,
+    }
+
+    impl Recorder {
+        pub(super) async fn fetch(
+            &self,
+            url: &str,
+            headers: &[(&str, &str)],
+        ) -> Result<HttpResponse> {
+            let response = self.inner.get(url, headers).await?;
+            let path = self.dir.join(file_name(url));
+            trace!("Recording {} to {}", url, path.display());
+            write_fixture(&path, url, &response)?;
+            Ok(response)
+        }
+    }
+
+    impl MockHttpClient {
+        /// Loads every fixture in `dir` for replay, each served only for the exact
+        /// URL it was recorded from.
+        ///
+        /// A fixture file holds a JSON header line with the `url`, `status` and
+        /// optional `headers` of the response, followed by the raw body, so
+        /// fixtures can also be written by hand.
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the directory can't be read or a fixture is malformed.
+        pub fn from_fixtures(dir: impl AsRef<Path>) -> Result<Self> {
+            let mut entries = fs::read_dir(dir.as_ref())?
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<std::io::Result<Vec<_>>>()?;
+            entries.sort();
+
+            let client = Self::new();
+            for path in entries {
+                if path.extension().and_then(|ext| ext.to_str()) != Some(EXTENSION) {
+                    continue;
+                }
+                let (url, response) = read_fixture(&path)?;
+                client.lock_fixtures().insert(url, response);
+            }
+            Ok(client)
+        }
+
+        /// Loads the fixtures in `dir` and records a fixture for every other URL
+        /// by fetching it with `inner`.
+        ///
+        /// Point `inner` at a [`ReqwestClient`](crate::ReqwestClient) once to
+        /// capture the live responses, then replay them with
+        /// [`MockHttpClient::from_fixtures`]. Failed requests aren't recorded;
+        /// error responses such as a `404` are.
+        ///
+        /// # Example
+        ///
+        /// ```rust,no_run
+        /// use edgar_rs::{Config, EdgarClient, MockHttpClient, ReqwestClient};
+        ///
+        /// # fn main() -> Result<(), Box<dyn std::error::Error>> {
+        /// let http_client = if std::env::var_os("RECORD_FIXTURES").is_some() {
+        ///     MockHttpClient::record(ReqwestClient::new()?, "tests/fixtures")?
+        /// } else {
+        ///     MockHttpClient::from_fixtures("tests/fixtures")?
+        /// };
+        /// let config = Config::new("Your Company Name your.email@example.com");
+        /// let edgar_api = EdgarClient::with_client(http_client, config);
+        /// # Ok(())
+        /// # }
+        /// ```
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the directory can't be created or holds a malformed
+        /// fixture.
+        pub fn record(inner: impl HttpClient + 'static, dir: impl Into<PathBuf>) -> Result<Self> {
+            let dir = dir.into();
+            fs::create_dir_all(&dir)?;
+            let mut client = Self::from_fixtures(&dir)?;
+            client.recorder = Some(Recorder {
+                inner: Arc::new(inner),
+                dir,
+            });
+            Ok(client)
+        }
+    }
+
+    fn read_fixture(path: &Path) -> Result<(String, HttpResponse)> {
+        let contents = fs::read(path)?;
+        let malformed = |message: &dyn std::fmt::Display| {
+            EdgarApiError::parse(format!("Fixture {}: {}", path.display(), message))
+        };
+
+        let newline = contents
+            .iter()
+            .position(|&b| b == b'\n')
+            .ok_or_else(|| malformed(&"missing header line"))?;
+        let header: Header =
+            serde_json::from_slice(&contents[..newline]).map_err(|e| malformed(&e))?;
+
+        let response = HttpResponse {
+            status: header.status,
+            headers: header.headers,
+            body: contents[newline + 1..].to_vec(),
+        };
+        Ok((header.url, response))
+    }
+
+    fn write_fixture(path: &Path, url: &str, response: &HttpResponse) -> Result<()> {
+        let header = Header {
+            url: url.to_string(),
+            status: response.status,
+            headers: response.headers.clone(),
+        };
+        let header = serde_json::to_vec(&header).map_err(EdgarApiError::parse)?;
+
+        let dir = path.parent().unwrap_or_else(|| Path::new("."));
+        let mut file = tempfile::NamedTempFile::new_in(dir)?;
+        file.write_all(&header)?;
+        file.write_all(b"\n")?;
+        file.write_all(&response.body)?;
+        file.persist(path).map_err(|e| e.error)?;
+        Ok(())
+    }
+
+    /// Returns a readable file name for `url`, such as
+    /// `data.sec.gov_submissions_CIK0000320193.json.fixture`.
+    fn file_name(url: &str) -> String {
+        let trimmed = url
+            .strip_prefix("https://")
+            .or_else(|| url.strip_prefix("http://"))
+            .unwrap_or(url);
+        let mut stem: String = trimmed
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || matches!(c, '.' | '-') {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        if stem.len() > MAX_STEM_LEN {
+            stem.truncate(MAX_STEM_LEN);
+            stem = format!("{}-{:016x}", stem, fnv1a(url));
+        }
+        format!("{}.{}", stem, EXTENSION)
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_file_name() {
+            assert_eq!(
+                file_name("https://data.sec.gov/submissions/CIK0000320193.json"),
+                "data.sec.gov_submissions_CIK0000320193.json.fixture"
+            );
+            let long = format!(
+                "https://efts.sec.gov/LATEST/search-index?q={}",
+                "a".repeat(200)
+            );
+            assert!(file_name(&long).len() < 160);
+            assert_ne!(file_name(&long), file_name(&format!("{}b", long)));
+        }
+
+        #[tokio::test]
+        async fn test_write_fixture_round_trip() {
+            let dir = tempfile::tempdir().unwrap();
+            let url = "https://data.sec.gov/submissions/CIK0000000001.json";
+            let response = HttpResponse {
+                status: 404,
+                headers: HashMap::from([
+                    ("content-type".to_string(), "application/xml".to_string()),
+                    ("etag".to_string(), "\"abc\"".to_string()),
+                ]),
+                body: b"<Error>\n  <Code>NoSuchKey</Code>\xff\n</Error>\n".to_vec(),
+            };
+            write_fixture(&dir.path().join(file_name(url)), url, &response).unwrap();
+
+            let replay = MockHttpClient::from_fixtures(dir.path()).unwrap();
+            let replayed = replay.get(url, &[]).await.unwrap();
+            assert_eq!(replayed.status, response.status);
+            assert_eq!(replayed.headers, response.headers);
+            assert_eq!(replayed.body, response.body);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "native"))]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    const FACTS_URL: &str = "https://data.sec.gov/api/xbrl/companyfacts/CIK0000320193.json";
+
+    #[tokio::test]
+    async fn test_routes() {
+        let client = MockHttpClient::new()
+            .with_status("data.sec.gov", 404)
+            .with_json("/companyfacts/", json!({"cik": 320193}))
+            .with_network_error("/frames/", "connection reset");
+
+        let response = client.get(FACTS_URL, &[]).await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.json::<serde_json::Value>().unwrap()["cik"], 320193);
+
+        let response = client
+            .get("https://data.sec.gov/submissions/CIK0000320193.json", &[])
+            .await
+            .unwrap();
+        assert_eq!(response.status, 404);
+
+        let error = client
+            .get(
+                "https://data.sec.gov/api/xbrl/frames/us-gaap/Revenues/USD/CY2023.json",
+                &[],
+            )
+            .await
+            .unwrap_err();
+        assert!(error.is_transient());
+
+        let error = client
+            .get("https://www.sec.gov/files/company_tickers.json", &[])
+            .await
+            .unwrap_err();
+        assert!(matches!(error, EdgarApiError::RequestError(_)));
+        assert_eq!(client.clone().requests().len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let live = MockHttpClient::new().with_body(FACTS_URL, "facts\nwith newline");
+
+        let recorder = MockHttpClient::record(live.clone(), dir.path()).unwrap();
+        assert_eq!(
+            recorder.get(FACTS_URL, &[]).await.unwrap().body,
+            b"facts\nwith newline"
+        );
+        assert!(recorder
+            .get("https://www.sec.gov/unknown", &[])
+            .await
+            .is_err());
+        assert_eq!(live.requests().len(), 2);
+
+        let replay = MockHttpClient::from_fixtures(dir.path()).unwrap();
+        let response = replay.get(FACTS_URL, &[]).await.unwrap();
+        assert_eq!(response.status, 200);
+        assert_eq!(response.body, b"facts\nwith newline");
+        assert!(replay
+            .get("https://www.sec.gov/unknown", &[])
+            .await
+            .is_err());
+
+        // Recording again serves existing fixtures without fetching them
+        let recorder = MockHttpClient::record(live.clone(), dir.path()).unwrap();
+        recorder.get(FACTS_URL, &[]).await.unwrap();
+        assert_eq!(live.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_fixtures_match_exact_urls() {
+        let dir = tempfile::tempdir().unwrap();
+        let first_page = "https://efts.sec.gov/LATEST/search-index?q=apple";
+        let second_page = "https://efts.sec.gov/LATEST/search-index?q=apple&from=100";
+        let live = MockHttpClient::new()
+            .with_body(first_page, "page 1")
+            .with_body(second_page, "page 2");
+
+        let recorder = MockHttpClient::record(live.clone(), dir.path()).unwrap();
+        assert_eq!(recorder.get(first_page, &[]).await.unwrap().body, b"page 1");
+        assert_eq!(
+            recorder.get(second_page, &[]).await.unwrap().body,
+            b"page 2"
+        );
+        // A URL recorded in this session is replayed without fetching it again
+        assert_eq!(recorder.get(first_page, &[]).await.unwrap().body, b"page 1");
+        assert_eq!(live.requests(), vec![first_page, second_page]);
+
+        let replay = MockHttpClient::from_fixtures(dir.path()).unwrap();
+        assert_eq!(replay.get(first_page, &[]).await.unwrap().body, b"page 1");
+        assert_eq!(replay.get(second_page, &[]).await.unwrap().body, b"page 2");
+        assert!(replay
+            .get(
+                "https://efts.sec.gov/LATEST/search-index?q=apple%20pie",
+                &[]
+            )
+            .await
+            .is_err());
+    }
+}
@@ -1,36 +1,48 @@
 //! Native HTTP client implementation using reqwest.
 
 use async_trait::async_trait;
-use log::{error, trace};
+use log::{debug, trace};
 use reqwest::{Client, Proxy};
 use std::collections::HashMap;
 use std::env;
+use std::io::Write;
 use std::time::Duration;
 
 use crate::error::{EdgarApiError, Result};
 
-use super::{HttpClient, HttpResponse};
+use super::{DownloadProgress, HttpClient, HttpResponse, ProgressCallback};
+
+/// Timeout for regular requests, covering connection and the whole response body.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Maximum time a streaming download may go without receiving any data.
+const DOWNLOAD_IDLE_TIMEOUT: Duration = Duration::from_secs(60);
 
 /// HTTP client implementation using reqwest
+#[derive(Clone)]
 pub struct ReqwestClient {
     client: Client,
+    /// Per-request timeout for regular requests; `None` leaves it to the reqwest client.
+    timeout: Option<Duration>,
 }
 
 impl ReqwestClient {
     /// Create a new ReqwestClient with default settings
     pub fn new() -> Result<Self> {
-        let mut builder = Client::builder().timeout(Duration::from_secs(30));
+        // Only the connection gets a global timeout: streaming downloads of bulk
+        // archives take far longer than a regular request.
+        let mut builder = Client::builder().connect_timeout(REQUEST_TIMEOUT);
 
         // Check for proxy environment variables and configure if present
         if let Ok(proxy_url) = env::var("HTTP_PROXY").or_else(|_| env::var("http_proxy")) {
             trace!("Configuring HTTP proxy: {}", proxy_url);
-            let proxy = Proxy::http(&proxy_url).map_err(|e| EdgarApiError::network(e))?;
+            let proxy = Proxy::http(&proxy_url).map_err(EdgarApiError::network)?;
             builder = builder.proxy(proxy);
         }
 
         if let Ok(proxy_url) = env::var("HTTPS_PROXY").or_else(|_| env::var("https_proxy")) {
             trace!("Configuring HTTPS proxy: {}", proxy_url);
-            let proxy = Proxy::https(&proxy_url).map_err(|e| EdgarApiError::network(e))?;
+            let proxy = Proxy::https(&proxy_url).map_err(EdgarApiError::network)?;
             builder = builder.proxy(proxy);
         }
 
@@ -40,31 +52,45 @@ impl ReqwestClient {
             builder = builder.danger_accept_invalid_certs(true);
         }
 
-        let client = builder.build().map_err(|e| EdgarApiError::network(e))?;
+        let client = builder.build().map_err(EdgarApiError::network)?;
 
-        Ok(Self { client })
+        Ok(Self {
+            client,
+            timeout: Some(REQUEST_TIMEOUT),
+        })
     }
 
     /// Create a new ReqwestClient with custom settings
+    ///
+    /// Timeouts configured on `client` apply to every request, including streaming downloads.
     pub fn with_client(client: Client) -> Self {
-        Self { client }
+        Self {
+            client,
+            timeout: None,
+        }
     }
-}
 
-#[async_trait]
-impl HttpClient for ReqwestClient {
-    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+    /// Sends a GET request and returns the response once its headers have arrived.
+    async fn send(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        timeout: Option<Duration>,
+    ) -> Result<(u16, HashMap<String, String>, reqwest::Response)> {
         trace!("Starting HTTP request to {}", url);
 
         // Build request
         let mut request_builder = self.client.get(url);
+        if let Some(timeout) = timeout {
+            request_builder = request_builder.timeout(timeout);
+        }
         for (key, value) in headers {
             request_builder = request_builder.header(*key, *value);
         }
 
         trace!("Sending GET request to {}", url);
         let response = request_builder.send().await.map_err(|e| {
-            error!("Network error while requesting {}: {}", url, e);
+            debug!("Network error while requesting {}: {}", url, e);
             EdgarApiError::network(e)
         })?;
 
@@ -85,16 +111,25 @@ impl HttpClient for ReqwestClient {
                 .get("retry-after")
                 .and_then(|s| s.parse::<u64>().ok());
 
-            error!(
+            debug!(
                 "Rate limited by API (status 429). Retry-After: {:?}",
                 retry_after
             );
             return Err(EdgarApiError::rate_limit(retry_after));
         }
 
+        Ok((status, response_headers, response))
+    }
+}
+
+#[async_trait]
+impl HttpClient for ReqwestClient {
+    async fn get(&self, url: &str, headers: &[(&str, &str)]) -> Result<HttpResponse> {
+        let (status, response_headers, response) = self.send(url, headers, self.timeout).await?;
+
         // Get response body
         let body = response.bytes().await.map_err(|e| {
-            error!("Failed to read response body from {}: {}", url, e);
+            debug!("Failed to read response body from {}: {}", url, e);
             EdgarApiError::network(e)
         })?;
 
@@ -105,6 +140,55 @@ impl HttpClient for ReqwestClient {
             body: body.to_vec(),
         })
     }
+
+    async fn download(
+        &self,
+        url: &str,
+        headers: &[(&str, &str)],
+        writer: &mut (dyn Write + Send),
+        progress: Option<&ProgressCallback<'_>>,
+    ) -> Result<u64> {
+        let (status, _, mut response) = self.send(url, headers, None).await?;
+        if !(200..300).contains(&status) {
+            return Err(EdgarApiError::api(
+                status,
+                format!("Request to {} failed with status {}", url, status),
+            ));
+        }
+
+        let total = response.content_length();
+        let mut downloaded = 0u64;
+
+        loop {
+            let chunk = tokio::time::timeout(DOWNLOAD_IDLE_TIMEOUT, response.chunk())
+                .await
+                .map_err(|_| {
+                    debug!("Download from {} stalled after {} bytes", url, downloaded);
+                    EdgarApiError::network(format!(
+                        "No data received from {} for {:?}",
+                        url, DOWNLOAD_IDLE_TIMEOUT
+                    ))
+                })?
+                .map_err(|e| {
+                    debug!("Failed to read response body from {}: {}", url, e);
+                    EdgarApiError::network(e)
+                })?;
+
+            let Some(chunk) = chunk else {
+                break;
+            };
+
+            writer.write_all(&chunk)?;
+            downloaded += chunk.len() as u64;
+
+            if let Some(progress) = progress {
+                progress(DownloadProgress { downloaded, total });
+            }
+        }
+
+        trace!("Downloaded {} bytes from {}", downloaded, url);
+        Ok(downloaded)
+    }
 }
 
 impl Default for ReqwestClient {
@@ -0,0 +1,369 @@
+//! Validated EDGAR identifiers.
+//!
+//! This module contains the [`Cik`] and [`AccessionNumber`] types used by the
+//! `EdgarApi` trait and the response models.
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde::de::{self, Deserializer, Visitor};
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::error::{EdgarApiError, Result};
+
+/// Largest value that fits in a 10-digit CIK.
+const MAX_CIK: u64 = 9_999_999_999;
+
+/// A Central Index Key, the identifier EDGAR assigns to every filer.
+///
+/// Displays as the 10-digit, zero-padded form used by the data.sec.gov APIs.
+/// Parses from strings with or without leading zeros or a "CIK" prefix, and
+/// deserializes from both JSON numbers and strings.
+///
+/// # Example
+///
+/// ```
+/// use edgar_rs::Cik;
+///
+/// let cik: Cik = "CIK0000320193".parse().unwrap();
+/// assert_eq!(cik, Cik::new(320193));
+/// assert_eq!(cik.to_string(), "0000320193");
+/// assert_eq!(cik.value(), 320193);
+/// assert!("not a cik".parse::<Cik>().is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Cik(u64);
+
+impl Cik {
+    /// Creates a CIK from its numeric value. Every `u32` fits in 10 digits.
+    pub const fn new(cik: u32) -> Self {
+        Cik(cik as u64)
+    }
+
+    /// The numeric value, without leading zeros.
+    pub fn value(&self) -> u64 {
+        self.0
+    }
+}
+
+impl fmt::Display for Cik {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:010}", self.0)
+    }
+}
+
+impl FromStr for Cik {
+    type Err = EdgarApiError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let trimmed = s.trim();
+        let digits = match trimmed.get(..3) {
+            Some(prefix) if prefix.eq_ignore_ascii_case("cik") => &trimmed[3..],
+            _ => trimmed,
+        };
+
+        if digits.is_empty() || digits.len() > 10 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(EdgarApiError::invalid_cik(s));
+        }
+        digits
+            .parse()
+            .map(Cik)
+            .map_err(|_| EdgarApiError::invalid_cik(s))
+    }
+}
+
+impl TryFrom<u64> for Cik {
+    type Error = EdgarApiError;
+
+    fn try_from(cik: u64) -> Result<Self> {
+        if cik > MAX_CIK {
+            return Err(EdgarApiError::invalid_cik(cik));
+        }
+        Ok(Cik(cik))
+    }
+}
+
+impl TryFrom<&str> for Cik {
+    type Error = EdgarApiError;
+
+    fn try_from(cik: &str) -> Result<Self> {
+        cik.parse()
+    }
+}
+
+impl From<u32> for Cik {
+    fn from(cik: u32) -> Self {
+        Cik::new(cik)
+    }
+}
+
+impl From<Cik> for u64 {
+    fn from(cik: Cik) -> Self {
+        cik.0
+    }
+}
+
+impl PartialEq<u64> for Cik {
+    fn eq(&self, other: &u64) -> bool {
+        self.0 == *other
+    }
+}
+
+impl PartialEq<&str> for Cik {
+    fn eq(&self, other: &&str) -> bool {
+        other.parse::<Cik>().is_ok_and(|cik| cik == *self)
+    }
+}
+
+impl Serialize for Cik {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for Cik {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        struct CikVisitor;
+
+        impl Visitor<'_> for CikVisitor {
+            type Value = Cik;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a CIK as a number or a string")
+            }
+
+            fn visit_u64<E: de::Error>(self, value: u64) -> std::result::Result<Cik, E> {
+                Cik::try_from(value).map_err(E::custom)
+            }
+
+            fn visit_i64<E: de::Error>(self, value: i64) -> std::result::Result<Cik, E> {
+                u64::try_from(value)
+                    .map_err(E::custom)
+                    .and_then(|value| self.visit_u64(value))
+            }
+
+            fn visit_str<E: de::Error>(self, value: &str) -> std::result::Result<Cik, E> {
+                value.parse().map_err(E::custom)
+            }
+        }
+
+        deserializer.deserialize_any(CikVisitor)
+    }
+}
+
+/// An accession number, the identifier EDGAR assigns to every filing.
+///
+/// It consists of the 10-digit CIK of the entity that submitted the filing
+/// (often a filing agent rather than the company), a two-digit year and a
+/// six-digit sequence number. Displays and serializes in the dashed form used by
+/// the JSON APIs, "0000320193-23-000106"; Archives paths use the undashed form.
+///
+/// # Example
+///
+/// ```
+/// use edgar_rs::{AccessionNumber, Cik};
+///
+/// let accession: AccessionNumber = "000032019323000106".parse().unwrap();
+/// assert_eq!(accession.to_string(), "0000320193-23-000106");
+/// assert_eq!(accession.undashed(), "000032019323000106");
+/// assert_eq!(accession.filer_id(), Cik::new(320193));
+/// assert_eq!(accession, "0000320193-23-000106");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct AccessionNumber {
+    filer_id: Cik,
+    year: u8,
+    sequence: u32,
+}
+
+impl AccessionNumber {
+    /// The CIK of the entity that submitted the filing.
+    pub fn filer_id(&self) -> Cik {
+        self.filer_id
+    }
+
+    /// The last two digits of the year the filing was submitted.
+    pub fn year(&self) -> u8 {
+        self.year
+    }
+
+    /// The sequence number of the filing within the filer's submissions that year.
+    pub fn sequence(&self) -> u32 {
+        self.sequence
+    }
+
+    /// The 18-digit form without dashes, as used in Archives paths.
+    pub fn undashed(&self) -> String {
+        format!(
+            "{:010}{:02}{:06}",
+            self.filer_id.0, self.year, self.sequence
+        )
+    }
+
+    /// The Archives URL of the filing's directory for the company `cik`, ending with a slash.
+    pub fn directory_url(&self, cik: Cik) -> String {
+        format!(
+            "https://www.sec.gov/Archives/edgar/data/{}/{}/",
+            cik.value(),
+            self.undashed()
+        )
+    }
+}
+
+impl fmt::Display for AccessionNumber {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:010}-{:02}-{:06}",
+            self.filer_id.0, self.year, self.sequence
+        )
+    }
+}
+
+impl FromStr for AccessionNumber {
+    type Err = EdgarApiError;
+
+    /// Parses the dashed or the undashed form.
+    fn from_str(s: &str) -> Result<Self> {
+        let trimmed = s.trim();
+        let digits: String = match trimmed.len() {
+            20 if trimmed.as_bytes()[10] == b'-' && trimmed.as_bytes()[13] == b'-' => {
+                trimmed.chars().filter(|c| *c != '-').collect()
+            }
+            18 => trimmed.to_string(),
+            _ => return Err(EdgarApiError::invalid_accession_number(s)),
+        };
+        if digits.len() != 18 || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(EdgarApiError::invalid_accession_number(s));
+        }
+
+        let invalid = |_| EdgarApiError::invalid_accession_number(s);
+        Ok(AccessionNumber {
+            filer_id: Cik(digits[..10].parse().map_err(invalid)?),
+            year: digits[10..12].parse().map_err(invalid)?,
+            sequence: digits[12..].parse().map_err(invalid)?,
+        })
+    }
+}
+
+impl TryFrom<&str> for AccessionNumber {
+    type Error = EdgarApiError;
+
+    fn try_from(accession: &str) -> Result<Self> {
+        accession.parse()
+    }
+}
+
+impl PartialEq<&str> for AccessionNumber {
+    fn eq(&self, other: &&str) -> bool {
+        other
+            .parse::<AccessionNumber>()
+            .is_ok_and(|accession| accession == *self)
+    }
+}
+
+impl Serialize for AccessionNumber {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for AccessionNumber {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let accession = String::deserialize(deserializer)?;
+        accession.parse().map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_cik() {
+        let test_cases = [
+            ("320193", 320193),
+            ("0000320193", 320193),
+            ("000320193", 320193),
+            (" 320193 ", 320193),
+            ("CIK0000320193", 320193),
+            ("cik1", 1),
+            ("123456789", 123456789),
+            ("9999999999", 9999999999),
+        ];
+
+        for (input, expected) in test_cases {
+            assert_eq!(
+                input.parse::<Cik>().unwrap(),
+                expected,
+                "Failed for input: {}",
+                input
+            );
+        }
+        assert_eq!(Cik::new(1).to_string(), "0000000001");
+    }
+
+    #[test]
+    fn test_parse_cik_errors() {
+        for input in ["", "   ", "abcdef", "CIK", "12345678901", "320193-", "-1"] {
+            assert!(
+                matches!(input.parse::<Cik>(), Err(EdgarApiError::InvalidCik(_))),
+                "Expected error for input: {}",
+                input
+            );
+        }
+        assert!(Cik::try_from(10_000_000_000u64).is_err());
+    }
+
+    #[test]
+    fn test_cik_serde() {
+        let ciks: Vec<Cik> = serde_json::from_str(r#"[320193, "320193", "0000320193"]"#).unwrap();
+        assert!(ciks.iter().all(|cik| *cik == Cik::new(320193)));
+        assert_eq!(serde_json::to_string(&ciks[0]).unwrap(), "320193");
+
+        assert!(serde_json::from_str::<Cik>("-1").is_err());
+        assert!(serde_json::from_str::<Cik>(r#""apple""#).is_err());
+    }
+
+    #[test]
+    fn test_parse_accession_number() {
+        let dashed: AccessionNumber = "0000320193-23-000106".parse().unwrap();
+        let undashed: AccessionNumber = "000032019323000106".parse().unwrap();
+        assert_eq!(dashed, undashed);
+        assert_eq!(dashed.to_string(), "0000320193-23-000106");
+        assert_eq!(dashed.undashed(), "000032019323000106");
+        assert_eq!(dashed.filer_id(), Cik::new(320193));
+        assert_eq!(dashed.year(), 23);
+        assert_eq!(dashed.sequence(), 106);
+
+        for input in [
+            "",
+            "0000320193-23-0001",
+            "0000320193-23-00010x",
+            "000032019-323-000106",
+            "0000320193--23000106",
+        ] {
+            assert!(
+                matches!(
+                    input.parse::<AccessionNumber>(),
+                    Err(EdgarApiError::InvalidAccessionNumber(_))
+                ),
+                "Expected error for input: {}",
+                input
+            );
+        }
+    }
+
+    #[test]
+    fn test_accession_number_serde_and_urls() {
+        let accession: AccessionNumber = serde_json::from_str(r#""0000320193-23-000106""#).unwrap();
+        assert_eq!(
+            serde_json::to_string(&accession).unwrap(),
+            r#""0000320193-23-000106""#
+        );
+        assert_eq!(
+            accession.directory_url(Cik::new(320193)),
+            "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/"
+        );
+    }
+}
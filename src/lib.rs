@@ -11,12 +11,33 @@
 //! - Fetch XBRL frames data
 //! - Get company tickers exchange data
 //! - Get mutual fund tickers data
+//! - Validated `Cik` and `AccessionNumber` identifiers with serde support
+//! - Resolve tickers, fund symbols and company names to CIKs
+//! - Typed form types that group amendments with the forms they amend
+//! - Collapse re-reported XBRL values and track restatements
+//! - Point-in-time views of company facts for look-ahead-free backtests
+//! - Standardized income statements, balance sheets and cash flow statements
+//! - Discrete quarterly values derived from year-to-date and annual figures
+//! - Fiscal calendars and trailing-twelve-month values aligned to calendar frames
+//! - Financial ratios and custom metrics with source tracking and diagnostics
+//! - Typed filing, period and acceptance dates with the `chrono` feature
+//! - Search the full text of filings
+//! - List and download the documents of a filing
+//! - Fetch and parse the daily and quarterly form, company and master indexes
+//! - Follow the latest filings feed, or poll it as a de-duplicated stream on native
 //! - Download bulk submissions and company facts data
+//! - Read bulk company facts straight from the downloaded ZIP archive
+//! - Serve the `EdgarApi` offline from an extracted bulk snapshot
+//! - Cache responses in memory or on disk with ETag/Last-Modified revalidation
+//! - Fetch many companies concurrently within the rate limit on native
+//! - Call the API synchronously with `BlockingEdgarClient` on native
+//! - Run in the browser on `wasm32-unknown-unknown` with `BrowserClient` and a CORS proxy
+//! - Test offline against canned responses or recorded fixtures with `MockHttpClient`
 //!
 //! ## Example
 //!
 //! ```rust,no_run
-//! use edgar_rs::{EdgarApi, EdgarClient, Config, Taxonomy, Unit, Period};
+//! use edgar_rs::{Cik, EdgarApi, EdgarClient, Config, Taxonomy, Unit, Period};
 //!
 //! #[tokio::main]
 //! async fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -25,7 +46,7 @@
 //!     let edgar_api = EdgarClient::new(config)?;
 //!     
 //!     // Get submissions history for Apple Inc. (CIK: 0000320193)
-//!     let submissions = edgar_api.get_submissions_history("0000320193").await?;
+//!     let submissions = edgar_api.get_submissions_history(Cik::new(320193)).await?;
 //!     println!("Apple Inc. submissions status: {}", submissions.status);
 //!     
 //!     // Get XBRL frames for AccountsPayableCurrent in USD for Q1 2019
@@ -51,37 +72,111 @@
 //! }
 //! ```
 
+// The browser client's futures can't be `Send`, which the native runtime requires
+#[cfg(all(target_arch = "wasm32", feature = "native", feature = "wasm-browser"))]
+compile_error!(
+    "the `wasm-browser` feature can't be combined with `native`; disable default features"
+);
+
 // Re-export main components
+pub use analysis::{
+    deduplicate_values, quarterly_values, restatement_histories, trailing_twelve_months,
+    values_as_of, Concept, DedupPolicy, FiscalCalendar, Formula, LineItem, Metric,
+    MetricDiagnostic, MetricEngine, MetricInput, MetricSeries, MetricSource, MetricValue,
+    QuarterSource, QuarterlyValue, ReportedValue, ReportingPeriod, RestatementHistory, Statement,
+    StatementBuilder, StatementKind, StatementPeriod, StatementValue, TtmValue, UnitKind,
+};
 pub use api::EdgarApi;
 pub use client::EdgarClient;
-pub use config::Config;
+pub use config::{Config, DEFAULT_CIK_RESOLVER_TTL};
 pub use error::{EdgarApiError, Result};
+pub use form_type::FormType;
+pub use identifiers::{AccessionNumber, Cik};
+pub use rate_limit::{RateLimit, SEC_MAX_REQUESTS_PER_SECOND};
+pub use resolver::CikResolver;
+pub use retry::RetryPolicy;
+
+// Re-export bulk data types
+#[cfg(feature = "native")]
+pub use bulk::{CompanyFactsArchive, CompanyFactsEntries, LocalBulkStore};
+
+// Re-export batch fetching defaults
+#[cfg(feature = "native")]
+pub use batch::DEFAULT_BATCH_CONCURRENCY;
+
+// Re-export the blocking client
+#[cfg(feature = "native")]
+pub use blocking::BlockingEdgarClient;
+
+// Re-export the latest filings poller
+#[cfg(feature = "native")]
+pub use feed::{CurrentFilingsPoller, DEFAULT_POLL_INTERVAL};
 
 // Re-export HTTP client types
 #[cfg(feature = "native")]
 pub use http::ReqwestClient;
+#[cfg(feature = "native")]
+pub use http::{DownloadProgress, ProgressCallback};
 #[cfg(feature = "cloudflare-workers")]
 pub use http::WorkerClient;
-pub use http::HttpClient;
+#[cfg(all(feature = "wasm-browser", not(feature = "native")))]
+pub use http::BrowserClient;
+pub use http::{HttpClient, MaybeSendSync};
+pub use http::{CacheEntry, CachePolicy, CacheStore, CachingClient, MemoryCache};
+#[cfg(feature = "native")]
+pub use http::DiskCache;
+pub use http::MockHttpClient;
+
+#[cfg(feature = "native")]
+pub use models::edgar_index::read_index_file;
+
+// Re-export date parsing and the chrono version it uses
+#[cfg(feature = "chrono")]
+pub use chrono;
+#[cfg(feature = "chrono")]
+pub use utils::date::{parse_date, parse_datetime};
 
 // Re-export types
-pub use types::{ApiResponse, Period, Taxonomy, Unit};
+pub use types::{ApiResponse, BulkDataset, Period, Taxonomy, Unit};
 
 // Export models
 pub use models::{
-    company_concept::CompanyConcept, company_facts::CompanyFacts,
+    company_concept::{CompanyConcept, ConceptValue},
+    company_facts::{CompanyFacts, Fact, FactValue},
     company_tickers::{CompanyTickers, CompanyTickerEntry},
-    company_tickers_mf::{CompanyTickersMf, MutualFundTickerEntry}, frames::XbrlFrames,
+    company_tickers_mf::{CompanyTickersMf, MutualFundTickerEntry},
+    current_feed::{CurrentFeed, CurrentFeedEntry, CurrentFeedQuery, CURRENT_FEED_MAX_COUNT},
+    edgar_index::{IndexEntry, IndexKind},
+    filing_index::{FilingDirectory, FilingIndex, FilingIndexItem}, frames::XbrlFrames,
+    full_text_search::{
+        FullTextSearchFiling, FullTextSearchHit, FullTextSearchHits, FullTextSearchQuery,
+        FullTextSearchResults, TotalHits, FULL_TEXT_SEARCH_PAGE_SIZE,
+    },
     submission::FilingEntry, submission::SubmissionHistory,
 };
 
 // Modules
+mod analysis;
 mod api;
+#[cfg(feature = "native")]
+mod batch;
+#[cfg(feature = "native")]
+mod blocking;
+#[cfg(feature = "native")]
+mod bulk;
 mod client;
 mod config;
 mod error;
+#[cfg(feature = "native")]
+mod feed;
+mod form_type;
 mod http;
+mod identifiers;
 mod models;
+mod rate_limit;
+mod resolver;
+mod retry;
+mod runtime;
 mod types;
 mod utils;
 
@@ -18,10 +18,9 @@ impl Config {
     }
 
     pub fn build_url(&self, url: &str) -> String {
-        if url.starts_with("https://") {
-            format!("{}{}", self.base_url, &url[8..])
-        } else {
-            url.to_string()
+        match url.strip_prefix("https://") {
+            Some(rest) => format!("{}{}", self.base_url, rest),
+            None => url.to_string(),
         }
     }
 }
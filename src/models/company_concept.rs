@@ -2,28 +2,25 @@
 //!
 //! This module contains data models for the SEC EDGAR API company concept responses.
 
-use serde::{Deserialize, Deserializer, Serialize};
+use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-/// Custom deserializer for CIK that accepts both string and integer values.
-fn deserialize_cik<'de, D>(deserializer: D) -> Result<u64, D::Error>
-where
-    D: Deserializer<'de>,
-{
-    use serde::de::Error;
-
-    #[derive(Deserialize)]
-    #[serde(untagged)]
-    enum CikValue {
-        String(String),
-        Integer(u64),
-    }
-
-    match CikValue::deserialize(deserializer)? {
-        CikValue::String(s) => s.parse().map_err(D::Error::custom),
-        CikValue::Integer(i) => Ok(i),
-    }
-}
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+use crate::error::Result;
+use crate::analysis::{
+    deduplicate_values, parse_as_of_date, quarterly_values, restatement_histories,
+    trailing_twelve_months, values_known_on, DedupPolicy, QuarterlyValue, RestatementHistory,
+    TtmValue,
+};
+#[cfg(feature = "chrono")]
+use crate::analysis::as_of_cutoff;
+use crate::form_type::FormType;
+use crate::identifiers::{AccessionNumber, Cik};
+use crate::utils::date::parse_ymd;
+#[cfg(feature = "chrono")]
+use crate::utils::date::{parse_date, parse_optional_date};
 
 /// A company concept response from the SEC EDGAR API.
 ///
@@ -32,9 +29,8 @@ where
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompanyConcept {
     /// The CIK number of the company.
-    #[serde(deserialize_with = "deserialize_cik")]
-    pub cik: u64,
-    //pub cik: String,
+    pub cik: Cik,
+
     /// The entity name.
     #[serde(rename = "entityName")]
     pub entity_name: String,
@@ -65,7 +61,7 @@ pub struct ConceptValue {
     pub val: f64,
 
     /// The accession number of the filing.
-    pub accn: String,
+    pub accn: AccessionNumber,
 
     /// The fiscal year.
     pub fy: i32,
@@ -103,12 +99,12 @@ impl CompanyConcept {
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config, Taxonomy};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config, Taxonomy};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
     /// let concept = edgar_api.get_company_concept(
-    ///     "0000320193",
+    ///     Cik::new(320193),
     ///     Taxonomy::UsGaap,
     ///     "AccountsPayableCurrent"
     /// ).await?;
@@ -135,17 +131,18 @@ impl CompanyConcept {
     /// # Returns
     ///
     /// The most recent value for the specified unit of measure, or `None` if no
-    /// values exist for the unit.
+    /// values exist for the unit. Values are compared by period end date; values
+    /// with a malformed end date are only returned if no other value exists.
     ///
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config, Taxonomy};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config, Taxonomy};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
     /// let concept = edgar_api.get_company_concept(
-    ///     "0000320193",
+    ///     Cik::new(320193),
     ///     Taxonomy::UsGaap,
     ///     "AccountsPayableCurrent"
     /// ).await?;
@@ -156,7 +153,230 @@ impl CompanyConcept {
     /// # }
     /// ```
     pub fn get_most_recent_value(&self, unit: &str) -> Option<&ConceptValue> {
-        self.units.get(unit)?.iter().max_by_key(|v| &v.end)
+        self.units
+            .get(unit)?
+            .iter()
+            .max_by_key(|v| parse_ymd(&v.end))
+    }
+
+    /// Returns the values for the specified unit of measure whose reporting
+    /// period ends between `start` and `end`, inclusive.
+    ///
+    /// # Parameters
+    ///
+    /// * `unit` - The unit of measure.
+    /// * `start` - The earliest end date to include.
+    /// * `end` - The latest end date to include.
+    ///
+    /// # Returns
+    ///
+    /// The matching values, or an empty vector if no values exist for the unit.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate)
+    /// if a value has a malformed end date.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config, Taxonomy};
+    /// # use edgar_rs::chrono::NaiveDate;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let concept = edgar_api.get_company_concept(
+    ///     Cik::new(320193),
+    ///     Taxonomy::UsGaap,
+    ///     "AccountsPayableCurrent"
+    /// ).await?;
+    /// let values = concept.data.get_values_ending_between(
+    ///     "USD",
+    ///     NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2022, 12, 31).unwrap(),
+    /// )?;
+    /// for value in values {
+    ///     println!("{}: {} USD", value.end_date()?, value.val);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn get_values_ending_between(
+        &self,
+        unit: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<&ConceptValue>> {
+        let mut results = Vec::new();
+
+        for value in self.units.get(unit).into_iter().flatten() {
+            let date = value.end_date()?;
+            if start <= date && date <= end {
+                results.push(value);
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Returns one value per reporting period for the specified unit of measure.
+    ///
+    /// Every filing that reports a period lists it again; `policy` picks the
+    /// latest filed report, including restatements, or the originally reported value.
+    ///
+    /// # Parameters
+    ///
+    /// * `unit` - The unit of measure.
+    /// * `policy` - Which report of a period to keep.
+    ///
+    /// # Returns
+    ///
+    /// The values sorted by period end date, or an empty vector if no values
+    /// exist for the unit.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{Cik, DedupPolicy, EdgarApi, EdgarClient, Config, Taxonomy};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let concept = edgar_api.get_company_concept(
+    ///     Cik::new(320193),
+    ///     Taxonomy::UsGaap,
+    ///     "AccountsPayableCurrent"
+    /// ).await?;
+    /// for value in concept.data.get_deduplicated_values("USD", DedupPolicy::OriginallyReported) {
+    ///     println!("{}: {} USD as first reported", value.end, value.val);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_deduplicated_values(&self, unit: &str, policy: DedupPolicy) -> Vec<&ConceptValue> {
+        deduplicate_values(self.units.get(unit).into_iter().flatten(), policy)
+    }
+
+    /// Returns one value per quarter for the specified unit of measure, deriving
+    /// quarters that were only reported as part of a year-to-date or annual value.
+    ///
+    /// See [`quarterly_values`](crate::quarterly_values) for how quarters are
+    /// derived.
+    ///
+    /// # Parameters
+    ///
+    /// * `unit` - The unit of measure.
+    /// * `policy` - Which report of a period to use.
+    ///
+    /// # Returns
+    ///
+    /// The quarters in order, or an empty vector if the unit doesn't exist.
+    pub fn get_quarterly_values(&self, unit: &str, policy: DedupPolicy) -> Vec<QuarterlyValue> {
+        quarterly_values(self.units.get(unit).into_iter().flatten(), policy)
+    }
+
+    /// Returns the trailing-twelve-month sums for the specified unit of measure,
+    /// each tagged with the calendar frame (CY####Q#) of its last quarter.
+    ///
+    /// See [`trailing_twelve_months`](crate::trailing_twelve_months).
+    pub fn get_ttm_values(&self, unit: &str, policy: DedupPolicy) -> Vec<TtmValue> {
+        trailing_twelve_months(self.units.get(unit).into_iter().flatten(), policy)
+    }
+
+    /// Returns every report of each period for the specified unit of measure,
+    /// to see when and how values were restated.
+    ///
+    /// # Parameters
+    ///
+    /// * `unit` - The unit of measure.
+    ///
+    /// # Returns
+    ///
+    /// One history per reporting period, sorted by period end date.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config, Taxonomy};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let concept = edgar_api.get_company_concept(
+    ///     Cik::new(320193),
+    ///     Taxonomy::UsGaap,
+    ///     "AccountsPayableCurrent"
+    /// ).await?;
+    /// for history in concept.data.get_restatement_history("USD") {
+    ///     if history.is_restated() {
+    ///         println!("{}: {} -> {}", history.period(), history.original().val, history.latest().val);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_restatement_history(&self, unit: &str) -> Vec<RestatementHistory<'_, ConceptValue>> {
+        restatement_histories(self.units.get(unit).into_iter().flatten())
+    }
+
+    /// Returns the concept as it was known on `date` (YYYY-MM-DD), for
+    /// backtests free of look-ahead bias.
+    ///
+    /// Only values filed on or before `date` are kept, and each reporting period
+    /// keeps the latest of those reports, so later restatements are not visible.
+    /// Units with no values known on `date` are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate)
+    /// if `date` is not a YYYY-MM-DD calendar date.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config, Taxonomy};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let concept = edgar_api.get_company_concept(
+    ///     Cik::new(320193),
+    ///     Taxonomy::UsGaap,
+    ///     "AccountsPayableCurrent"
+    /// ).await?;
+    /// for value in concept.data.as_of("2020-06-30")?.get_values_for_unit("USD") {
+    ///     println!("{}: {} USD", value.end, value.val);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_of(&self, date: &str) -> Result<CompanyConcept> {
+        Ok(self.known_on(parse_as_of_date(date)?))
+    }
+
+    /// Returns the concept as it was known on `date`, like [`as_of`](Self::as_of).
+    #[cfg(feature = "chrono")]
+    pub fn as_of_date(&self, date: NaiveDate) -> CompanyConcept {
+        self.known_on(as_of_cutoff(date))
+    }
+
+    /// Returns the values known on `cutoff`, a (year, month, day) date.
+    fn known_on(&self, cutoff: (i32, u32, u32)) -> CompanyConcept {
+        let mut units = HashMap::new();
+        for (unit, values) in &self.units {
+            let known = values_known_on(values, cutoff);
+            if !known.is_empty() {
+                units.insert(unit.clone(), known.into_iter().cloned().collect());
+            }
+        }
+
+        CompanyConcept {
+            cik: self.cik,
+            entity_name: self.entity_name.clone(),
+            taxonomy: self.taxonomy.clone(),
+            tag: self.tag.clone(),
+            label: self.label.clone(),
+            description: self.description.clone(),
+            units,
+        }
     }
 
     /// Returns all available units of measure.
@@ -168,12 +388,12 @@ impl CompanyConcept {
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config, Taxonomy};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config, Taxonomy};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
     /// let concept = edgar_api.get_company_concept(
-    ///     "0000320193",
+    ///     Cik::new(320193),
     ///     Taxonomy::UsGaap,
     ///     "AccountsPayableCurrent"
     /// ).await?;
@@ -200,12 +420,12 @@ impl CompanyConcept {
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config, Taxonomy};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config, Taxonomy};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
     /// let concept = edgar_api.get_company_concept(
-    ///     "0000320193",
+    ///     Cik::new(320193),
     ///     Taxonomy::UsGaap,
     ///     "AccountsPayableCurrent"
     /// ).await?;
@@ -236,13 +456,52 @@ impl CompanyConcept {
 
     /// Get the CIK formatted as a string with 10 digits (with leading zeros)
     pub fn get_cik_as_string(&self) -> String {
-        format!("{:010}", self.cik)
+        self.cik.to_string()
+    }
+}
+
+impl ConceptValue {
+    /// Returns the form type of the filing that reported the value.
+    pub fn form_type(&self) -> FormType {
+        FormType::from(self.form.as_str())
+    }
+}
+
+/// Typed dates for ConceptValue
+#[cfg(feature = "chrono")]
+impl ConceptValue {
+    /// Returns the end date of the reporting period.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate) if the date is malformed.
+    pub fn end_date(&self) -> Result<NaiveDate> {
+        parse_date(&self.end)
+    }
+
+    /// Returns the start date of the reporting period, or `None` for instant values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate) if the date is malformed.
+    pub fn start_date(&self) -> Result<Option<NaiveDate>> {
+        parse_optional_date(self.start.as_deref())
+    }
+
+    /// Returns the date the report was filed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate) if the date is malformed.
+    pub fn filed_on(&self) -> Result<NaiveDate> {
+        parse_date(&self.filed)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::error::EdgarApiError;
     use serde_json::json;
 
     #[test]
@@ -280,7 +539,7 @@ mod tests {
     #[test]
     fn test_get_cik_as_string() {
         let concept = CompanyConcept {
-            cik: 320193,
+            cik: Cik::new(320193),
             entity_name: "APPLE INC".to_string(),
             taxonomy: "us-gaap".to_string(),
             tag: "AccountsPayableCurrent".to_string(),
@@ -299,7 +558,7 @@ mod tests {
             ConceptValue {
                 end: "2023-12-31".to_string(),
                 val: 1000000.0,
-                accn: "0000320193-23-000064".to_string(),
+                accn: "0000320193-23-000064".parse().unwrap(),
                 fy: 2023,
                 fp: "FY".to_string(),
                 form: "10-K".to_string(),
@@ -310,7 +569,7 @@ mod tests {
             ConceptValue {
                 end: "2023-09-30".to_string(),
                 val: 950000.0,
-                accn: "0000320193-23-000106".to_string(),
+                accn: "0000320193-23-000106".parse().unwrap(),
                 fy: 2024,
                 fp: "Q1".to_string(),
                 form: "10-Q".to_string(),
@@ -324,7 +583,7 @@ mod tests {
             ConceptValue {
                 end: "2023-12-31".to_string(),
                 val: 850000.0,
-                accn: "0000320193-23-000064".to_string(),
+                accn: "0000320193-23-000064".parse().unwrap(),
                 fy: 2023,
                 fp: "FY".to_string(),
                 form: "10-K".to_string(),
@@ -338,7 +597,7 @@ mod tests {
         units.insert("EUR".to_string(), eur_values);
 
         CompanyConcept {
-            cik: 320193,
+            cik: Cik::new(320193),
             entity_name: "APPLE INC".to_string(),
             taxonomy: "us-gaap".to_string(),
             tag: "AccountsPayableCurrent".to_string(),
@@ -407,4 +666,96 @@ mod tests {
         let nonexistent_values = concept.get_values_for_fiscal_period(2022, "Q1");
         assert_eq!(nonexistent_values.len(), 0);
     }
+
+    #[test]
+    fn test_get_most_recent_value_ignores_malformed_dates() {
+        let mut concept = create_test_concept();
+        concept.units.get_mut("USD").unwrap()[1].end = "FY2023".to_string();
+
+        let latest_usd = concept.get_most_recent_value("USD").unwrap();
+        assert_eq!(latest_usd.end, "2023-12-31");
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_get_values_ending_between() {
+        let mut concept = create_test_concept();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        let values = concept
+            .get_values_ending_between("USD", date(2023, 7, 1), date(2023, 9, 30))
+            .unwrap();
+        assert_eq!(values.len(), 1);
+        assert_eq!(values[0].val, 950000.0);
+        assert_eq!(values[0].start_date().unwrap(), Some(date(2023, 7, 1)));
+        assert_eq!(values[0].filed_on().unwrap(), date(2023, 11, 2));
+
+        assert!(concept
+            .get_values_ending_between("GBP", date(2023, 1, 1), date(2023, 12, 31))
+            .unwrap()
+            .is_empty());
+
+        concept.units.get_mut("USD").unwrap()[0].end = "2023-02-30".to_string();
+        let error = concept
+            .get_values_ending_between("USD", date(2023, 1, 1), date(2023, 12, 31))
+            .unwrap_err();
+        assert!(matches!(error, EdgarApiError::InvalidDate(_)));
+        assert!(error.to_string().contains("2023-02-30"));
+    }
+
+    #[test]
+    fn test_get_deduplicated_values() {
+        let mut concept = create_test_concept();
+        let mut restated = concept.units["USD"][0].clone();
+        restated.val = 1100000.0;
+        restated.accn = "0000320193-24-000010".parse().unwrap();
+        restated.filed = "2024-11-01".to_string();
+        concept.units.get_mut("USD").unwrap().push(restated);
+
+        let latest = concept.get_deduplicated_values("USD", DedupPolicy::LatestFiled);
+        assert_eq!(latest.len(), 2);
+        assert_eq!(latest[1].val, 1100000.0);
+
+        let original = concept.get_deduplicated_values("USD", DedupPolicy::OriginallyReported);
+        assert_eq!(original[1].val, 1000000.0);
+
+        let history = concept.get_restatement_history("USD");
+        assert!(!history[0].is_restated());
+        assert!(history[1].is_restated());
+        assert!(concept.get_restatement_history("GBP").is_empty());
+    }
+
+    #[test]
+    fn test_as_of() {
+        let concept = create_test_concept();
+
+        let known = concept.as_of("2023-11-02").unwrap();
+        assert_eq!(known.get_values_for_unit("USD").len(), 1);
+        assert_eq!(known.get_values_for_unit("USD")[0].end, "2023-09-30");
+        assert!(known.get_values_for_unit("EUR").is_empty());
+
+        let known = concept.as_of("2023-11-03").unwrap();
+        assert_eq!(known.get_values_for_unit("USD").len(), 2);
+        assert_eq!(known.get_available_units().len(), 2);
+
+        assert!(concept.as_of("2023-11").is_err());
+        assert!(matches!(
+            concept.as_of("2023-11-31"),
+            Err(EdgarApiError::InvalidDate(_))
+        ));
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_as_of_date() {
+        let concept = create_test_concept();
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        // Values filed on the day itself are known
+        let known = concept.as_of_date(date(2023, 11, 2));
+        assert_eq!(known.get_values_for_unit("USD").len(), 1);
+        assert_eq!(known.get_values_for_unit("USD")[0].filed, "2023-11-02");
+        assert_eq!(concept.as_of_date(date(2023, 11, 3)).get_available_units().len(), 2);
+        assert!(concept.as_of_date(date(2023, 11, 1)).units.is_empty());
+    }
 }
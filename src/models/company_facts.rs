@@ -5,6 +5,25 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+use crate::error::Result;
+use crate::analysis::{
+    deduplicate_values, parse_as_of_date, quarterly_values, restatement_histories,
+    trailing_twelve_months, values_known_on, DedupPolicy, FiscalCalendar, MetricEngine,
+    MetricSeries, QuarterlyValue, ReportedValue, RestatementHistory, Statement, StatementBuilder,
+    TtmValue,
+};
+#[cfg(feature = "chrono")]
+use crate::analysis::as_of_cutoff;
+use crate::form_type::FormType;
+use crate::identifiers::{AccessionNumber, Cik};
+use crate::models::company_concept::{CompanyConcept, ConceptValue};
+#[cfg(feature = "chrono")]
+use crate::utils::date::{parse_date, parse_optional_date};
+use crate::utils::date::parse_ymd;
+
 /// A company facts response from the SEC EDGAR API.
 ///
 /// This struct represents the response from the company facts endpoint, which
@@ -12,7 +31,7 @@ use std::collections::HashMap;
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompanyFacts {
     /// The CIK number of the company.
-    pub cik: u64, // TODO: Changed, Review
+    pub cik: Cik,
 
     /// The entity name.
     #[serde(rename = "entityName")]
@@ -46,7 +65,7 @@ pub struct FactValue {
     pub val: Option<serde_json::Value>,
 
     /// The accession number of the filing.
-    pub accn: String,
+    pub accn: AccessionNumber,
 
     /// The fiscal year.
     pub fy: Option<i32>, // TODO: Changed, Review
@@ -79,11 +98,11 @@ impl CompanyFacts {
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let facts = edgar_api.get_company_facts("0000320193").await?;
+    /// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
     /// let taxonomies = facts.data.get_taxonomies();
     /// println!("Available taxonomies: {:?}", taxonomies);
     /// # Ok(())
@@ -106,11 +125,11 @@ impl CompanyFacts {
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let facts = edgar_api.get_company_facts("0000320193").await?;
+    /// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
     /// let tags = facts.data.get_tags_for_taxonomy("us-gaap");
     /// println!("Available us-gaap tags: {:?}", tags);
     /// # Ok(())
@@ -137,11 +156,11 @@ impl CompanyFacts {
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let facts = edgar_api.get_company_facts("0000320193").await?;
+    /// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
     /// if let Some(fact) = facts.data.get_fact("us-gaap", "AccountsPayableCurrent") {
     ///     println!("Accounts Payable Current: {:?}", fact);
     /// }
@@ -166,11 +185,11 @@ impl CompanyFacts {
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let facts = edgar_api.get_company_facts("0000320193").await?;
+    /// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
     /// let q1_2023_facts = facts.data.get_facts_for_fiscal_period(2023, "Q1");
     /// for (taxonomy, tag, unit, value) in q1_2023_facts {
     ///     println!("{}.{} ({}) = {:?}", taxonomy, tag, unit, value.val);
@@ -183,30 +202,20 @@ impl CompanyFacts {
         fiscal_year: i32,
         fiscal_period: &str,
     ) -> Vec<(&str, &str, &str, &FactValue)> {
-        let mut results = Vec::new();
-
-        for (taxonomy, tags) in &self.facts {
-            for (tag, fact) in tags {
-                for (unit, values) in &fact.units {
-                    for value in values {
-                        if value.fy == Some(fiscal_year)
-                            && value.fp.as_deref() == Some(fiscal_period)
-                        {
-                            results.push((taxonomy.as_str(), tag.as_str(), unit.as_str(), value));
-                        }
-                    }
-                }
-            }
-        }
-
-        results
+        self.filter_facts(|value| {
+            value.fy == Some(fiscal_year) && value.fp.as_deref() == Some(fiscal_period)
+        })
     }
 
     /// Returns all facts from a specific form.
     ///
+    /// Amendments only match amendments: facts from a "10-K/A" are not returned
+    /// for "10-K". Use [`get_facts_for_base_form`](Self::get_facts_for_base_form)
+    /// to include them.
+    ///
     /// # Parameters
     ///
-    /// * `form` - The form type (e.g., "10-K", "10-Q").
+    /// * `form` - The form type (e.g., "10-K", `FormType::TenQ`).
     ///
     /// # Returns
     ///
@@ -215,11 +224,11 @@ impl CompanyFacts {
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let facts = edgar_api.get_company_facts("0000320193").await?;
+    /// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
     /// let form_10k_facts = facts.data.get_facts_for_form("10-K");
     /// for (taxonomy, tag, unit, value) in form_10k_facts {
     ///     println!("{}.{} ({}) = {:?}", taxonomy, tag, unit, value.val);
@@ -227,14 +236,59 @@ impl CompanyFacts {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn get_facts_for_form(&self, form: &str) -> Vec<(&str, &str, &str, &FactValue)> {
+    pub fn get_facts_for_form(
+        &self,
+        form: impl Into<FormType>,
+    ) -> Vec<(&str, &str, &str, &FactValue)> {
+        let form = form.into();
+        self.filter_facts(|value| value.form_type() == form)
+    }
+
+    /// Returns all facts from a form or its amendments.
+    ///
+    /// # Parameters
+    ///
+    /// * `form` - The form type (e.g., "10-K"). Amendments match their base form,
+    ///   so "10-K" and "10-K/A" return the same facts.
+    ///
+    /// # Returns
+    ///
+    /// A vector of (taxonomy, tag, unit, fact value) tuples.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config, FormType};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
+    /// for (taxonomy, tag, unit, value) in facts.data.get_facts_for_base_form(FormType::TenK) {
+    ///     println!("{}.{} ({}) = {:?} from {}", taxonomy, tag, unit, value.val, value.form);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_facts_for_base_form(
+        &self,
+        form: impl Into<FormType>,
+    ) -> Vec<(&str, &str, &str, &FactValue)> {
+        let form = form.into();
+        self.filter_facts(|value| value.form_type().same_base_form(&form))
+    }
+
+    /// Returns (taxonomy, tag, unit, fact value) tuples for the values matching `predicate`.
+    fn filter_facts(
+        &self,
+        predicate: impl Fn(&FactValue) -> bool,
+    ) -> Vec<(&str, &str, &str, &FactValue)> {
         let mut results = Vec::new();
 
         for (taxonomy, tags) in &self.facts {
             for (tag, fact) in tags {
                 for (unit, values) in &fact.units {
                     for value in values {
-                        if value.form == form {
+                        if predicate(value) {
                             results.push((taxonomy.as_str(), tag.as_str(), unit.as_str(), value));
                         }
                     }
@@ -247,6 +301,9 @@ impl CompanyFacts {
 
     /// Returns the most recent value for a specific fact.
     ///
+    /// Values are compared by period end date; values with a malformed end date
+    /// are only returned if no other value exists.
+    ///
     /// # Parameters
     ///
     /// * `taxonomy` - The taxonomy of the fact.
@@ -260,11 +317,11 @@ impl CompanyFacts {
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let facts = edgar_api.get_company_facts("0000320193").await?;
+    /// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
     /// if let Some(value) = facts.data.get_most_recent_value("us-gaap", "AccountsPayableCurrent", "USD") {
     ///     println!("Most recent accounts payable: {:?} USD (as of {})", value.val, value.end);
     /// }
@@ -279,12 +336,460 @@ impl CompanyFacts {
     ) -> Option<&FactValue> {
         let fact = self.get_fact(taxonomy, tag)?;
         let values = fact.units.get(unit)?;
-        values.iter().max_by_key(|v| &v.end)
+        values.iter().max_by_key(|v| parse_ymd(&v.end))
+    }
+
+    /// Returns the values of a fact whose reporting period ends between `start`
+    /// and `end`, inclusive.
+    ///
+    /// # Parameters
+    ///
+    /// * `taxonomy` - The taxonomy of the fact.
+    /// * `tag` - The tag of the fact.
+    /// * `unit` - The unit of measure.
+    /// * `start` - The earliest end date to include.
+    /// * `end` - The latest end date to include.
+    ///
+    /// # Returns
+    ///
+    /// The matching values, or an empty vector if the fact or unit doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate)
+    /// if a value has a malformed end date.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::chrono::NaiveDate;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
+    /// let values = facts.data.get_values_ending_between(
+    ///     "us-gaap",
+    ///     "AccountsPayableCurrent",
+    ///     "USD",
+    ///     NaiveDate::from_ymd_opt(2022, 1, 1).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2022, 12, 31).unwrap(),
+    /// )?;
+    /// for value in values {
+    ///     println!("{}: {:?} USD", value.end_date()?, value.val);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn get_values_ending_between(
+        &self,
+        taxonomy: &str,
+        tag: &str,
+        unit: &str,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<&FactValue>> {
+        let values = match self.get_fact(taxonomy, tag).and_then(|fact| fact.units.get(unit)) {
+            Some(values) => values,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut results = Vec::new();
+        for value in values {
+            let date = value.end_date()?;
+            if start <= date && date <= end {
+                results.push(value);
+            }
+        }
+
+        Ok(results)
+    }
+
+    /// Returns one value per reporting period for a specific fact.
+    ///
+    /// Every filing that reports a period lists it again, so a fiscal year's
+    /// revenue appears in three consecutive 10-Ks. `policy` picks the latest
+    /// filed report, including restatements, or the originally reported value.
+    ///
+    /// # Parameters
+    ///
+    /// * `taxonomy` - The taxonomy of the fact.
+    /// * `tag` - The tag of the fact.
+    /// * `unit` - The unit of measure.
+    /// * `policy` - Which report of a period to keep.
+    ///
+    /// # Returns
+    ///
+    /// The values sorted by period end date, or an empty vector if the fact or
+    /// unit doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{Cik, DedupPolicy, EdgarApi, EdgarClient, Config};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
+    /// let values = facts.data.get_deduplicated_values(
+    ///     "us-gaap",
+    ///     "AccountsPayableCurrent",
+    ///     "USD",
+    ///     DedupPolicy::LatestFiled,
+    /// );
+    /// for value in values {
+    ///     println!("{}: {:?} USD (filed {})", value.end, value.val, value.filed);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_deduplicated_values(
+        &self,
+        taxonomy: &str,
+        tag: &str,
+        unit: &str,
+        policy: DedupPolicy,
+    ) -> Vec<&FactValue> {
+        deduplicate_values(self.get_values(taxonomy, tag, unit), policy)
+    }
+
+    /// Returns one value per quarter for a flow item such as revenue, deriving
+    /// quarters that were only reported as part of a year-to-date or annual
+    /// value (Q4 as the fiscal year minus nine months, for example).
+    ///
+    /// See [`quarterly_values`](crate::quarterly_values) for how quarters are
+    /// derived. Derived values have `is_derived()` set.
+    ///
+    /// # Parameters
+    ///
+    /// * `taxonomy` - The taxonomy of the fact.
+    /// * `tag` - The tag of the fact.
+    /// * `unit` - The unit of measure.
+    /// * `policy` - Which report of a period to use.
+    ///
+    /// # Returns
+    ///
+    /// The quarters in order, or an empty vector if the fact or unit doesn't exist.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{Cik, DedupPolicy, EdgarApi, EdgarClient, Config};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
+    /// let quarters = facts.data.get_quarterly_values(
+    ///     "us-gaap",
+    ///     "RevenueFromContractWithCustomerExcludingAssessedTax",
+    ///     "USD",
+    ///     DedupPolicy::LatestFiled,
+    /// );
+    /// for quarter in quarters {
+    ///     println!("{}: {} USD (derived: {})", quarter.period, quarter.value, quarter.is_derived());
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_quarterly_values(
+        &self,
+        taxonomy: &str,
+        tag: &str,
+        unit: &str,
+        policy: DedupPolicy,
+    ) -> Vec<QuarterlyValue> {
+        quarterly_values(self.get_values(taxonomy, tag, unit), policy)
+    }
+
+    /// Returns the trailing-twelve-month sums of a flow item, each tagged with
+    /// the calendar frame (CY####Q#) of its last quarter.
+    ///
+    /// See [`trailing_twelve_months`](crate::trailing_twelve_months).
+    ///
+    /// # Parameters
+    ///
+    /// * `taxonomy` - The taxonomy of the fact.
+    /// * `tag` - The tag of the fact.
+    /// * `unit` - The unit of measure.
+    /// * `policy` - Which report of a period to use.
+    ///
+    /// # Returns
+    ///
+    /// The sums sorted by end date, or an empty vector if the fact or unit
+    /// doesn't exist.
+    pub fn get_ttm_values(
+        &self,
+        taxonomy: &str,
+        tag: &str,
+        unit: &str,
+        policy: DedupPolicy,
+    ) -> Vec<TtmValue> {
+        trailing_twelve_months(self.get_values(taxonomy, tag, unit), policy)
+    }
+
+    /// Returns the company's fiscal calendar.
+    ///
+    /// Uses the DEI `CurrentFiscalYearEndDate` if the facts include it, and
+    /// otherwise the most common end date of the annual periods reported with
+    /// fiscal period "FY". The submissions endpoint has the fiscal year end
+    /// too, see [`SubmissionHistory::fiscal_calendar`](crate::SubmissionHistory::fiscal_calendar).
+    ///
+    /// # Returns
+    ///
+    /// The calendar, or `None` if the facts have no annual values.
+    pub fn fiscal_calendar(&self) -> Option<FiscalCalendar> {
+        let reported = self
+            .get_fact("dei", "CurrentFiscalYearEndDate")
+            .into_iter()
+            .flat_map(|fact| fact.units.values().flatten())
+            .filter_map(|value| value.val.as_ref()?.as_str())
+            .find_map(|date| FiscalCalendar::parse(date).ok());
+        if reported.is_some() {
+            return reported;
+        }
+
+        let mut year_ends: HashMap<(u32, u32), usize> = HashMap::new();
+        let values = self
+            .facts
+            .values()
+            .flat_map(|tags| tags.values())
+            .flat_map(|fact| fact.units.values().flatten());
+        for value in values {
+            if value.fp.as_deref() == Some("FY") && value.period().is_annual() {
+                if let Some((_, month, day)) = parse_ymd(&value.end) {
+                    *year_ends.entry((month, day)).or_default() += 1;
+                }
+            }
+        }
+
+        year_ends
+            .into_iter()
+            .max_by_key(|(year_end, count)| (*count, *year_end))
+            .and_then(|((month, day), _)| FiscalCalendar::new(month, day).ok())
+    }
+
+    /// Returns every report of each period for a specific fact, to see when and
+    /// how values were restated.
+    ///
+    /// # Parameters
+    ///
+    /// * `taxonomy` - The taxonomy of the fact.
+    /// * `tag` - The tag of the fact.
+    /// * `unit` - The unit of measure.
+    ///
+    /// # Returns
+    ///
+    /// One history per reporting period, sorted by period end date.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
+    /// for history in facts.data.get_restatement_history("us-gaap", "AccountsPayableCurrent", "USD") {
+    ///     for revision in history.revisions() {
+    ///         println!("{}: {:?} as filed {} in {}", history.period(), revision.val, revision.filed, revision.accn);
+    ///     }
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_restatement_history(
+        &self,
+        taxonomy: &str,
+        tag: &str,
+        unit: &str,
+    ) -> Vec<RestatementHistory<'_, FactValue>> {
+        restatement_histories(self.get_values(taxonomy, tag, unit))
+    }
+
+    /// Returns the facts as they were known on `date` (YYYY-MM-DD), for
+    /// backtests free of look-ahead bias.
+    ///
+    /// Only values filed on or before `date` are kept, and each reporting period
+    /// keeps the latest of those reports, so later restatements are not visible.
+    /// Facts with no values known on `date` are left out.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate)
+    /// if `date` is not a YYYY-MM-DD calendar date.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
+    /// let known = facts.data.as_of("2020-06-30")?;
+    /// if let Some(value) = known.get_most_recent_value("us-gaap", "AccountsPayableCurrent", "USD") {
+    ///     println!("Known on 2020-06-30: {:?} USD (as of {})", value.val, value.end);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn as_of(&self, date: &str) -> Result<CompanyFacts> {
+        Ok(self.known_on(parse_as_of_date(date)?))
+    }
+
+    /// Returns the facts as they were known on `date`, like [`as_of`](Self::as_of).
+    #[cfg(feature = "chrono")]
+    pub fn as_of_date(&self, date: NaiveDate) -> CompanyFacts {
+        self.known_on(as_of_cutoff(date))
+    }
+
+    /// Returns the facts known on `cutoff`, a (year, month, day) date.
+    fn known_on(&self, cutoff: (i32, u32, u32)) -> CompanyFacts {
+        let mut facts = HashMap::new();
+        for (taxonomy, tags) in &self.facts {
+            let mut known_tags = HashMap::new();
+            for (tag, fact) in tags {
+                let mut units = HashMap::new();
+                for (unit, values) in &fact.units {
+                    let known = values_known_on(values, cutoff);
+                    if !known.is_empty() {
+                        units.insert(unit.clone(), known.into_iter().cloned().collect());
+                    }
+                }
+                if !units.is_empty() {
+                    let fact = Fact {
+                        label: fact.label.clone(),
+                        description: fact.description.clone(),
+                        units,
+                    };
+                    known_tags.insert(tag.clone(), fact);
+                }
+            }
+            if !known_tags.is_empty() {
+                facts.insert(taxonomy.clone(), known_tags);
+            }
+        }
+
+        CompanyFacts {
+            cik: self.cik,
+            entity_name: self.entity_name.clone(),
+            facts,
+        }
+    }
+
+    /// Returns the standardized income statement, built with the default
+    /// [`StatementBuilder`] concept chains.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config, LineItem};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let facts = edgar_api.get_company_facts(Cik::new(320193)).await?;
+    /// for column in facts.data.income_statement().annual() {
+    ///     println!(
+    ///         "{}: revenue {:?}, net income {:?}",
+    ///         column.period,
+    ///         column.get(LineItem::Revenue),
+    ///         column.get(LineItem::NetIncome)
+    ///     );
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn income_statement(&self) -> Statement {
+        StatementBuilder::new().income_statement(self)
+    }
+
+    /// Returns the standardized balance sheet, built with the default
+    /// [`StatementBuilder`] concept chains.
+    pub fn balance_sheet(&self) -> Statement {
+        StatementBuilder::new().balance_sheet(self)
+    }
+
+    /// Returns the standardized cash flow statement, built with the default
+    /// [`StatementBuilder`] concept chains.
+    pub fn cash_flow_statement(&self) -> Statement {
+        StatementBuilder::new().cash_flow_statement(self)
+    }
+
+    /// Computes the [standard metrics](crate::Metric::standard), such as gross
+    /// margin, return on equity and free cash flow, for every period.
+    ///
+    /// Use [`MetricEngine`] to add metrics or change how line items are read.
+    pub fn compute_metrics(&self) -> Vec<MetricSeries> {
+        MetricEngine::new().compute(self)
+    }
+
+    /// Returns the values of a fact in a unit, or an empty slice.
+    fn get_values(&self, taxonomy: &str, tag: &str, unit: &str) -> &[FactValue] {
+        self.get_fact(taxonomy, tag)
+            .and_then(|fact| fact.units.get(unit))
+            .map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns a single concept in the shape of the company concept endpoint.
+    ///
+    /// Values without a numeric value, fiscal year or fiscal period are skipped,
+    /// since `ConceptValue` requires them.
+    ///
+    /// # Parameters
+    ///
+    /// * `taxonomy` - The taxonomy of the fact.
+    /// * `tag` - The tag of the fact.
+    ///
+    /// # Returns
+    ///
+    /// The concept, or `None` if the fact doesn't exist.
+    pub fn to_company_concept(&self, taxonomy: &str, tag: &str) -> Option<CompanyConcept> {
+        let fact = self.get_fact(taxonomy, tag)?;
+
+        let units = fact
+            .units
+            .iter()
+            .map(|(unit, values)| {
+                let values = values
+                    .iter()
+                    .filter_map(|value| {
+                        Some(ConceptValue {
+                            end: value.end.clone(),
+                            val: value.as_f64()?,
+                            accn: value.accn,
+                            fy: value.fy?,
+                            fp: value.fp.clone()?,
+                            form: value.form.clone(),
+                            filed: value.filed.clone(),
+                            frame: value.frame.clone(),
+                            start: value.start.clone(),
+                        })
+                    })
+                    .collect();
+                (unit.clone(), values)
+            })
+            .collect();
+
+        Some(CompanyConcept {
+            cik: self.cik,
+            entity_name: self.entity_name.clone(),
+            taxonomy: taxonomy.to_string(),
+            tag: tag.to_string(),
+            label: fact.label.clone().unwrap_or_default(),
+            description: fact.description.clone().unwrap_or_default(),
+            units,
+        })
     }
 }
 
 /// Helper methods for extracting typed values from FactValue
 impl FactValue {
+    /// Returns the form type of the filing that reported the value.
+    pub fn form_type(&self) -> FormType {
+        FormType::from(self.form.as_str())
+    }
+
     /// Returns the value as a float, if possible.
     ///
     /// # Returns
@@ -333,3 +838,347 @@ impl FactValue {
         }
     }
 }
+
+/// Typed dates for FactValue
+#[cfg(feature = "chrono")]
+impl FactValue {
+    /// Returns the end date of the reporting period.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate) if the date is malformed.
+    pub fn end_date(&self) -> Result<NaiveDate> {
+        parse_date(&self.end)
+    }
+
+    /// Returns the start date of the reporting period, or `None` for instant values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate) if the date is malformed.
+    pub fn start_date(&self) -> Result<Option<NaiveDate>> {
+        parse_optional_date(self.start.as_deref())
+    }
+
+    /// Returns the date the report was filed.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate) if the date is malformed.
+    pub fn filed_on(&self) -> Result<NaiveDate> {
+        parse_date(&self.filed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::test_support::{company_facts, fact};
+    use crate::analysis::LineItem;
+    use crate::error::EdgarApiError;
+    use crate::types::Period;
+    use serde_json::json;
+
+    /// Calendar-year 2023 revenue reported in a 10-Q style (Q1, then six and
+    /// nine months to date), a restated fiscal year and 2024's first quarter,
+    /// plus a few balance sheet and cash flow items.
+    fn facts() -> CompanyFacts {
+        serde_json::from_value(json!({
+            "cik": 1,
+            "entityName": "Example Corp",
+            "facts": {
+                "us-gaap": {
+                    "Revenues": {"units": {
+                        "USD": [
+                            fact(Some("2023-01-01"), "2023-03-31", 10.0, "2023-05-01"),
+                            fact(Some("2023-01-01"), "2023-06-30", 30.0, "2023-08-01"),
+                            fact(Some("2023-01-01"), "2023-09-30", 60.0, "2023-11-01"),
+                            fact(Some("2023-01-01"), "2023-12-31", 100.0, "2024-02-01"),
+                            fact(Some("2023-01-01"), "2023-12-31", 105.0, "2025-02-01"),
+                            fact(Some("2024-01-01"), "2024-03-31", 20.0, "2024-05-01")
+                        ],
+                        "EUR": [
+                            fact(Some("2023-01-01"), "2023-12-31", 90.0, "2024-02-01")
+                        ]
+                    }},
+                    "NetIncomeLoss": {"units": {"USD": [
+                        fact(Some("2023-01-01"), "2023-12-31", 10.0, "2024-02-01")
+                    ]}},
+                    "Assets": {"units": {"USD": [
+                        fact(None, "2023-12-31", 500.0, "2024-02-01")
+                    ]}},
+                    "StockholdersEquity": {"units": {"USD": [
+                        fact(None, "2023-12-31", 200.0, "2024-02-01")
+                    ]}},
+                    "NetCashProvidedByUsedInOperatingActivities": {"units": {"USD": [
+                        fact(Some("2023-01-01"), "2023-12-31", 50.0, "2024-02-01")
+                    ]}},
+                    "PaymentsToAcquirePropertyPlantAndEquipment": {"units": {"USD": [
+                        fact(Some("2023-01-01"), "2023-12-31", 20.0, "2024-02-01")
+                    ]}}
+                },
+                "ifrs-full": {
+                    "Revenue": {"units": {"USD": [
+                        fact(Some("2023-01-01"), "2023-12-31", 999.0, "2024-02-01")
+                    ]}}
+                }
+            }
+        }))
+        .unwrap()
+    }
+
+    fn vals(values: &[&FactValue]) -> Vec<f64> {
+        values.iter().filter_map(|value| value.as_f64()).collect()
+    }
+
+    #[test]
+    fn test_get_deduplicated_values() {
+        let facts = facts();
+
+        let latest =
+            facts.get_deduplicated_values("us-gaap", "Revenues", "USD", DedupPolicy::LatestFiled);
+        assert_eq!(vals(&latest), vec![10.0, 30.0, 60.0, 105.0, 20.0]);
+        let original = facts.get_deduplicated_values(
+            "us-gaap",
+            "Revenues",
+            "USD",
+            DedupPolicy::OriginallyReported,
+        );
+        assert_eq!(vals(&original), vec![10.0, 30.0, 60.0, 100.0, 20.0]);
+
+        let eur =
+            facts.get_deduplicated_values("us-gaap", "Revenues", "EUR", DedupPolicy::LatestFiled);
+        assert_eq!(vals(&eur), vec![90.0]);
+        let ifrs =
+            facts.get_deduplicated_values("ifrs-full", "Revenue", "USD", DedupPolicy::LatestFiled);
+        assert_eq!(vals(&ifrs), vec![999.0]);
+        assert!(facts
+            .get_deduplicated_values("ifrs-full", "Revenues", "USD", DedupPolicy::LatestFiled)
+            .is_empty());
+        assert!(facts
+            .get_deduplicated_values("us-gaap", "Revenues", "GBP", DedupPolicy::LatestFiled)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_get_restatement_history() {
+        let facts = facts();
+
+        let histories = facts.get_restatement_history("us-gaap", "Revenues", "USD");
+        assert_eq!(histories.len(), 5);
+        let restated: Vec<&RestatementHistory<'_, FactValue>> = histories
+            .iter()
+            .filter(|history| history.is_restated())
+            .collect();
+        assert_eq!(restated.len(), 1);
+        assert_eq!(restated[0].period().end, "2023-12-31");
+        assert_eq!(restated[0].original().as_f64(), Some(100.0));
+        assert_eq!(restated[0].latest().as_f64(), Some(105.0));
+
+        assert!(!facts.get_restatement_history("us-gaap", "Revenues", "EUR")[0].is_restated());
+        assert!(facts
+            .get_restatement_history("us-gaap", "Missing", "USD")
+            .is_empty());
+    }
+
+    #[test]
+    fn test_get_quarterly_values() {
+        let facts = facts();
+
+        let quarters =
+            facts.get_quarterly_values("us-gaap", "Revenues", "USD", DedupPolicy::LatestFiled);
+        let values: Vec<(f64, bool)> = quarters
+            .iter()
+            .map(|quarter| (quarter.value, quarter.is_derived()))
+            .collect();
+        assert_eq!(
+            values,
+            vec![
+                (10.0, false),
+                (20.0, true),
+                (30.0, true),
+                (45.0, true),
+                (20.0, false)
+            ]
+        );
+
+        let original = facts.get_quarterly_values(
+            "us-gaap",
+            "Revenues",
+            "USD",
+            DedupPolicy::OriginallyReported,
+        );
+        assert_eq!(original[3].value, 40.0);
+
+        // A fiscal year alone has no quarters to derive
+        assert!(facts
+            .get_quarterly_values("us-gaap", "Revenues", "EUR", DedupPolicy::LatestFiled)
+            .is_empty());
+        assert!(facts
+            .get_quarterly_values("ifrs-full", "Revenue", "USD", DedupPolicy::LatestFiled)
+            .is_empty());
+        assert!(facts
+            .get_quarterly_values("us-gaap", "Missing", "USD", DedupPolicy::LatestFiled)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_get_ttm_values() {
+        let facts = facts();
+
+        let ttm = facts.get_ttm_values("us-gaap", "Revenues", "USD", DedupPolicy::LatestFiled);
+        let values: Vec<f64> = ttm.iter().map(|sum| sum.value).collect();
+        assert_eq!(values, vec![105.0, 115.0]);
+        assert_eq!(ttm[0].frame, Some(Period::Quarterly(2023, 4)));
+        assert_eq!(ttm[1].frame, Some(Period::Quarterly(2024, 1)));
+        assert_eq!(ttm[1].period.start.as_deref(), Some("2023-04-01"));
+        assert!(ttm[1].is_derived());
+
+        assert!(facts
+            .get_ttm_values("us-gaap", "Revenues", "EUR", DedupPolicy::LatestFiled)
+            .is_empty());
+        assert!(facts
+            .get_ttm_values("us-gaap", "Missing", "USD", DedupPolicy::LatestFiled)
+            .is_empty());
+    }
+
+    #[test]
+    fn test_fiscal_calendar() {
+        let mut facts = facts();
+        assert_eq!(
+            facts.fiscal_calendar(),
+            Some(FiscalCalendar::calendar_year())
+        );
+
+        // The DEI fiscal year end wins over the annual periods
+        let dei = json!({"CurrentFiscalYearEndDate": {"units": {"pure": [{
+            "end": "2023-12-31", "val": "--09-30", "accn": "0000000001-24-000001",
+            "fy": 2023, "fp": "FY", "form": "10-K", "filed": "2024-02-01"
+        }]}}});
+        facts
+            .facts
+            .insert("dei".to_string(), serde_json::from_value(dei).unwrap());
+        assert_eq!(
+            facts.fiscal_calendar(),
+            Some(FiscalCalendar::new(9, 30).unwrap())
+        );
+
+        // Only annual periods reported as "FY" count
+        let quarterly = company_facts(json!({"Revenues": {"units": {"USD": [
+            fact(Some("2023-01-01"), "2023-03-31", 10.0, "2023-05-01")
+        ]}}}));
+        assert_eq!(quarterly.fiscal_calendar(), None);
+        assert_eq!(company_facts(json!({})).fiscal_calendar(), None);
+    }
+
+    #[test]
+    fn test_statements() {
+        let facts = facts();
+
+        // us-gaap tags are tried first and the EUR value is ignored
+        let income = facts.income_statement();
+        assert_eq!(income.annual().len(), 1);
+        let fy2023 = income.annual()[0];
+        assert_eq!(fy2023.get(LineItem::Revenue), Some(105.0));
+        assert_eq!(fy2023.items[&LineItem::Revenue].unit, "USD");
+        assert_eq!(fy2023.get(LineItem::NetIncome), Some(10.0));
+        assert_eq!(income.quarterly().len(), 5);
+
+        let balance_sheet = facts.balance_sheet();
+        let year_end = balance_sheet.annual()[0];
+        assert_eq!(year_end.get(LineItem::TotalAssets), Some(500.0));
+        assert_eq!(year_end.get(LineItem::StockholdersEquity), Some(200.0));
+        assert_eq!(year_end.get(LineItem::Revenue), None);
+
+        let cash_flow = facts.cash_flow_statement();
+        assert_eq!(cash_flow.periods.len(), 1);
+        assert_eq!(
+            cash_flow.periods[0].get(LineItem::OperatingCashFlow),
+            Some(50.0)
+        );
+        assert_eq!(
+            cash_flow.periods[0].get(LineItem::CapitalExpenditures),
+            Some(20.0)
+        );
+
+        let empty = company_facts(json!({}));
+        assert!(empty.income_statement().periods.is_empty());
+        assert!(empty.balance_sheet().periods.is_empty());
+        assert!(empty.cash_flow_statement().periods.is_empty());
+    }
+
+    #[test]
+    fn test_compute_metrics() {
+        let facts = facts();
+        let metrics = facts.compute_metrics();
+        let annual = |name: &str| -> Vec<Option<f64>> {
+            metrics
+                .iter()
+                .find(|series| series.name == name)
+                .unwrap()
+                .annual()
+                .iter()
+                .map(|value| value.value)
+                .collect()
+        };
+
+        assert_eq!(annual("free_cash_flow"), vec![Some(30.0)]);
+        assert_eq!(annual("return_on_equity"), vec![Some(0.05)]);
+        assert_eq!(annual("net_margin"), vec![Some(10.0 / 105.0)]);
+        assert_eq!(annual("revenue_growth"), vec![None]);
+
+        let empty = company_facts(json!({})).compute_metrics();
+        assert_eq!(empty.len(), metrics.len());
+        assert!(empty.iter().all(|series| series.values.is_empty()));
+    }
+
+    #[test]
+    fn test_as_of() {
+        let facts = company_facts(json!({
+            "Assets": {"units": {"USD": [
+                fact(None, "2023-12-31", 500.0, "2024-02-01"),
+                fact(None, "2023-12-31", 510.0, "2024-02-02")
+            ]}},
+            "Liabilities": {"units": {"USD": [
+                fact(None, "2024-03-31", 300.0, "2024-05-01")
+            ]}}
+        }));
+
+        // A value filed on the day itself is known
+        let known = facts.as_of("2024-02-01").unwrap();
+        assert_eq!(
+            known.get_values("us-gaap", "Assets", "USD")[0].val,
+            Some(json!(500.0))
+        );
+        assert!(known.get_fact("us-gaap", "Liabilities").is_none());
+        let known = facts.as_of("2024-02-02").unwrap();
+        assert_eq!(
+            known.get_values("us-gaap", "Assets", "USD")[0].val,
+            Some(json!(510.0))
+        );
+        assert!(facts.as_of("2024-01-31").unwrap().facts.is_empty());
+
+        for date in ["2024-02-30", "2023-02-29", "2024-2-1"] {
+            assert!(matches!(
+                facts.as_of(date),
+                Err(EdgarApiError::InvalidDate(_))
+            ));
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_as_of_date() {
+        use chrono::NaiveDate;
+
+        let facts = company_facts(json!({
+            "Assets": {"units": {"USD": [
+                fact(None, "2023-12-31", 500.0, "2024-02-29")
+            ]}}
+        }));
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d).unwrap();
+
+        assert_eq!(facts.as_of_date(date(2024, 2, 29)).facts.len(), 1);
+        assert!(facts.as_of_date(date(2024, 2, 28)).facts.is_empty());
+    }
+}
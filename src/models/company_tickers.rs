@@ -4,6 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::identifiers::Cik;
+
 /// Company tickers exchange data from the SEC.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompanyTickers {
@@ -24,7 +26,10 @@ impl CompanyTickers {
                     return Err("Invalid row length".into());
                 }
 
-                let cik = row[0].as_u64().ok_or("Invalid CIK")?;
+                let cik = row[0]
+                    .as_u64()
+                    .and_then(|cik| Cik::try_from(cik).ok())
+                    .ok_or("Invalid CIK")?;
                 let name = row[1].as_str().ok_or("Invalid name")?.to_string();
                 let ticker = row[2].as_str().ok_or("Invalid ticker")?.to_string();
                 let exchange = row[3].as_str().unwrap_or("").to_string();
@@ -44,7 +49,7 @@ impl CompanyTickers {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompanyTickerEntry {
     /// Central Index Key
-    pub cik: u64,
+    pub cik: Cik,
 
     /// Company name
     pub name: String,
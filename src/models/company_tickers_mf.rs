@@ -4,6 +4,8 @@
 
 use serde::{Deserialize, Serialize};
 
+use crate::identifiers::Cik;
+
 /// Mutual fund company tickers data from the SEC.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CompanyTickersMf {
@@ -24,7 +26,10 @@ impl CompanyTickersMf {
                     return Err("Invalid row length".into());
                 }
 
-                let cik = row[0].as_u64().ok_or("Invalid CIK")?;
+                let cik = row[0]
+                    .as_u64()
+                    .and_then(|cik| Cik::try_from(cik).ok())
+                    .ok_or("Invalid CIK")?;
                 let series_id = row[1].as_str().ok_or("Invalid series ID")?.to_string();
                 let class_id = row[2].as_str().ok_or("Invalid class ID")?.to_string();
                 let symbol = row[3].as_str().ok_or("Invalid symbol")?.to_string();
@@ -44,7 +49,7 @@ impl CompanyTickersMf {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MutualFundTickerEntry {
     /// Central Index Key
-    pub cik: u64,
+    pub cik: Cik,
 
     /// Series identifier
     pub series_id: String,
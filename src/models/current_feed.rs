@@ -0,0 +1,442 @@
+//! Models for the latest filings feed.
+//!
+//! This module contains the query builder and data models for EDGAR's
+//! "getcurrent" Atom feed at `https://www.sec.gov/cgi-bin/browse-edgar?action=getcurrent`,
+//! which lists filings within minutes of their acceptance.
+
+use log::warn;
+use quick_xml::events::{BytesStart, Event};
+use quick_xml::Reader;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{EdgarApiError, Result};
+use crate::identifiers::{AccessionNumber, Cik};
+use crate::utils::text::decode_latin1_or_utf8;
+use crate::utils::url::encode_query_component;
+
+/// Maximum number of entries EDGAR returns per page of the feed.
+pub const CURRENT_FEED_MAX_COUNT: u32 = 100;
+
+/// A query for the latest filings feed.
+///
+/// # Example
+///
+/// ```
+/// use edgar_rs::CurrentFeedQuery;
+///
+/// let query = CurrentFeedQuery::new().with_form_type("8-K").with_count(100);
+/// assert_eq!(
+///     query.url(),
+///     "https://www.sec.gov/cgi-bin/browse-edgar?action=getcurrent&type=8-K\
+///      &company=&dateb=&owner=include&start=0&count=100&output=atom"
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CurrentFeedQuery {
+    /// Form type prefix to filter on, e.g. "8-K" also matches "8-K/A".
+    pub form_type: Option<String>,
+
+    /// Offset of the first entry to return.
+    pub start: u32,
+
+    /// Number of entries to return, at most 100.
+    pub count: u32,
+}
+
+impl Default for CurrentFeedQuery {
+    fn default() -> Self {
+        Self {
+            form_type: None,
+            start: 0,
+            count: 40,
+        }
+    }
+}
+
+impl CurrentFeedQuery {
+    /// Creates a query for the 40 most recent filings of any form type.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Only returns filings whose form type starts with `form_type`.
+    pub fn with_form_type(mut self, form_type: impl Into<String>) -> Self {
+        self.form_type = Some(form_type.into());
+        self
+    }
+
+    /// Starts the results at entry number `start` (zero-based).
+    pub fn with_start(mut self, start: u32) -> Self {
+        self.start = start;
+        self
+    }
+
+    /// Sets the number of entries to return, clamped to 1-100.
+    pub fn with_count(mut self, count: u32) -> Self {
+        self.count = count.clamp(1, CURRENT_FEED_MAX_COUNT);
+        self
+    }
+
+    /// Builds the request URL.
+    pub fn url(&self) -> String {
+        format!(
+            "https://www.sec.gov/cgi-bin/browse-edgar?action=getcurrent&type={}\
+             &company=&dateb=&owner=include&start={}&count={}&output=atom",
+            encode_query_component(self.form_type.as_deref().unwrap_or_default()),
+            self.start,
+            self.count
+        )
+    }
+}
+
+/// The latest filings feed.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct CurrentFeed {
+    /// Feed title, e.g. "Latest Filings - Thu, 02 May 2024 16:37:21 EDT".
+    pub title: String,
+
+    /// When the feed was generated (RFC 3339).
+    pub updated: String,
+
+    /// Filings, most recent first.
+    pub entries: Vec<CurrentFeedEntry>,
+}
+
+/// A filing in the latest filings feed.
+///
+/// A filing with several parties, such as an insider transaction report, is
+/// listed once per party with the same accession number and a different `role`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CurrentFeedEntry {
+    /// The form type, e.g. "8-K".
+    pub form_type: String,
+
+    /// The name of the party this entry lists.
+    pub company_name: String,
+
+    /// The CIK of the party this entry lists.
+    pub cik: Cik,
+
+    /// The role of the party in the filing, e.g. "Filer", "Reporting" or "Subject".
+    pub role: String,
+
+    /// The accession number of the filing.
+    pub accession_number: AccessionNumber,
+
+    /// The filing date (YYYY-MM-DD).
+    pub filing_date: String,
+
+    /// When the filing was accepted (RFC 3339).
+    pub updated: String,
+
+    /// URL of the filing index page.
+    pub link: String,
+
+    /// Items reported in the filing, e.g. "Item 2.02: Results of Operations and Financial Condition".
+    pub items: Vec<String>,
+}
+
+#[cfg(feature = "chrono")]
+impl CurrentFeedEntry {
+    /// The filing date.
+    pub fn filed_on(&self) -> Result<chrono::NaiveDate> {
+        crate::utils::date::parse_date(&self.filing_date)
+    }
+
+    /// When the filing was accepted, in Eastern time.
+    pub fn accepted_at(&self) -> Result<chrono::DateTime<chrono::FixedOffset>> {
+        crate::utils::date::parse_datetime(&self.updated)
+    }
+}
+
+impl CurrentFeed {
+    /// Parses the Atom XML of the feed.
+    ///
+    /// Entries without a CIK or accession number are skipped.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use edgar_rs::CurrentFeed;
+    ///
+    /// let xml = r#"<feed xmlns="http://www.w3.org/2005/Atom">
+    ///   <title>Latest Filings</title>
+    ///   <entry>
+    ///     <title>8-K - Apple Inc. (0000320193) (Filer)</title>
+    ///     <id>urn:tag:sec.gov,2008:accession-number=0000320193-24-000069</id>
+    ///     <category scheme="https://www.sec.gov/" label="form type" term="8-K"/>
+    ///   </entry>
+    /// </feed>"#;
+    /// let feed = CurrentFeed::parse(xml).unwrap();
+    /// assert_eq!(feed.entries[0].cik, 320193);
+    /// assert_eq!(feed.entries[0].accession_number, "0000320193-24-000069");
+    /// ```
+    pub fn parse(xml: &str) -> Result<Self> {
+        let mut reader = Reader::from_str(xml);
+        reader.config_mut().trim_text(true);
+
+        let mut feed = CurrentFeed::default();
+        let mut entry: Option<PartialEntry> = None;
+        let mut element = Vec::new();
+
+        loop {
+            let event = reader.read_event().map_err(|e| {
+                EdgarApiError::parse(format!(
+                    "Invalid feed XML at position {}: {}",
+                    reader.error_position(),
+                    e
+                ))
+            })?;
+
+            match event {
+                Event::Start(start) => {
+                    let name = start.local_name().as_ref().to_vec();
+                    if name == b"entry" {
+                        entry = Some(PartialEntry::default());
+                    } else if let Some(entry) = &mut entry {
+                        entry.apply_attributes(&name, &start)?;
+                    }
+                    element = name;
+                }
+                Event::Empty(start) => {
+                    if let Some(entry) = &mut entry {
+                        entry.apply_attributes(start.local_name().as_ref(), &start)?;
+                    }
+                }
+                Event::Text(text) => {
+                    let text = text.unescape().map_err(EdgarApiError::parse)?;
+                    match &mut entry {
+                        Some(entry) => entry.apply_text(&element, &text),
+                        None => match element.as_slice() {
+                            b"title" => feed.title = text.into_owned(),
+                            b"updated" => feed.updated = text.into_owned(),
+                            _ => {}
+                        },
+                    }
+                }
+                Event::End(end) => {
+                    if end.local_name().as_ref() == b"entry" {
+                        feed.entries
+                            .extend(entry.take().and_then(PartialEntry::build));
+                    }
+                    element.clear();
+                }
+                Event::Eof => break,
+                _ => {}
+            }
+        }
+
+        Ok(feed)
+    }
+
+    /// Parses the raw bytes of the feed, which EDGAR serves as ISO-8859-1.
+    pub fn parse_bytes(body: &[u8]) -> Result<Self> {
+        Self::parse(&decode_latin1_or_utf8(body))
+    }
+}
+
+/// A feed entry whose elements are still being read.
+#[derive(Default)]
+struct PartialEntry {
+    form_type: String,
+    company_name: String,
+    cik: Option<Cik>,
+    role: String,
+    accession_number: Option<AccessionNumber>,
+    filing_date: String,
+    updated: String,
+    link: String,
+    items: Vec<String>,
+}
+
+impl PartialEntry {
+    fn build(self) -> Option<CurrentFeedEntry> {
+        let (Some(cik), Some(accession_number)) = (self.cik, self.accession_number) else {
+            warn!(
+                "Skipping feed entry without a CIK or accession number: {} {}",
+                self.form_type, self.company_name
+            );
+            return None;
+        };
+        Some(CurrentFeedEntry {
+            form_type: self.form_type,
+            company_name: self.company_name,
+            cik,
+            role: self.role,
+            accession_number,
+            filing_date: self.filing_date,
+            updated: self.updated,
+            link: self.link,
+            items: self.items,
+        })
+    }
+
+    fn apply_attributes(&mut self, element: &[u8], start: &BytesStart) -> Result<()> {
+        let attribute = match element {
+            b"link" => b"href".as_slice(),
+            b"category" => b"term".as_slice(),
+            _ => return Ok(()),
+        };
+
+        for attr in start.attributes() {
+            let attr = attr.map_err(EdgarApiError::parse)?;
+            if attr.key.as_ref() == attribute {
+                let value = attr.unescape_value().map_err(EdgarApiError::parse)?;
+                match element {
+                    b"link" => self.link = value.into_owned(),
+                    _ => self.form_type = value.into_owned(),
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn apply_text(&mut self, element: &[u8], text: &str) {
+        match element {
+            b"title" => self.apply_title(text),
+            b"summary" => self.apply_summary(text),
+            b"updated" => self.updated = text.to_string(),
+            b"id" => {
+                if let Some((_, accession)) = text.split_once("accession-number=") {
+                    self.accession_number = accession.parse().ok();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Parses a title such as "8-K - Apple Inc. (0000320193) (Filer)".
+    fn apply_title(&mut self, title: &str) {
+        let Some((form_type, rest)) = title.split_once(" - ") else {
+            return;
+        };
+        if self.form_type.is_empty() {
+            self.form_type = form_type.trim().to_string();
+        }
+
+        // Company names may contain parentheses themselves, so parse from the right
+        let mut rest = rest.trim();
+        if let Some((head, role)) = strip_parenthesized(rest) {
+            self.role = role.to_string();
+            rest = head;
+        }
+        if let Some((head, cik)) = strip_parenthesized(rest) {
+            if let Ok(cik) = cik.parse() {
+                self.cik = Some(cik);
+                rest = head;
+            }
+        }
+        self.company_name = rest.to_string();
+    }
+
+    /// Parses a summary such as
+    /// "<b>Filed:</b> 2024-05-02 <b>AccNo:</b> 0000320193-24-000069 <b>Size:</b> 1 MB<br>Item 2.02: ...".
+    fn apply_summary(&mut self, summary: &str) {
+        let mut parts = summary.split("<br>");
+
+        if let Some(header) = parts.next() {
+            if let Some((_, filed)) = header.split_once("Filed:</b>") {
+                if let Some(date) = filed.split_whitespace().next() {
+                    self.filing_date = date.to_string();
+                }
+            }
+        }
+
+        self.items = parts
+            .map(str::trim)
+            .filter(|item| !item.is_empty())
+            .map(str::to_string)
+            .collect();
+    }
+}
+
+/// Splits "name (value)" into "name" and "value".
+fn strip_parenthesized(s: &str) -> Option<(&str, &str)> {
+    let s = s.strip_suffix(')')?;
+    let open = s.rfind('(')?;
+    Some((s[..open].trim_end(), &s[open + 1..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FEED: &str = r#"<?xml version="1.0" encoding="ISO-8859-1" ?>
+<feed xmlns="http://www.w3.org/2005/Atom">
+<title>Latest Filings - Thu, 02 May 2024 16:37:21 EDT</title>
+<link rel="alternate" href="/cgi-bin/browse-edgar?action=getcurrent"/>
+<id>https://www.sec.gov/cgi-bin/browse-edgar?action=getcurrent</id>
+<author><name>Webmaster</name><email>webmaster@sec.gov</email></author>
+<updated>2024-05-02T16:37:21-04:00</updated>
+<entry>
+<title>8-K - Apple Inc. (0000320193) (Filer)</title>
+<link rel="alternate" type="text/html" href="https://www.sec.gov/Archives/edgar/data/320193/000032019324000069/0000320193-24-000069-index.htm"/>
+<summary type="html"> &lt;b&gt;Filed:&lt;/b&gt; 2024-05-02 &lt;b&gt;AccNo:&lt;/b&gt; 0000320193-24-000069 &lt;b&gt;Size:&lt;/b&gt; 1 MB&lt;br&gt;Item 2.02: Results of Operations and Financial Condition&lt;br&gt;Item 9.01: Financial Statements and Exhibits</summary>
+<updated>2024-05-02T16:30:37-04:00</updated>
+<category scheme="https://www.sec.gov/" label="form type" term="8-K"/>
+<id>urn:tag:sec.gov,2008:accession-number=0000320193-24-000069</id>
+</entry>
+<entry>
+<title>4 - Smith John (Jr.) (0001234567) (Reporting)</title>
+<link rel="alternate" type="text/html" href="https://www.sec.gov/Archives/edgar/data/1234567/000123456724000001/0001234567-24-000001-index.htm"/>
+<summary type="html"> &lt;b&gt;Filed:&lt;/b&gt; 2024-05-02 &lt;b&gt;AccNo:&lt;/b&gt; 0001234567-24-000001 &lt;b&gt;Size:&lt;/b&gt; 5 KB</summary>
+<updated>2024-05-02T16:29:01-04:00</updated>
+<category scheme="https://www.sec.gov/" label="form type" term="4"/>
+<id>urn:tag:sec.gov,2008:accession-number=0001234567-24-000001</id>
+</entry>
+</feed>"#;
+
+    #[test]
+    fn test_parse_feed() {
+        let feed = CurrentFeed::parse(FEED).unwrap();
+        assert_eq!(feed.title, "Latest Filings - Thu, 02 May 2024 16:37:21 EDT");
+        assert_eq!(feed.updated, "2024-05-02T16:37:21-04:00");
+        assert_eq!(feed.entries.len(), 2);
+
+        let entry = &feed.entries[0];
+        assert_eq!(entry.form_type, "8-K");
+        assert_eq!(entry.company_name, "Apple Inc.");
+        assert_eq!(entry.cik, 320193);
+        assert_eq!(entry.role, "Filer");
+        assert_eq!(entry.accession_number, "0000320193-24-000069");
+        assert_eq!(entry.filing_date, "2024-05-02");
+        assert_eq!(entry.updated, "2024-05-02T16:30:37-04:00");
+        assert!(entry.link.ends_with("0000320193-24-000069-index.htm"));
+        assert_eq!(
+            entry.items,
+            vec![
+                "Item 2.02: Results of Operations and Financial Condition",
+                "Item 9.01: Financial Statements and Exhibits"
+            ]
+        );
+
+        // Parentheses in names don't confuse the CIK and role
+        let entry = &feed.entries[1];
+        assert_eq!(entry.company_name, "Smith John (Jr.)");
+        assert_eq!(entry.cik, 1234567);
+        assert_eq!(entry.role, "Reporting");
+        assert!(entry.items.is_empty());
+    }
+
+    #[test]
+    fn test_parse_invalid_feed() {
+        assert!(CurrentFeed::parse("<feed><entry></feed>").is_err());
+
+        let feed = CurrentFeed::parse(
+            "<feed><entry><title>8-K - Apple Inc. (0000320193) (Filer)</title></entry></feed>",
+        )
+        .unwrap();
+        assert!(feed.entries.is_empty());
+    }
+
+    #[test]
+    fn test_parse_latin1_feed() {
+        let xml = "<feed><entry><title>8-K - Soci\u{e9}t\u{e9} G\u{e9}n\u{e9}rale (0000000001) (Filer)</title>\
+                   <id>urn:tag:sec.gov,2008:accession-number=0000000001-24-000001</id></entry></feed>";
+        let latin1: Vec<u8> = xml.chars().map(|c| c as u8).collect();
+        let feed = CurrentFeed::parse_bytes(&latin1).unwrap();
+        assert_eq!(feed.entries[0].company_name, "Société Générale");
+        let feed = CurrentFeed::parse_bytes(xml.as_bytes()).unwrap();
+        assert_eq!(feed.entries[0].company_name, "Société Générale");
+    }
+}
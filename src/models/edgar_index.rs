@@ -0,0 +1,403 @@
+//! Models for the EDGAR daily and quarterly indexes.
+//!
+//! EDGAR lists every filing in index files under `/Archives/edgar/daily-index/`
+//! and `/Archives/edgar/full-index/{year}/QTR{n}/`. `form.idx` and `company.idx`
+//! are fixed-width tables sorted by form type and company name, `master.idx` is
+//! pipe-delimited and sorted by CIK. All three contain the same records.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{EdgarApiError, Result};
+use crate::identifiers::{AccessionNumber, Cik};
+use crate::utils::text::decode_latin1_or_utf8;
+
+/// The layout of an index file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum IndexKind {
+    /// `form.idx`: fixed-width, sorted by form type
+    Form,
+    /// `company.idx`: fixed-width, sorted by company name
+    Company,
+    /// `master.idx`: pipe-delimited, sorted by CIK
+    Master,
+}
+
+impl IndexKind {
+    /// Returns the file name prefix, e.g. "form".
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            IndexKind::Form => "form",
+            IndexKind::Company => "company",
+            IndexKind::Master => "master",
+        }
+    }
+
+    /// Detects the kind from a file name such as "form.idx" or "master.20240102.idx".
+    pub fn from_file_name(file_name: &str) -> Option<Self> {
+        match file_name.split('.').next()? {
+            "form" => Some(IndexKind::Form),
+            "company" => Some(IndexKind::Company),
+            "master" => Some(IndexKind::Master),
+            _ => None,
+        }
+    }
+
+    /// Parses the contents of an index file of this kind.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use edgar_rs::IndexKind;
+    ///
+    /// let contents = "\
+    /// CIK|Company Name|Form Type|Date Filed|Filename
+    /// --------------------------------------------------------------------------------
+    /// 320193|Apple Inc.|10-K|2023-11-03|edgar/data/320193/0000320193-23-000106.txt
+    /// ";
+    /// let entries = IndexKind::Master.parse(contents).unwrap();
+    /// let accession_number = entries[0].accession_number().unwrap();
+    /// assert_eq!(accession_number, "0000320193-23-000106");
+    /// ```
+    pub fn parse(&self, contents: &str) -> Result<Vec<IndexEntry>> {
+        self.parse_lines(contents)
+    }
+
+    /// Parses the raw bytes of an index file of this kind.
+    ///
+    /// Some older filer names are ISO-8859-1 rather than UTF-8. Such files are
+    /// decoded one byte per character, which keeps the fixed-width columns aligned.
+    pub fn parse_bytes(&self, contents: &[u8]) -> Result<Vec<IndexEntry>> {
+        self.parse_lines(&decode_latin1_or_utf8(contents))
+    }
+
+    fn parse_lines(&self, contents: &str) -> Result<Vec<IndexEntry>> {
+        let mut lines = contents.lines().enumerate();
+
+        // Entries start after the dashed line below the column headers
+        let mut header = "";
+        for (_, line) in lines.by_ref() {
+            if line.starts_with("---") {
+                break;
+            }
+            if !line.trim().is_empty() {
+                header = line;
+            }
+        }
+
+        let column = match self {
+            IndexKind::Form => Some(header_offset(header, "Company Name")?),
+            IndexKind::Company => Some(header_offset(header, "Form Type")?),
+            IndexKind::Master => None,
+        };
+
+        lines
+            .filter(|(_, line)| !line.trim().is_empty())
+            .map(|(number, line)| {
+                let entry = match column {
+                    Some(column) => self.parse_fixed_width(line, column),
+                    None => parse_master(line),
+                };
+                entry.ok_or_else(|| {
+                    EdgarApiError::parse(format!(
+                        "Malformed {}.idx entry on line {}: {}",
+                        self.as_str(),
+                        number + 1,
+                        line
+                    ))
+                })
+            })
+            .collect()
+    }
+
+    /// Parses a `form.idx` or `company.idx` line. `column` is the character offset
+    /// of the second column, which separates the two free-text columns.
+    fn parse_fixed_width(&self, line: &str, column: usize) -> Option<IndexEntry> {
+        // The last three columns never contain spaces, the first two may
+        let (rest, filename) = split_last_token(line)?;
+        let (rest, date_filed) = split_last_token(rest)?;
+        let (rest, cik) = split_last_token(rest)?;
+
+        let (first, second) = match rest.char_indices().nth(column) {
+            Some((offset, _)) => rest.split_at(offset),
+            None => (rest, ""),
+        };
+        let (form_type, company_name) = match self {
+            IndexKind::Company => (second, first),
+            _ => (first, second),
+        };
+
+        IndexEntry::new(cik, company_name, form_type, date_filed, filename)
+    }
+}
+
+impl std::fmt::Display for IndexKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A filing listed in an EDGAR index.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct IndexEntry {
+    /// The CIK of the filer.
+    pub cik: Cik,
+
+    /// The name of the filer.
+    pub company_name: String,
+
+    /// The form type, e.g. "10-K" or "SC 13G/A".
+    pub form_type: String,
+
+    /// The filing date (YYYY-MM-DD).
+    pub date_filed: String,
+
+    /// Path of the complete submission file relative to `/Archives/`,
+    /// e.g. "edgar/data/320193/0000320193-23-000106.txt".
+    pub filename: String,
+}
+
+impl IndexEntry {
+    fn new(
+        cik: &str,
+        company_name: &str,
+        form_type: &str,
+        date_filed: &str,
+        filename: &str,
+    ) -> Option<Self> {
+        Some(Self {
+            cik: cik.trim().parse().ok()?,
+            company_name: company_name.trim().to_string(),
+            form_type: form_type.trim().to_string(),
+            date_filed: normalize_date(date_filed.trim())?,
+            filename: filename.trim().to_string(),
+        })
+    }
+
+    /// The accession number of the filing, taken from the file name.
+    pub fn accession_number(&self) -> Option<AccessionNumber> {
+        let file_name = self.filename.rsplit('/').next()?;
+        file_name
+            .strip_suffix(".txt")
+            .unwrap_or(file_name)
+            .parse()
+            .ok()
+    }
+
+    /// URL of the complete submission text file.
+    pub fn url(&self) -> String {
+        format!("https://www.sec.gov/Archives/{}", self.filename)
+    }
+
+    /// The filing date.
+    #[cfg(feature = "chrono")]
+    pub fn filed_on(&self) -> Result<chrono::NaiveDate> {
+        crate::utils::date::parse_date(&self.date_filed)
+    }
+}
+
+/// Reads and parses a local copy of an index file.
+///
+/// The kind is detected from the file name, e.g. "company.idx" or "form.20240102.idx".
+#[cfg(feature = "native")]
+pub fn read_index_file(path: impl AsRef<std::path::Path>) -> Result<Vec<IndexEntry>> {
+    let path = path.as_ref();
+    let kind = path
+        .file_name()
+        .and_then(|name| name.to_str())
+        .and_then(IndexKind::from_file_name)
+        .ok_or_else(|| {
+            EdgarApiError::request(format!("Unknown index file name: {}", path.display()))
+        })?;
+
+    kind.parse_bytes(&std::fs::read(path)?)
+}
+
+fn parse_master(line: &str) -> Option<IndexEntry> {
+    let mut fields = line.split('|');
+    let cik = fields.next()?;
+    let company_name = fields.next()?;
+    let form_type = fields.next()?;
+    let date_filed = fields.next()?;
+    let filename = fields.next()?;
+    IndexEntry::new(cik, company_name, form_type, date_filed, filename)
+}
+
+/// Returns the character offset of `column` in the header line.
+fn header_offset(header: &str, column: &str) -> Result<usize> {
+    let offset = header.find(column).ok_or_else(|| {
+        EdgarApiError::parse(format!("Index header is missing the {} column", column))
+    })?;
+    Ok(header[..offset].chars().count())
+}
+
+/// Splits off the last whitespace-separated token.
+fn split_last_token(s: &str) -> Option<(&str, &str)> {
+    let s = s.trim_end();
+    // Decoded Latin-1 may contain multi-byte whitespace such as U+00A0
+    let start = s
+        .char_indices()
+        .rfind(|(_, c)| c.is_whitespace())
+        .map(|(i, c)| i + c.len_utf8())?;
+    Some((&s[..start], &s[start..]))
+}
+
+/// Converts "20240102" (daily indexes) and "2024-01-02" (quarterly indexes) to "2024-01-02".
+fn normalize_date(date: &str) -> Option<String> {
+    let digits: String = date.chars().filter(|c| *c != '-').collect();
+    if digits.len() != 8 || !digits.chars().all(|c| c.is_ascii_digit()) {
+        return None;
+    }
+    Some(format!(
+        "{}-{}-{}",
+        &digits[..4],
+        &digits[4..6],
+        &digits[6..]
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORM_IDX: &str = "\
+Description:           Daily Index of EDGAR Dissemination Feed by Form Type
+Last Data Received:    Jan 2, 2024
+Comments:              webmaster@sec.gov
+Anonymous FTP:         ftp://ftp.sec.gov/edgar/
+
+Form Type   Company Name                                                  CIK         Date Filed  File Name
+---------------------------------------------------------------------------------------------------------------------------------------------
+10-K        1847 Holdings LLC                                             1599407     20240102    edgar/data/1599407/0001640334-24-000510.txt
+SC 13G/A    APPLE INC                                                     320193      20240102    edgar/data/320193/0000950103-24-000012.txt
+";
+
+    const COMPANY_IDX: &str = "\
+Description:           Master Index of EDGAR Dissemination Feed by Company Name
+Last Data Received:    March 31, 2024
+
+Company Name                                                  Form Type   CIK         Date Filed  File Name
+---------------------------------------------------------------------------------------------------------------------------------------------
+APPLE INC                                                     SC 13G/A    320193      2024-01-02  edgar/data/320193/0000950103-24-000012.txt
+MICROSOFT CORP                                                10-Q        789019      2024-01-25  edgar/data/789019/0000950170-24-008814.txt
+";
+
+    const MASTER_IDX: &str = "\
+Description:           Master Index of EDGAR Dissemination Feed
+Last Data Received:    March 31, 2024
+Comments:              webmaster@sec.gov
+Anonymous FTP:         ftp://ftp.sec.gov/edgar/
+Cloud HTTP:            https://www.sec.gov/Archives/
+
+
+
+
+CIK|Company Name|Form Type|Date Filed|Filename
+--------------------------------------------------------------------------------
+1000045|NICHOLAS FINANCIAL INC|10-Q|2024-02-13|edgar/data/1000045/0000950170-24-014566.txt
+320193|Apple Inc.|8-K|2024-02-01|edgar/data/320193/0000320193-24-000003.txt
+";
+
+    #[test]
+    fn test_parse_form_index() {
+        let entries = IndexKind::Form.parse(FORM_IDX).unwrap();
+        assert_eq!(entries.len(), 2);
+
+        assert_eq!(entries[0].form_type, "10-K");
+        assert_eq!(entries[0].company_name, "1847 Holdings LLC");
+        assert_eq!(entries[0].cik, 1599407);
+        assert_eq!(entries[0].date_filed, "2024-01-02");
+
+        // Form types may contain spaces
+        assert_eq!(entries[1].form_type, "SC 13G/A");
+        assert_eq!(entries[1].company_name, "APPLE INC");
+        assert_eq!(entries[1].cik.to_string(), "0000320193");
+    }
+
+    #[test]
+    fn test_parse_company_index() {
+        let entries = IndexKind::Company.parse(COMPANY_IDX).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].company_name, "APPLE INC");
+        assert_eq!(entries[0].form_type, "SC 13G/A");
+        assert_eq!(entries[1].company_name, "MICROSOFT CORP");
+        assert_eq!(entries[1].form_type, "10-Q");
+        assert_eq!(entries[1].date_filed, "2024-01-25");
+    }
+
+    #[test]
+    fn test_parse_master_index() {
+        let entries = IndexKind::Master.parse(MASTER_IDX).unwrap();
+        assert_eq!(entries.len(), 2);
+
+        let entry = &entries[1];
+        assert_eq!(entry.cik, 320193);
+        assert_eq!(entry.company_name, "Apple Inc.");
+        assert_eq!(entry.form_type, "8-K");
+        assert_eq!(entry.accession_number().unwrap(), "0000320193-24-000003");
+        assert_eq!(
+            entry.url(),
+            "https://www.sec.gov/Archives/edgar/data/320193/0000320193-24-000003.txt"
+        );
+    }
+
+    #[test]
+    fn test_parse_latin1_index() {
+        // 0x01 stands in for the ISO-8859-1 byte 0xC9 ("É")
+        let name =
+            "SOCI\u{1}T\u{1} G\u{1}N\u{1}RALE D'\u{1}QUIPEMENT ET D'\u{1}TUDES INDUSTRIELLES";
+        let contents = format!(
+            "Company Name{:<50}Form Type   CIK         Date Filed  File Name\n---\n{:<62}{:<12}{:<12}{:<12}{}\n",
+            "",
+            name,
+            "SC 13G/A",
+            "320193",
+            "2024-01-02",
+            "edgar/data/320193/0000950103-24-000012.txt"
+        );
+        let bytes: Vec<u8> = contents
+            .bytes()
+            .map(|b| if b == 1 { 0xC9 } else { b })
+            .collect();
+
+        let entries = IndexKind::Company.parse_bytes(&bytes).unwrap();
+        assert_eq!(entries[0].company_name, name.replace('\u{1}', "É"));
+        assert_eq!(entries[0].form_type, "SC 13G/A");
+        assert_eq!(entries[0].cik, 320193);
+    }
+
+    #[test]
+    fn test_parse_latin1_non_breaking_space() {
+        // A name ending in ISO-8859-1 0xA0 runs straight into the CIK column
+        let mut bytes = b"Form Type   Company Name  CIK         Date Filed  File Name\n---\n\
+                          10-K        FOO"
+            .to_vec();
+        bytes.push(0xA0);
+        bytes.extend_from_slice(
+            b"1599407     20240102    edgar/data/1599407/0001640334-24-000510.txt\n",
+        );
+
+        let entries = IndexKind::Form.parse_bytes(&bytes).unwrap();
+        assert_eq!(entries[0].company_name, "FOO");
+        assert_eq!(entries[0].cik, 1599407);
+    }
+
+    #[test]
+    fn test_parse_rejects_malformed_entries() {
+        let contents =
+            "CIK|Company Name|Form Type|Date Filed|Filename\n---\n320193|Apple Inc.|8-K\n";
+        let error = IndexKind::Master.parse(contents).unwrap_err();
+        assert!(error.to_string().contains("line 3"));
+
+        assert!(IndexKind::Form.parse(MASTER_IDX).is_err());
+    }
+
+    #[test]
+    fn test_index_kind_from_file_name() {
+        assert_eq!(IndexKind::from_file_name("form.idx"), Some(IndexKind::Form));
+        assert_eq!(
+            IndexKind::from_file_name("master.20240102.idx"),
+            Some(IndexKind::Master)
+        );
+        assert_eq!(IndexKind::from_file_name("xbrl.idx"), None);
+    }
+}
@@ -0,0 +1,153 @@
+//! Models for filing index data.
+//!
+//! This module contains data models for the `index.json` listing of a filing's
+//! directory in the EDGAR Archives.
+
+use serde::{Deserialize, Serialize};
+
+/// The directory listing of a filing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilingIndex {
+    /// The filing directory.
+    pub directory: FilingDirectory,
+}
+
+/// A directory in the EDGAR Archives.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilingDirectory {
+    /// Path of the directory, e.g. "/Archives/edgar/data/320193/000032019323000106".
+    pub name: String,
+
+    /// Path of the parent directory.
+    #[serde(default)]
+    #[serde(rename = "parent-dir")]
+    pub parent_dir: String,
+
+    /// Documents and subdirectories in the directory.
+    #[serde(default)]
+    pub item: Vec<FilingIndexItem>,
+}
+
+/// A document or subdirectory in a filing directory.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FilingIndexItem {
+    /// File name, e.g. "aapl-20230930.htm".
+    pub name: String,
+
+    /// Icon type shown by EDGAR, e.g. "text.gif" or "folder.gif".
+    #[serde(default)]
+    #[serde(rename = "type")]
+    pub item_type: String,
+
+    /// Size in bytes as reported by EDGAR; empty for directories and index pages.
+    #[serde(default)]
+    pub size: String,
+
+    /// Last modification time, e.g. "2023-11-02 18:08:27".
+    #[serde(default)]
+    #[serde(rename = "last-modified")]
+    pub last_modified: String,
+}
+
+impl FilingIndexItem {
+    /// Size in bytes, if EDGAR reported one.
+    pub fn size_bytes(&self) -> Option<u64> {
+        self.size.trim().parse().ok()
+    }
+
+    /// Returns true if the item is a subdirectory.
+    pub fn is_directory(&self) -> bool {
+        self.item_type == "folder.gif"
+    }
+}
+
+/// Helper methods for the FilingIndex struct.
+impl FilingIndex {
+    /// Returns the documents in the filing, skipping subdirectories.
+    pub fn documents(&self) -> impl Iterator<Item = &FilingIndexItem> {
+        self.directory
+            .item
+            .iter()
+            .filter(|item| !item.is_directory())
+    }
+
+    /// Returns the item with the given file name.
+    pub fn find(&self, name: &str) -> Option<&FilingIndexItem> {
+        self.directory.item.iter().find(|item| item.name == name)
+    }
+
+    /// Returns the documents whose file name ends with `extension` (case-insensitive),
+    /// e.g. "htm" or ".xml".
+    pub fn documents_with_extension<'a>(
+        &'a self,
+        extension: &'a str,
+    ) -> impl Iterator<Item = &'a FilingIndexItem> + 'a {
+        let extension = extension.trim_start_matches('.');
+        self.documents().filter(move |item| {
+            item.name
+                .rsplit_once('.')
+                .is_some_and(|(_, ext)| ext.eq_ignore_ascii_case(extension))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_deserialize_filing_index() {
+        let json_data = json!({
+            "directory": {
+                "item": [
+                    {
+                        "last-modified": "2023-11-02 18:08:27",
+                        "name": "0000320193-23-000106-index-headers.html",
+                        "type": "text.gif",
+                        "size": ""
+                    },
+                    {
+                        "last-modified": "2023-11-02 18:08:27",
+                        "name": "aapl-20230930.htm",
+                        "type": "text.gif",
+                        "size": "1684512"
+                    },
+                    {
+                        "last-modified": "2023-11-02 18:08:27",
+                        "name": "aapl-20230930_htm.xml",
+                        "type": "text.gif",
+                        "size": "850321"
+                    },
+                    {
+                        "last-modified": "2023-11-02 18:08:27",
+                        "name": "exhibits",
+                        "type": "folder.gif",
+                        "size": ""
+                    }
+                ],
+                "name": "/Archives/edgar/data/320193/000032019323000106",
+                "parent-dir": "/Archives/edgar/data/320193"
+            }
+        });
+
+        let index: FilingIndex = serde_json::from_value(json_data).unwrap();
+        assert_eq!(index.directory.parent_dir, "/Archives/edgar/data/320193");
+        assert_eq!(index.documents().count(), 3);
+
+        let document = index.find("aapl-20230930.htm").unwrap();
+        assert_eq!(document.size_bytes(), Some(1684512));
+        assert_eq!(
+            index
+                .find("0000320193-23-000106-index-headers.html")
+                .unwrap()
+                .size_bytes(),
+            None
+        );
+        assert!(index.find("exhibits").unwrap().is_directory());
+
+        let xml: Vec<_> = index.documents_with_extension(".XML").collect();
+        assert_eq!(xml.len(), 1);
+        assert_eq!(xml[0].name, "aapl-20230930_htm.xml");
+    }
+}
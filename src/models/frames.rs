@@ -2,9 +2,17 @@
 //!
 //! This module contains data models for the SEC EDGAR API XBRL frames responses.
 
-use log::{error, trace};
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "chrono")]
+use chrono::NaiveDate;
+
+#[cfg(feature = "chrono")]
+use crate::error::Result;
+use crate::identifiers::{AccessionNumber, Cik};
+#[cfg(feature = "chrono")]
+use crate::utils::date::{parse_date, parse_optional_date};
+
 /// An XBRL frames response from the SEC EDGAR API.
 ///
 /// This struct represents the response from the XBRL frames endpoint, which
@@ -19,7 +27,7 @@ pub struct XbrlFrames {
     pub tag: String,
 
     /// The CIK numbers included in the frame.
-    pub ciks: Option<Vec<String>>, // TODO: Changed, Review
+    pub ciks: Option<Vec<Cik>>,
 
     /// The unit of measure.
     pub unit: Option<String>, // TODO: Changed, Review
@@ -44,7 +52,7 @@ pub struct XbrlFrames {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FrameValue {
     /// The CIK number of the entity.
-    pub cik: u64,
+    pub cik: Cik,
 
     /// The name of the entity.
     #[serde(rename = "entityName")]
@@ -54,7 +62,7 @@ pub struct FrameValue {
     pub val: f64,
 
     /// The accession number of the filing.
-    pub accn: String,
+    pub accn: AccessionNumber,
 
     /// The fiscal year.
     pub fy: Option<i32>, // TODO: Changed, Review
@@ -76,6 +84,37 @@ pub struct FrameValue {
     pub start: Option<String>,
 }
 
+/// Typed dates for FrameValue
+#[cfg(feature = "chrono")]
+impl FrameValue {
+    /// Returns the end date of the reporting period.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate) if the date is malformed.
+    pub fn end_date(&self) -> Result<NaiveDate> {
+        parse_date(&self.end)
+    }
+
+    /// Returns the start date of the reporting period, or `None` for instant values.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate) if the date is malformed.
+    pub fn start_date(&self) -> Result<Option<NaiveDate>> {
+        parse_optional_date(self.start.as_deref())
+    }
+
+    /// Returns the date the report was filed, if the frame includes it.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate) if the date is malformed.
+    pub fn filed_on(&self) -> Result<Option<NaiveDate>> {
+        parse_optional_date(self.filed.as_deref())
+    }
+}
+
 impl XbrlFrames {
     /// Returns the values for a specific company.
     ///
@@ -90,7 +129,7 @@ impl XbrlFrames {
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config, Taxonomy, Unit, Period};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config, Taxonomy, Unit, Period};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
@@ -100,29 +139,15 @@ impl XbrlFrames {
     ///     Unit::Simple("USD".to_string()),
     ///     Period::Instantaneous(2019, 1)
     /// ).await?;
-    /// let apple_values = frames.data.get_values_for_company("0000320193");
+    /// let apple_values = frames.data.get_values_for_company(Cik::new(320193));
     /// for value in apple_values {
     ///     println!("Apple Accounts Payable: {} USD (as of {})", value.val, value.end);
     /// }
     /// # Ok(())
     /// # }
     /// ```
-    pub fn get_values_for_company(&self, cik: &str) -> Vec<&FrameValue> {
-        // Try to parse the CIK
-        let cik_number = match cik.parse::<u64>() {
-            Ok(num) => num,
-            Err(e) => {
-                error!("Failed to parse CIK '{}': {}", cik, e);
-                return Vec::new(); // Early return for invalid CIKs
-            }
-        };
-
-        trace!("Successfully parsed CIK '{}' to {}", cik, cik_number);
-
-        self.data
-            .iter()
-            .filter(|value| value.cik == cik_number)
-            .collect()
+    pub fn get_values_for_company(&self, cik: Cik) -> Vec<&FrameValue> {
+        self.data.iter().filter(|value| value.cik == cik).collect()
     }
 
     /// Returns the top N companies by value.
@@ -209,6 +234,8 @@ impl XbrlFrames {
         let sum: f64 = values.iter().sum();
         let mean = if count > 0 { sum / count as f64 } else { 0.0 };
 
+        // `usize::is_multiple_of` would require Rust 1.87
+        #[allow(unknown_lints, clippy::manual_is_multiple_of)]
         let median = if count > 0 {
             if count % 2 == 0 {
                 (values[count / 2 - 1] + values[count / 2]) / 2.0
@@ -0,0 +1,398 @@
+//! Models for EDGAR full-text search.
+//!
+//! This module contains the query builder and response models for the
+//! full-text search endpoint at `https://efts.sec.gov/LATEST/search-index`.
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::{EdgarApiError, Result};
+use crate::identifiers::{AccessionNumber, Cik};
+use crate::utils::date::parse_ymd;
+use crate::utils::url::encode_query_component;
+
+/// Number of hits returned per page of full-text search results.
+pub const FULL_TEXT_SEARCH_PAGE_SIZE: u32 = 100;
+
+/// A full-text search query.
+///
+/// Phrases can be matched exactly by wrapping them in double quotes, as on the
+/// EDGAR full-text search page.
+///
+/// # Example
+///
+/// ```
+/// use edgar_rs::{Cik, FullTextSearchQuery};
+///
+/// let query = FullTextSearchQuery::new("\"supply chain disruption\"")
+///     .with_forms(["10-K", "10-Q"])
+///     .with_date_range("2023-01-01", "2023-12-31")
+///     .with_cik(Cik::new(320193))
+///     .with_page(2);
+///
+/// let url = query.url().unwrap();
+/// assert!(url.starts_with("https://efts.sec.gov/LATEST/search-index?q=%22supply%20chain"));
+/// assert!(url.contains("&forms=10-K,10-Q"));
+/// assert!(url.contains("&ciks=0000320193"));
+/// assert!(url.ends_with("&from=100"));
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FullTextSearchQuery {
+    /// Search terms.
+    pub query: String,
+
+    /// Form types to include, e.g. "10-K". Empty means all forms.
+    pub forms: Vec<String>,
+
+    /// First filing date to include (YYYY-MM-DD).
+    pub start_date: Option<String>,
+
+    /// Last filing date to include (YYYY-MM-DD).
+    pub end_date: Option<String>,
+
+    /// Filer CIKs to include. Empty means all filers.
+    pub ciks: Vec<Cik>,
+
+    /// Offset of the first hit to return.
+    pub from: u32,
+}
+
+impl FullTextSearchQuery {
+    /// Creates a query for the given search terms.
+    pub fn new(query: impl Into<String>) -> Self {
+        Self {
+            query: query.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Restricts results to a form type. Can be called repeatedly.
+    pub fn with_form(mut self, form: impl Into<String>) -> Self {
+        self.forms.push(form.into());
+        self
+    }
+
+    /// Restricts results to the given form types.
+    pub fn with_forms<I, S>(mut self, forms: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.forms.extend(forms.into_iter().map(Into::into));
+        self
+    }
+
+    /// Restricts results to filings made between `start` and `end` (YYYY-MM-DD), inclusive.
+    pub fn with_date_range(mut self, start: impl Into<String>, end: impl Into<String>) -> Self {
+        self.start_date = Some(start.into());
+        self.end_date = Some(end.into());
+        self
+    }
+
+    /// Restricts results to a filer. Can be called repeatedly.
+    pub fn with_cik(mut self, cik: Cik) -> Self {
+        self.ciks.push(cik);
+        self
+    }
+
+    /// Starts the results at hit number `from` (zero-based).
+    pub fn with_offset(mut self, from: u32) -> Self {
+        self.from = from;
+        self
+    }
+
+    /// Returns the given page of results (starting at 1).
+    pub fn with_page(self, page: u32) -> Self {
+        self.with_offset(
+            page.saturating_sub(1)
+                .saturating_mul(FULL_TEXT_SEARCH_PAGE_SIZE),
+        )
+    }
+
+    /// Returns a copy of the query for the following page of results.
+    pub fn next_page(&self) -> Self {
+        self.clone()
+            .with_offset(self.from.saturating_add(FULL_TEXT_SEARCH_PAGE_SIZE))
+    }
+
+    /// Builds the request URL.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a date is malformed.
+    pub fn url(&self) -> Result<String> {
+        let mut url = format!(
+            "https://efts.sec.gov/LATEST/search-index?q={}",
+            encode_query_component(&self.query)
+        );
+
+        if !self.forms.is_empty() {
+            let forms: Vec<String> = self
+                .forms
+                .iter()
+                .map(|form| encode_query_component(form))
+                .collect();
+            url.push_str(&format!("&forms={}", forms.join(",")));
+        }
+
+        if self.start_date.is_some() || self.end_date.is_some() {
+            url.push_str("&dateRange=custom");
+            for (name, date) in [("startdt", &self.start_date), ("enddt", &self.end_date)] {
+                if let Some(date) = date {
+                    if parse_ymd(date).is_none() {
+                        return Err(EdgarApiError::request(format!(
+                            "Invalid date for full-text search, expected YYYY-MM-DD: {}",
+                            date
+                        )));
+                    }
+                    url.push_str(&format!("&{}={}", name, date));
+                }
+            }
+        }
+
+        if !self.ciks.is_empty() {
+            let ciks: Vec<String> = self.ciks.iter().map(Cik::to_string).collect();
+            url.push_str(&format!("&ciks={}", ciks.join(",")));
+        }
+
+        if self.from > 0 {
+            url.push_str(&format!("&from={}", self.from));
+        }
+
+        Ok(url)
+    }
+}
+
+/// Full-text search results.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FullTextSearchResults {
+    /// The matching documents.
+    pub hits: FullTextSearchHits,
+}
+
+impl FullTextSearchResults {
+    /// Total number of matching documents.
+    ///
+    /// EDGAR stops counting at 10,000; see [`TotalHits::relation`].
+    pub fn total(&self) -> u64 {
+        self.hits.total.value
+    }
+
+    /// The hits on this page.
+    pub fn hits(&self) -> &[FullTextSearchHit] {
+        &self.hits.hits
+    }
+}
+
+/// A page of full-text search hits.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FullTextSearchHits {
+    /// Number of matching documents.
+    pub total: TotalHits,
+
+    /// The hits on this page.
+    #[serde(default)]
+    pub hits: Vec<FullTextSearchHit>,
+}
+
+/// The number of matching documents.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TotalHits {
+    /// The count.
+    pub value: u64,
+
+    /// "eq" if the count is exact, "gte" if it is a lower bound.
+    #[serde(default)]
+    pub relation: String,
+}
+
+/// A document matching a full-text search.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FullTextSearchHit {
+    /// Identifier of the form "{accession number}:{document name}".
+    #[serde(rename = "_id")]
+    pub id: String,
+
+    /// Relevance score.
+    #[serde(default)]
+    #[serde(rename = "_score")]
+    pub score: Option<f64>,
+
+    /// The filing the document belongs to.
+    #[serde(rename = "_source")]
+    pub source: FullTextSearchFiling,
+}
+
+impl FullTextSearchHit {
+    /// The accession number of the filing.
+    pub fn accession_number(&self) -> AccessionNumber {
+        self.source.adsh
+    }
+
+    /// File name of the matching document within the filing.
+    pub fn document_name(&self) -> Option<&str> {
+        self.id.split_once(':').map(|(_, name)| name)
+    }
+
+    /// URL of the matching document in the EDGAR archives.
+    pub fn document_url(&self) -> Option<String> {
+        let cik = *self.source.ciks.first()?;
+        let document = self.document_name()?;
+        Some(format!(
+            "{}{}",
+            self.source.adsh.directory_url(cik),
+            document
+        ))
+    }
+}
+
+/// Filing metadata attached to a full-text search hit.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FullTextSearchFiling {
+    /// The accession number of the filing.
+    pub adsh: AccessionNumber,
+
+    /// The CIKs of the filers.
+    #[serde(default)]
+    pub ciks: Vec<Cik>,
+
+    /// Filer names as displayed by EDGAR, e.g. "Apple Inc.  (AAPL)  (CIK 0000320193)".
+    #[serde(default)]
+    pub display_names: Vec<String>,
+
+    /// The form type of the filing.
+    #[serde(default)]
+    pub form: String,
+
+    /// The base form types of the filing, without amendment suffixes.
+    #[serde(default)]
+    pub root_forms: Vec<String>,
+
+    /// The filing date (YYYY-MM-DD).
+    #[serde(default)]
+    pub file_date: String,
+
+    /// The end of the reporting period (YYYY-MM-DD).
+    #[serde(default)]
+    pub period_ending: Option<String>,
+
+    /// The type of the matching document, e.g. "10-K" or "EX-21.1".
+    #[serde(default)]
+    pub file_type: Option<String>,
+
+    /// The description of the matching document.
+    #[serde(default)]
+    pub file_description: Option<String>,
+
+    /// The 8-K items reported in the filing.
+    #[serde(default)]
+    pub items: Vec<String>,
+
+    /// The SIC codes of the filers.
+    #[serde(default)]
+    pub sics: Vec<String>,
+
+    /// The business locations of the filers.
+    #[serde(default)]
+    pub biz_locations: Vec<String>,
+
+    /// The states of incorporation of the filers.
+    #[serde(default)]
+    pub inc_states: Vec<String>,
+
+    /// The SEC file numbers of the filing.
+    #[serde(default)]
+    pub file_num: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_query_url() {
+        let query = FullTextSearchQuery::new("cyber attack");
+        assert_eq!(
+            query.url().unwrap(),
+            "https://efts.sec.gov/LATEST/search-index?q=cyber%20attack"
+        );
+
+        let query = query
+            .with_form("8-K")
+            .with_date_range("2024-01-01", "2024-03-31")
+            .with_cik(Cik::new(320193))
+            .with_cik(Cik::new(789019));
+        assert_eq!(
+            query.next_page().url().unwrap(),
+            "https://efts.sec.gov/LATEST/search-index?q=cyber%20attack&forms=8-K\
+             &dateRange=custom&startdt=2024-01-01&enddt=2024-03-31\
+             &ciks=0000320193,0000789019&from=100"
+        );
+    }
+
+    #[test]
+    fn test_page_offsets_saturate() {
+        let query = FullTextSearchQuery::new("risk");
+        assert_eq!(query.clone().with_page(0).from, 0);
+        assert_eq!(query.clone().with_page(3).from, 200);
+        assert_eq!(query.clone().with_page(u32::MAX).from, u32::MAX);
+        assert_eq!(query.with_offset(u32::MAX - 1).next_page().from, u32::MAX);
+    }
+
+    #[test]
+    fn test_query_url_rejects_invalid_input() {
+        let query = FullTextSearchQuery::new("risk").with_date_range("2024-1-1", "2024-12-31");
+        assert!(matches!(query.url(), Err(EdgarApiError::RequestError(_))));
+    }
+
+    #[test]
+    fn test_deserialize_results() {
+        let json_data = json!({
+            "took": 42,
+            "timed_out": false,
+            "hits": {
+                "total": { "value": 10000, "relation": "gte" },
+                "max_score": 12.5,
+                "hits": [{
+                    "_index": "edgar_file",
+                    "_id": "0000320193-23-000106:aapl-20230930.htm",
+                    "_score": 12.5,
+                    "_source": {
+                        "ciks": ["0000320193"],
+                        "period_ending": "2023-09-30",
+                        "file_num": ["001-36743"],
+                        "display_names": ["Apple Inc.  (AAPL)  (CIK 0000320193)"],
+                        "xsl": null,
+                        "sequence": 1,
+                        "root_forms": ["10-K"],
+                        "file_date": "2023-11-03",
+                        "biz_states": ["CA"],
+                        "sics": ["3571"],
+                        "form": "10-K",
+                        "adsh": "0000320193-23-000106",
+                        "film_num": ["231373899"],
+                        "biz_locations": ["Cupertino, CA"],
+                        "file_type": "10-K",
+                        "file_description": "10-K",
+                        "inc_states": ["CA"],
+                        "items": []
+                    }
+                }]
+            }
+        });
+
+        let results: FullTextSearchResults = serde_json::from_value(json_data).unwrap();
+        assert_eq!(results.total(), 10000);
+        assert_eq!(results.hits.total.relation, "gte");
+
+        let hit = &results.hits()[0];
+        assert_eq!(hit.accession_number(), "0000320193-23-000106");
+        assert_eq!(hit.document_name(), Some("aapl-20230930.htm"));
+        assert_eq!(
+            hit.document_url().unwrap(),
+            "https://www.sec.gov/Archives/edgar/data/320193/000032019323000106/aapl-20230930.htm"
+        );
+        assert_eq!(hit.source.form, "10-K");
+        assert_eq!(hit.source.period_ending.as_deref(), Some("2023-09-30"));
+    }
+}
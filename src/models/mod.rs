@@ -6,5 +6,9 @@ pub mod company_concept;
 pub mod company_facts;
 pub mod company_tickers;
 pub mod company_tickers_mf;
+pub mod current_feed;
+pub mod edgar_index;
+pub mod filing_index;
 pub mod frames;
+pub mod full_text_search;
 pub mod submission;
@@ -5,11 +5,22 @@
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, FixedOffset, NaiveDate};
+
+#[cfg(feature = "chrono")]
+use crate::error::Result;
+use crate::analysis::FiscalCalendar;
+use crate::form_type::FormType;
+use crate::identifiers::{AccessionNumber, Cik};
+#[cfg(feature = "chrono")]
+use crate::utils::date::{parse_date, parse_datetime, parse_optional_date};
+
 /// A company's submission history from the SEC EDGAR API.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SubmissionHistory {
     /// The CIK number of the company.
-    pub cik: String,
+    pub cik: Cik,
 
     /// The entity type.
     #[serde(default)]
@@ -51,6 +62,11 @@ pub struct SubmissionHistory {
     #[serde(rename = "formerNames")]
     pub former_names: Vec<FormerName>,
 
+    /// The month and day the company's fiscal year ends, as "MMDD".
+    #[serde(default)]
+    #[serde(rename = "fiscalYearEnd")]
+    pub fiscal_year_end: Option<String>,
+
     /// The company's filing history.
     pub filings: Filings,
 
@@ -89,7 +105,7 @@ pub struct Recent {
     /// The accession numbers of the filings.
     #[serde(default)]
     #[serde(rename = "accessionNumber")]
-    pub accession_number: Vec<String>,
+    pub accession_number: Vec<AccessionNumber>,
 
     /// The filing dates of the filings.
     #[serde(default)]
@@ -189,11 +205,11 @@ impl SubmissionHistory {
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let submissions = edgar_api.get_submissions_history("0000320193").await?;
+    /// let submissions = edgar_api.get_submissions_history(Cik::new(320193)).await?;
     /// let filings = submissions.data.get_recent_filings();
     /// for filing in filings {
     ///     println!("Form: {}, Filing Date: {}", filing.form, filing.filing_date);
@@ -205,6 +221,103 @@ impl SubmissionHistory {
         Self::convert_recent_to_filing_entries(&self.filings.recent)
     }
 
+    /// Returns the recent filings of a specific form.
+    ///
+    /// Amendments only match amendments; use
+    /// [`get_recent_filings_for_base_form`](Self::get_recent_filings_for_base_form)
+    /// to include them.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config, FormType};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let submissions = edgar_api.get_submissions_history(Cik::new(320193)).await?;
+    /// for filing in submissions.data.get_recent_filings_for_form(FormType::EightK) {
+    ///     println!("8-K filed {}: {}", filing.filing_date, filing.items);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_recent_filings_for_form(&self, form: impl Into<FormType>) -> Vec<FilingEntry> {
+        let form = form.into();
+        self.get_recent_filings()
+            .into_iter()
+            .filter(|filing| filing.form_type() == form)
+            .collect()
+    }
+
+    /// Returns the recent filings of a form and its amendments, so "10-K"
+    /// also returns "10-K/A" filings.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let submissions = edgar_api.get_submissions_history(Cik::new(320193)).await?;
+    /// for filing in submissions.data.get_recent_filings_for_base_form("10-K") {
+    ///     println!("{} filed {}", filing.form, filing.filing_date);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn get_recent_filings_for_base_form(&self, form: impl Into<FormType>) -> Vec<FilingEntry> {
+        let form = form.into();
+        self.get_recent_filings()
+            .into_iter()
+            .filter(|filing| filing.form_type().same_base_form(&form))
+            .collect()
+    }
+
+    /// Returns the recent filings filed between `start` and `end`, inclusive.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate)
+    /// if a filing has a malformed filing date.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::chrono::NaiveDate;
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let submissions = edgar_api.get_submissions_history(Cik::new(320193)).await?;
+    /// let filings = submissions.data.get_recent_filings_between(
+    ///     NaiveDate::from_ymd_opt(2023, 1, 1).unwrap(),
+    ///     NaiveDate::from_ymd_opt(2023, 12, 31).unwrap(),
+    /// )?;
+    /// for filing in filings {
+    ///     println!("Form: {}, accepted at {}", filing.form, filing.accepted_at()?);
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    #[cfg(feature = "chrono")]
+    pub fn get_recent_filings_between(
+        &self,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<FilingEntry>> {
+        let mut filings = Vec::new();
+
+        for filing in self.get_recent_filings() {
+            let date = filing.filed_on()?;
+            if start <= date && date <= end {
+                filings.push(filing);
+            }
+        }
+
+        Ok(filings)
+    }
+
     /// Returns a comprehensive list of all filings including those from paginated files.
     ///
     /// This method fetches additional filing files if they exist and returns
@@ -217,11 +330,11 @@ impl SubmissionHistory {
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let submissions = edgar_api.get_submissions_history("0001067983").await?;
+    /// let submissions = edgar_api.get_submissions_history(Cik::new(1067983)).await?;
     /// let all_filings = submissions.data.get_all_filings(&edgar_api).await?;
     /// println!("Total filings: {}", all_filings.len());
     /// # Ok(())
@@ -248,10 +361,10 @@ impl SubmissionHistory {
     fn convert_recent_to_filing_entries(recent: &Recent) -> Vec<FilingEntry> {
         let mut entries = Vec::new();
 
-        for i in 0..recent.accession_number.len() {
+        for (i, accession_number) in recent.accession_number.iter().enumerate() {
             if i < recent.form.len() && i < recent.filing_date.len() {
                 entries.push(FilingEntry {
-                    accession_number: recent.accession_number.get(i).cloned().unwrap_or_default(),
+                    accession_number: *accession_number,
                     filing_date: recent.filing_date.get(i).cloned().unwrap_or_default(),
                     report_date: recent.report_date.get(i).cloned().unwrap_or_default(),
                     acceptance_date_time: recent
@@ -286,11 +399,11 @@ impl SubmissionHistory {
     /// # Example
     ///
     /// ```rust,no_run
-    /// # use edgar_rs::{EdgarApi, EdgarClient, Config};
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
     /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
     /// let config = Config::new("Your Company Name your.email@example.com");
     /// let edgar_api = EdgarClient::new(config)?;
-    /// let submissions = edgar_api.get_submissions_history("0000320193").await?;
+    /// let submissions = edgar_api.get_submissions_history(Cik::new(320193)).await?;
     /// let ticker_map = submissions.data.get_ticker_map();
     /// for (ticker, exchange) in ticker_map {
     ///     println!("Ticker: {} on Exchange: {}", ticker, exchange);
@@ -311,6 +424,27 @@ impl SubmissionHistory {
 
         ticker_map
     }
+
+    /// Returns the company's fiscal calendar, or `None` if the fiscal year end
+    /// is missing or malformed.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// # use edgar_rs::{Cik, EdgarApi, EdgarClient, Config};
+    /// # async fn example() -> Result<(), Box<dyn std::error::Error>> {
+    /// let config = Config::new("Your Company Name your.email@example.com");
+    /// let edgar_api = EdgarClient::new(config)?;
+    /// let submissions = edgar_api.get_submissions_history(Cik::new(320193)).await?;
+    /// if let Some(calendar) = submissions.data.fiscal_calendar() {
+    ///     println!("Fiscal 2024 ended on {}", calendar.year_end(2024));
+    /// }
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn fiscal_calendar(&self) -> Option<FiscalCalendar> {
+        FiscalCalendar::parse(self.fiscal_year_end.as_deref()?).ok()
+    }
 }
 
 /// A filing entry in a company's submission history. NOTE: The data in the Recent struct is copied
@@ -318,7 +452,7 @@ impl SubmissionHistory {
 #[derive(Debug, Clone)]
 pub struct FilingEntry {
     /// The accession number of the filing.
-    pub accession_number: String,
+    pub accession_number: AccessionNumber,
 
     /// The filing date of the filing.
     pub filing_date: String,
@@ -362,3 +496,41 @@ pub struct FilingEntry {
     /// Instance document URL.
     pub instance_url: Option<String>,
 }
+
+impl FilingEntry {
+    /// Returns the form type of the filing.
+    pub fn form_type(&self) -> FormType {
+        FormType::from(self.form.as_str())
+    }
+}
+
+/// Typed dates for FilingEntry
+#[cfg(feature = "chrono")]
+impl FilingEntry {
+    /// Returns the filing date.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate) if the date is malformed.
+    pub fn filed_on(&self) -> Result<NaiveDate> {
+        parse_date(&self.filing_date)
+    }
+
+    /// Returns the report date, or `None` for filings without a reporting period.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate) if the date is malformed.
+    pub fn reported_on(&self) -> Result<Option<NaiveDate>> {
+        parse_optional_date(Some(&self.report_date))
+    }
+
+    /// Returns the acceptance timestamp as published by EDGAR.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::InvalidDate`](crate::EdgarApiError::InvalidDate) if the timestamp is malformed.
+    pub fn accepted_at(&self) -> Result<DateTime<FixedOffset>> {
+        parse_datetime(&self.acceptance_date_time)
+    }
+}
@@ -0,0 +1,201 @@
+//! Client-side rate limiting.
+//!
+//! SEC's fair access policy allows at most 10 requests per second from a single
+//! IP address, and clients that exceed it are blocked for 10 minutes. This module
+//! provides a token bucket that throttles requests before they are sent.
+
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use log::trace;
+
+use crate::runtime;
+
+/// Maximum request rate allowed by SEC's fair access policy.
+pub const SEC_MAX_REQUESTS_PER_SECOND: u32 = 10;
+
+/// Rate limit settings for outgoing requests.
+///
+/// # Example
+/// ```
+/// use edgar_rs::{Config, RateLimit};
+///
+/// // Stay well below SEC's limit when sharing an IP with other jobs
+/// let config = Config::new("Your Company contact@example.com")
+///     .with_rate_limit(RateLimit::new(5).with_burst(1));
+/// assert_eq!(config.rate_limit, Some(RateLimit::new(5).with_burst(1)));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Sustained number of requests per second.
+    pub requests_per_second: u32,
+    /// Number of requests that may be sent back-to-back before throttling kicks in.
+    pub burst: u32,
+}
+
+impl RateLimit {
+    /// Creates a rate limit of `requests_per_second`, allowing a burst of the same size.
+    ///
+    /// Values below 1 are treated as 1.
+    pub fn new(requests_per_second: u32) -> Self {
+        let requests_per_second = requests_per_second.max(1);
+        Self {
+            requests_per_second,
+            burst: requests_per_second,
+        }
+    }
+
+    /// Sets the maximum burst size. Values below 1 are treated as 1.
+    pub fn with_burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+}
+
+impl Default for RateLimit {
+    /// Returns SEC's fair access limit of 10 requests per second.
+    fn default() -> Self {
+        Self::new(SEC_MAX_REQUESTS_PER_SECOND)
+    }
+}
+
+/// A shared token bucket.
+///
+/// Clones share the same bucket, so every clone of a client draws from a single budget.
+#[derive(Debug, Clone, Default)]
+pub(crate) struct RateLimiter {
+    bucket: Option<Arc<Mutex<TokenBucket>>>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter for the given settings; `None` disables rate limiting.
+    pub(crate) fn new(rate_limit: Option<RateLimit>) -> Self {
+        Self {
+            bucket: rate_limit.map(|limit| Arc::new(Mutex::new(TokenBucket::new(limit)))),
+        }
+    }
+
+    /// Waits until a request may be sent.
+    pub(crate) async fn acquire(&self) {
+        let wait = self.reserve();
+        if !wait.is_zero() {
+            trace!("Rate limiter delaying request by {:?}", wait);
+            runtime::sleep(wait).await;
+        }
+    }
+
+    /// Reserves a slot for one request and returns how long to wait before sending it.
+    fn reserve(&self) -> Duration {
+        match &self.bucket {
+            Some(bucket) => bucket
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                .reserve(runtime::now()),
+            None => Duration::ZERO,
+        }
+    }
+}
+
+/// Token bucket state.
+///
+/// Tokens may go negative: each reservation takes a token immediately and callers
+/// that overdraw the bucket wait until their token has been refilled. This keeps
+/// concurrent callers in FIFO order without a separate queue.
+#[derive(Debug)]
+struct TokenBucket {
+    capacity: f64,
+    tokens: f64,
+    refill_per_second: f64,
+    last_refill: Option<Duration>,
+}
+
+impl TokenBucket {
+    fn new(limit: RateLimit) -> Self {
+        Self {
+            capacity: f64::from(limit.burst),
+            tokens: f64::from(limit.burst),
+            refill_per_second: f64::from(limit.requests_per_second),
+            last_refill: None,
+        }
+    }
+
+    fn reserve(&mut self, now: Duration) -> Duration {
+        if let Some(last) = self.last_refill {
+            let elapsed = now.saturating_sub(last).as_secs_f64();
+            self.tokens = (self.tokens + elapsed * self.refill_per_second).min(self.capacity);
+        }
+        self.last_refill = Some(now);
+
+        self.tokens -= 1.0;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.refill_per_second)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limit_defaults_to_sec_policy() {
+        let limit = RateLimit::default();
+        assert_eq!(limit.requests_per_second, 10);
+        assert_eq!(limit.burst, 10);
+    }
+
+    #[test]
+    fn test_rate_limit_clamps_zero() {
+        let limit = RateLimit::new(0).with_burst(0);
+        assert_eq!(limit.requests_per_second, 1);
+        assert_eq!(limit.burst, 1);
+    }
+
+    #[test]
+    fn test_token_bucket_allows_burst_then_throttles() {
+        let mut bucket = TokenBucket::new(RateLimit::new(10).with_burst(2));
+        let start = Duration::from_secs(100);
+
+        assert_eq!(bucket.reserve(start), Duration::ZERO);
+        assert_eq!(bucket.reserve(start), Duration::ZERO);
+
+        // Third and fourth requests queue behind each other at 100ms intervals
+        let third = bucket.reserve(start);
+        let fourth = bucket.reserve(start);
+        assert!((third.as_secs_f64() - 0.1).abs() < 1e-9);
+        assert!((fourth.as_secs_f64() - 0.2).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_token_bucket_refills_over_time() {
+        let mut bucket = TokenBucket::new(RateLimit::new(10).with_burst(1));
+        let start = Duration::from_secs(100);
+
+        assert_eq!(bucket.reserve(start), Duration::ZERO);
+        assert!(bucket.reserve(start) > Duration::ZERO);
+
+        // After a full second the bucket is back at capacity, but never above it
+        let later = start + Duration::from_secs(1);
+        assert_eq!(bucket.reserve(later), Duration::ZERO);
+        assert!(bucket.reserve(later) > Duration::ZERO);
+    }
+
+    #[test]
+    fn test_rate_limiter_clones_share_bucket() {
+        let limiter = RateLimiter::new(Some(RateLimit::new(1)));
+        let clone = limiter.clone();
+
+        assert_eq!(limiter.reserve(), Duration::ZERO);
+        assert!(clone.reserve() > Duration::ZERO);
+    }
+
+    #[test]
+    fn test_disabled_rate_limiter_never_waits() {
+        let limiter = RateLimiter::new(None);
+        for _ in 0..100 {
+            assert_eq!(limiter.reserve(), Duration::ZERO);
+        }
+    }
+}
@@ -0,0 +1,341 @@
+//! Resolution of tickers and company names to CIKs.
+//!
+//! [`CikResolver`] indexes the SEC's exchange and mutual fund ticker lists so
+//! that "AAPL", "BRK.B", "VFIAX" or "Berkshire Hathaway" can be used where a
+//! CIK is expected.
+
+use std::collections::{HashMap, HashSet};
+
+use crate::api::EdgarApi;
+use crate::error::{EdgarApiError, Result};
+use crate::identifiers::Cik;
+use crate::models::company_tickers::{CompanyTickerEntry, CompanyTickers};
+use crate::models::company_tickers_mf::CompanyTickersMf;
+
+/// Words dropped from the end of company names before matching.
+const NAME_SUFFIXES: &[&str] = &[
+    "inc",
+    "incorporated",
+    "corp",
+    "corporation",
+    "co",
+    "company",
+    "ltd",
+    "limited",
+    "plc",
+    "llc",
+    "lp",
+    "sa",
+    "nv",
+    "ag",
+    "de",
+];
+
+/// Maximum number of candidates listed in an ambiguous name error.
+const MAX_LISTED_CANDIDATES: usize = 5;
+
+/// Maps tickers, fund symbols and company names to CIKs.
+///
+/// Identifiers are tried in this order:
+///
+/// 1. A numeric CIK, with or without leading zeros or a "CIK" prefix.
+/// 2. An exchange ticker or mutual fund symbol, case-insensitively. Share class
+///    separators are normalized, so "BRK.B", "BRK/B" and "BRK-B" are equivalent.
+/// 3. A company name. Case, punctuation and legal suffixes such as "Inc." are
+///    ignored; a name may also be a prefix of, or a set of words from, the full
+///    name as long as a single company matches best.
+///
+/// [`EdgarClient`](crate::EdgarClient) builds and caches a resolver on first use; see
+/// [`EdgarClient::resolve_cik`](crate::EdgarClient::resolve_cik).
+///
+/// # Example
+///
+/// ```rust,no_run
+/// use edgar_rs::{CikResolver, Config, EdgarClient};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let client = EdgarClient::new(Config::new("Your Company Name your.email@example.com"))?;
+///     let resolver = CikResolver::fetch(&client).await?;
+///
+///     assert_eq!(resolver.resolve("BRK.B")?, 1067983);
+///     assert_eq!(resolver.resolve("Apple")?, 320193);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct CikResolver {
+    companies: Vec<CompanyTickerEntry>,
+    names: Vec<String>,
+    tickers: HashMap<String, Cik>,
+}
+
+impl CikResolver {
+    /// Builds a resolver from the exchange and mutual fund ticker lists.
+    ///
+    /// # Errors
+    ///
+    /// Returns a parse error if either list contains malformed rows.
+    pub fn new(tickers: &CompanyTickers, funds: &CompanyTickersMf) -> Result<Self> {
+        let mut resolver = Self::default();
+
+        for entry in tickers.entries().map_err(EdgarApiError::parse)? {
+            resolver
+                .tickers
+                .entry(normalize_ticker(&entry.ticker))
+                .or_insert(entry.cik);
+            resolver.names.push(normalize_name(&entry.name));
+            resolver.companies.push(entry);
+        }
+
+        for entry in funds.entries().map_err(EdgarApiError::parse)? {
+            resolver
+                .tickers
+                .entry(normalize_ticker(&entry.symbol))
+                .or_insert(entry.cik);
+        }
+
+        Ok(resolver)
+    }
+
+    /// Fetches both ticker lists through `api` and builds a resolver.
+    pub async fn fetch<A>(api: &A) -> Result<Self>
+    where
+        A: EdgarApi + ?Sized,
+    {
+        let tickers = api.get_company_tickers().await?;
+        let funds = api.get_company_tickers_mf().await?;
+        Self::new(&tickers.data, &funds.data)
+    }
+
+    /// Number of distinct tickers and fund symbols known to the resolver.
+    pub fn len(&self) -> usize {
+        self.tickers.len()
+    }
+
+    /// Returns true if the resolver knows no tickers.
+    pub fn is_empty(&self) -> bool {
+        self.tickers.is_empty()
+    }
+
+    /// Resolves a CIK, ticker, fund symbol or company name to a CIK.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`EdgarApiError::UnresolvedIdentifier`] if nothing matches, or if a
+    /// company name matches several companies equally well.
+    pub fn resolve(&self, identifier: &str) -> Result<Cik> {
+        let identifier = identifier.trim();
+        if identifier.is_empty() {
+            return Err(EdgarApiError::unresolved_identifier("empty identifier"));
+        }
+
+        if let Ok(cik) = identifier.parse() {
+            return Ok(cik);
+        }
+
+        if let Some(&cik) = self.tickers.get(&normalize_ticker(identifier)) {
+            return Ok(cik);
+        }
+
+        let matches = self.ranked_matches(identifier);
+        let best_rank = match matches.first() {
+            Some(&(rank, _)) => rank,
+            None => return Err(EdgarApiError::unresolved_identifier(identifier)),
+        };
+
+        let best: Vec<&CompanyTickerEntry> = matches
+            .iter()
+            .take_while(|(rank, _)| *rank == best_rank)
+            .map(|&(_, index)| &self.companies[index])
+            .collect();
+
+        match best.as_slice() {
+            [entry] => Ok(entry.cik),
+            candidates => {
+                let listed: Vec<String> = candidates
+                    .iter()
+                    .take(MAX_LISTED_CANDIDATES)
+                    .map(|entry| format!("{} ({})", entry.name, entry.ticker))
+                    .collect();
+                Err(EdgarApiError::unresolved_identifier(format!(
+                    "{} is ambiguous, matching {}",
+                    identifier,
+                    listed.join(", ")
+                )))
+            }
+        }
+    }
+
+    /// Returns the listed companies whose name matches `name`, best matches first.
+    ///
+    /// Each company appears once, with its first listed ticker.
+    pub fn search(&self, name: &str) -> Vec<&CompanyTickerEntry> {
+        self.ranked_matches(name)
+            .into_iter()
+            .map(|(_, index)| &self.companies[index])
+            .collect()
+    }
+
+    /// Returns (rank, company index) pairs for the companies matching `name`,
+    /// one per CIK, sorted by rank and then by name length.
+    ///
+    /// Rank 0 is an exact match, 1 a prefix match and 2 a match of all words.
+    fn ranked_matches(&self, name: &str) -> Vec<(u8, usize)> {
+        let query = normalize_name(name);
+        if query.is_empty() {
+            return Vec::new();
+        }
+        let query_words: Vec<&str> = query.split(' ').collect();
+
+        let mut seen = HashSet::new();
+        let mut matches: Vec<(u8, usize)> = self
+            .names
+            .iter()
+            .enumerate()
+            .filter_map(|(index, candidate)| {
+                let rank = if *candidate == query {
+                    0
+                } else if candidate.starts_with(&query) && candidate[query.len()..].starts_with(' ')
+                {
+                    1
+                } else if query_words
+                    .iter()
+                    .all(|word| candidate.split(' ').any(|w| w == *word))
+                {
+                    2
+                } else {
+                    return None;
+                };
+                Some((rank, index))
+            })
+            .collect();
+
+        matches.sort_by_key(|&(rank, index)| (rank, self.names[index].len()));
+        matches.retain(|&(_, index)| seen.insert(self.companies[index].cik));
+        matches
+    }
+}
+
+/// Uppercases a ticker and uses '-' as the share class separator, as the SEC lists do.
+fn normalize_ticker(ticker: &str) -> String {
+    ticker
+        .trim()
+        .chars()
+        .map(|c| match c {
+            '.' | '/' | ' ' => '-',
+            c => c.to_ascii_uppercase(),
+        })
+        .collect()
+}
+
+/// Lowercases a company name, drops punctuation and trailing legal suffixes,
+/// and joins the remaining words with single spaces.
+fn normalize_name(name: &str) -> String {
+    let cleaned: String = name
+        .to_lowercase()
+        .replace('&', " and ")
+        .chars()
+        .map(|c| if c.is_alphanumeric() { c } else { ' ' })
+        .collect();
+
+    let mut words: Vec<&str> = cleaned.split_whitespace().collect();
+    if words.first() == Some(&"the") {
+        words.remove(0);
+    }
+    while words.len() > 1 && NAME_SUFFIXES.contains(words.last().unwrap()) {
+        words.pop();
+    }
+    words.join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn resolver() -> CikResolver {
+        let tickers: CompanyTickers = serde_json::from_value(json!({
+            "fields": ["cik", "name", "ticker", "exchange"],
+            "data": [
+                [320193, "Apple Inc.", "AAPL", "Nasdaq"],
+                [1067983, "BERKSHIRE HATHAWAY INC", "BRK-B", "NYSE"],
+                [1067983, "BERKSHIRE HATHAWAY INC", "BRK-A", "NYSE"],
+                [1418121, "Apple Hospitality REIT, Inc.", "APLE", "NYSE"],
+                [1652044, "Alphabet Inc.", "GOOGL", "Nasdaq"],
+                [34088, "EXXON MOBIL CORP", "XOM", "NYSE"],
+                [1800, "ABBOTT LABORATORIES", "ABT", "NYSE"],
+                [1551152, "AbbVie Inc.", "ABBV", "NYSE"]
+            ]
+        }))
+        .unwrap();
+        let funds: CompanyTickersMf = serde_json::from_value(json!({
+            "fields": ["cik", "seriesId", "classId", "symbol"],
+            "data": [[36405, "S000002839", "C000007773", "VFIAX"]]
+        }))
+        .unwrap();
+        CikResolver::new(&tickers, &funds).unwrap()
+    }
+
+    #[test]
+    fn test_resolve_ciks_and_tickers() {
+        let resolver = resolver();
+        assert_eq!(resolver.len(), 9);
+
+        assert_eq!(resolver.resolve("320193").unwrap(), 320193);
+        assert_eq!(resolver.resolve("0000320193").unwrap(), 320193);
+        assert_eq!(resolver.resolve("CIK0000320193").unwrap(), 320193);
+
+        assert_eq!(resolver.resolve("aapl").unwrap(), 320193);
+        assert_eq!(resolver.resolve("BRK.B").unwrap(), 1067983);
+        assert_eq!(resolver.resolve("brk/a").unwrap(), 1067983);
+        assert_eq!(resolver.resolve(" VFIAX ").unwrap(), 36405);
+    }
+
+    #[test]
+    fn test_resolve_company_names() {
+        let resolver = resolver();
+        assert_eq!(resolver.resolve("Apple").unwrap(), 320193);
+        assert_eq!(resolver.resolve("apple inc").unwrap(), 320193);
+        assert_eq!(resolver.resolve("Berkshire Hathaway").unwrap(), 1067983);
+        assert_eq!(resolver.resolve("Exxon Mobil Corporation").unwrap(), 34088);
+        assert_eq!(resolver.resolve("Apple Hospitality").unwrap(), 1418121);
+        assert_eq!(resolver.resolve("Hathaway").unwrap(), 1067983);
+
+        let names: Vec<&str> = resolver
+            .search("apple")
+            .iter()
+            .map(|entry| entry.ticker.as_str())
+            .collect();
+        assert_eq!(names, vec!["AAPL", "APLE"]);
+    }
+
+    #[test]
+    fn test_resolve_rejects_unknown_and_ambiguous_identifiers() {
+        let resolver = resolver();
+        assert!(matches!(
+            resolver.resolve("NOPE"),
+            Err(EdgarApiError::UnresolvedIdentifier(_))
+        ));
+        assert!(matches!(
+            resolver.resolve("  "),
+            Err(EdgarApiError::UnresolvedIdentifier(_))
+        ));
+
+        let companies: CompanyTickers = serde_json::from_value(json!({
+            "fields": ["cik", "name", "ticker", "exchange"],
+            "data": [
+                [1, "Acme Holdings Inc", "ACMH", "Nasdaq"],
+                [2, "ACME INDUSTRIES CORP", "ACMI", "NYSE"]
+            ]
+        }))
+        .unwrap();
+        let funds = CompanyTickersMf {
+            fields: Vec::new(),
+            data: Vec::new(),
+        };
+        let resolver = CikResolver::new(&companies, &funds).unwrap();
+        let error = resolver.resolve("Acme").unwrap_err();
+        assert!(error.to_string().contains("Acme Holdings Inc (ACMH)"));
+    }
+}
@@ -0,0 +1,207 @@
+//! Automatic retries for transient failures.
+//!
+//! Requests that fail with a transient error (see [`EdgarApiError::is_transient`])
+//! are retried with exponential backoff. When SEC answers with `429 Too Many Requests`
+//! and a `Retry-After` header, that delay is honored instead of the computed backoff,
+//! up to the policy's `max_delay`.
+
+use std::time::Duration;
+
+use crate::error::EdgarApiError;
+use crate::runtime;
+
+/// Retry settings for outgoing requests.
+///
+/// # Example
+/// ```
+/// use std::time::Duration;
+/// use edgar_rs::{Config, RetryPolicy};
+///
+/// let config = Config::new("Your Company contact@example.com").with_retry_policy(
+///     RetryPolicy::new(5)
+///         .with_base_delay(Duration::from_secs(1))
+///         .with_jitter(false),
+/// );
+/// assert_eq!(config.retry_policy.max_attempts, 5);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Total number of attempts, including the first one. `1` disables retries.
+    pub max_attempts: u32,
+    /// Delay before the first retry; doubled for every further retry.
+    pub base_delay: Duration,
+    /// Upper bound for every delay, including one requested by `Retry-After`.
+    pub max_delay: Duration,
+    /// Randomize delays to avoid synchronized retries from parallel tasks.
+    pub jitter: bool,
+    /// Wait for the `Retry-After` duration sent with `429` responses when present.
+    pub respect_retry_after: bool,
+}
+
+impl RetryPolicy {
+    /// Creates a policy making at most `max_attempts` attempts (values below 1 are treated as 1).
+    pub fn new(max_attempts: u32) -> Self {
+        Self {
+            max_attempts: max_attempts.max(1),
+            ..Self::default()
+        }
+    }
+
+    /// Creates a policy that never retries.
+    pub fn none() -> Self {
+        Self::new(1)
+    }
+
+    /// Sets the delay before the first retry.
+    pub fn with_base_delay(mut self, base_delay: Duration) -> Self {
+        self.base_delay = base_delay;
+        self
+    }
+
+    /// Sets the upper bound for every delay, including one requested by `Retry-After`.
+    pub fn with_max_delay(mut self, max_delay: Duration) -> Self {
+        self.max_delay = max_delay;
+        self
+    }
+
+    /// Enables or disables jitter.
+    pub fn with_jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// Enables or disables honoring `Retry-After`.
+    pub fn with_respect_retry_after(mut self, respect_retry_after: bool) -> Self {
+        self.respect_retry_after = respect_retry_after;
+        self
+    }
+
+    /// Returns true if a request that failed with `error` on attempt number
+    /// `attempt` (starting at 1) should be retried.
+    pub fn should_retry(&self, attempt: u32, error: &EdgarApiError) -> bool {
+        attempt < self.max_attempts && error.is_transient()
+    }
+
+    /// Returns the delay to wait after attempt number `attempt` (starting at 1) failed with `error`.
+    pub fn delay_for(&self, attempt: u32, error: &EdgarApiError) -> Duration {
+        if self.respect_retry_after {
+            if let EdgarApiError::RateLimitExceeded {
+                retry_after: Some(seconds),
+            } = error
+            {
+                return Duration::from_secs(*seconds).min(self.max_delay);
+            }
+        }
+
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .base_delay
+            .saturating_mul(1u32 << exponent)
+            .min(self.max_delay);
+
+        if self.jitter {
+            // "Equal jitter": keep half of the delay and randomize the other half
+            backoff / 2 + backoff.mul_f64(random_fraction() / 2.0)
+        } else {
+            backoff
+        }
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Three attempts starting at 500ms, capped at 30 seconds, with jitter.
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            jitter: true,
+            respect_retry_after: true,
+        }
+    }
+}
+
+/// Returns a pseudo-random number in `[0, 1)`.
+///
+/// Jitter only needs to spread retries apart, so the clock's sub-second part is
+/// mixed with xorshift instead of pulling in a random number generator.
+fn random_fraction() -> f64 {
+    let mut x = u64::from(runtime::now().subsec_nanos()) | 1;
+    x ^= x << 13;
+    x ^= x >> 7;
+    x ^= x << 17;
+    (x % 1_000_000) as f64 / 1_000_000.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_should_retry_transient_errors_only() {
+        let policy = RetryPolicy::new(3);
+
+        assert!(policy.should_retry(1, &EdgarApiError::network("connection reset")));
+        assert!(policy.should_retry(2, &EdgarApiError::api(503, "unavailable")));
+        assert!(!policy.should_retry(3, &EdgarApiError::api(503, "unavailable")));
+        assert!(!policy.should_retry(1, &EdgarApiError::api(404, "not found")));
+        assert!(!policy.should_retry(1, &EdgarApiError::parse("bad json")));
+    }
+
+    #[test]
+    fn test_none_never_retries() {
+        let policy = RetryPolicy::none();
+        assert!(!policy.should_retry(1, &EdgarApiError::network("timeout")));
+    }
+
+    #[test]
+    fn test_exponential_backoff_without_jitter() {
+        let policy = RetryPolicy::new(10)
+            .with_base_delay(Duration::from_millis(100))
+            .with_max_delay(Duration::from_millis(500))
+            .with_jitter(false);
+        let error = EdgarApiError::network("timeout");
+
+        assert_eq!(policy.delay_for(1, &error), Duration::from_millis(100));
+        assert_eq!(policy.delay_for(2, &error), Duration::from_millis(200));
+        assert_eq!(policy.delay_for(3, &error), Duration::from_millis(400));
+        assert_eq!(policy.delay_for(4, &error), Duration::from_millis(500));
+        assert_eq!(policy.delay_for(40, &error), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let policy = RetryPolicy::new(3).with_base_delay(Duration::from_millis(100));
+        let error = EdgarApiError::network("timeout");
+
+        for _ in 0..100 {
+            let delay = policy.delay_for(1, &error);
+            assert!(delay >= Duration::from_millis(50));
+            assert!(delay <= Duration::from_millis(100));
+        }
+    }
+
+    #[test]
+    fn test_retry_after_is_honored() {
+        let error = EdgarApiError::rate_limit(Some(7));
+
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.delay_for(1, &error), Duration::from_secs(7));
+
+        let policy = RetryPolicy::default()
+            .with_respect_retry_after(false)
+            .with_jitter(false);
+        assert_eq!(policy.delay_for(1, &error), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_retry_after_is_capped_at_max_delay() {
+        let error = EdgarApiError::rate_limit(Some(3600));
+
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.delay_for(1, &error), Duration::from_secs(30));
+
+        let policy = RetryPolicy::default().with_max_delay(Duration::from_secs(5));
+        assert_eq!(policy.delay_for(1, &error), Duration::from_secs(5));
+    }
+}
@@ -0,0 +1,100 @@
+//! Runtime helpers for timers.
+//!
+//! Native builds run on tokio while Cloudflare Workers and browsers run inside a
+//! JavaScript engine, so clocks and sleeping are abstracted here for the rest of
+//! the library.
+
+use std::time::Duration;
+
+/// Returns a monotonic timestamp measured from an arbitrary, process-wide origin.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn now() -> Duration {
+    use std::sync::OnceLock;
+    use std::time::Instant;
+
+    static ORIGIN: OnceLock<Instant> = OnceLock::new();
+    ORIGIN.get_or_init(Instant::now).elapsed()
+}
+
+/// Returns a timestamp measured from the Unix epoch.
+///
+/// `std::time::Instant` is not available on `wasm32-unknown-unknown`, so the
+/// JavaScript clock is used instead.
+#[cfg(all(target_arch = "wasm32", feature = "cloudflare-workers"))]
+pub(crate) fn now() -> Duration {
+    Duration::from_secs_f64(worker::js_sys::Date::now() / 1000.0)
+}
+
+/// Returns a timestamp measured from the Unix epoch, using the JavaScript clock.
+#[cfg(all(
+    target_arch = "wasm32",
+    feature = "wasm-browser",
+    not(feature = "cloudflare-workers")
+))]
+pub(crate) fn now() -> Duration {
+    Duration::from_secs_f64(js_sys::Date::now() / 1000.0)
+}
+
+/// Returns the wall-clock time as a duration since the Unix epoch.
+///
+/// Unlike [`now`], this is comparable across processes, which persisted data needs.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) fn unix_time() -> Duration {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or(Duration::ZERO)
+}
+
+/// Returns the wall-clock time as a duration since the Unix epoch.
+#[cfg(all(
+    target_arch = "wasm32",
+    any(feature = "cloudflare-workers", feature = "wasm-browser")
+))]
+pub(crate) fn unix_time() -> Duration {
+    now()
+}
+
+/// Suspends the current task for the given duration.
+#[cfg(feature = "native")]
+pub(crate) async fn sleep(duration: Duration) {
+    tokio::time::sleep(duration).await;
+}
+
+/// Suspends the current task for the given duration.
+#[cfg(all(feature = "cloudflare-workers", not(feature = "native")))]
+pub(crate) async fn sleep(duration: Duration) {
+    worker::Delay::from(duration).await;
+}
+
+#[cfg(all(
+    feature = "wasm-browser",
+    not(feature = "native"),
+    not(feature = "cloudflare-workers")
+))]
+#[wasm_bindgen::prelude::wasm_bindgen]
+extern "C" {
+    #[wasm_bindgen(js_name = setTimeout, catch)]
+    fn set_timeout(
+        handler: &js_sys::Function,
+        millis: i32,
+    ) -> Result<wasm_bindgen::JsValue, wasm_bindgen::JsValue>;
+}
+
+/// Suspends the current task for the given duration, using `setTimeout`.
+#[cfg(all(
+    feature = "wasm-browser",
+    not(feature = "native"),
+    not(feature = "cloudflare-workers")
+))]
+pub(crate) async fn sleep(duration: Duration) {
+    let millis = i32::try_from(duration.as_millis()).unwrap_or(i32::MAX);
+    let promise = js_sys::Promise::new(&mut |resolve, _reject| {
+        if set_timeout(&resolve, millis).is_err() {
+            // Don't hang if the timer can't be scheduled
+            let _ = resolve.call0(&wasm_bindgen::JsValue::NULL);
+        }
+    });
+    let _ = wasm_bindgen_futures::JsFuture::from(promise).await;
+}
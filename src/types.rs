@@ -40,6 +40,7 @@ impl Taxonomy {
     }
 
     /// Attempts to parse a string into a Taxonomy
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
         match s.to_lowercase().as_str() {
             "us-gaap" => Some(Taxonomy::UsGaap),
@@ -79,29 +80,28 @@ impl Period {
     }
 
     /// Attempts to parse a string into a Period
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Option<Self> {
-        if s.starts_with("CY") {
-            let s = &s[2..]; // Remove "CY"
-
-            if let Some(i) = s.find('Q') {
-                let year = s[..i].parse::<u16>().ok()?;
-                let remainder = &s[i + 1..];
-
-                if remainder.ends_with('I') {
-                    let quarter = remainder[..remainder.len() - 1].parse::<u8>().ok()?;
-                    if quarter >= 1 && quarter <= 4 {
-                        return Some(Period::Instantaneous(year, quarter));
-                    }
-                } else {
-                    let quarter = remainder.parse::<u8>().ok()?;
-                    if quarter >= 1 && quarter <= 4 {
-                        return Some(Period::Quarterly(year, quarter));
-                    }
+        let s = s.strip_prefix("CY")?;
+
+        if let Some(i) = s.find('Q') {
+            let year = s[..i].parse::<u16>().ok()?;
+            let remainder = &s[i + 1..];
+
+            if let Some(quarter) = remainder.strip_suffix('I') {
+                let quarter = quarter.parse::<u8>().ok()?;
+                if (1..=4).contains(&quarter) {
+                    return Some(Period::Instantaneous(year, quarter));
                 }
             } else {
-                let year = s.parse::<u16>().ok()?;
-                return Some(Period::Annual(year));
+                let quarter = remainder.parse::<u8>().ok()?;
+                if (1..=4).contains(&quarter) {
+                    return Some(Period::Quarterly(year, quarter));
+                }
             }
+        } else {
+            let year = s.parse::<u16>().ok()?;
+            return Some(Period::Annual(year));
         }
 
         None
@@ -133,6 +133,7 @@ impl Unit {
     }
 
     /// Attempts to parse a string into a Unit
+    #[allow(clippy::should_implement_trait)]
     pub fn from_str(s: &str) -> Self {
         if let Some(idx) = s.find("-per-") {
             let numerator = s[..idx].to_string();
@@ -143,3 +144,26 @@ impl Unit {
         }
     }
 }
+
+/// Bulk archives published nightly by SEC
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BulkDataset {
+    /// Submission histories for all filers (`submissions.zip`)
+    Submissions,
+    /// XBRL company facts for all filers (`companyfacts.zip`)
+    CompanyFacts,
+}
+
+impl BulkDataset {
+    /// Returns the URL of the archive
+    pub fn url(&self) -> &'static str {
+        match self {
+            BulkDataset::Submissions => {
+                "https://www.sec.gov/Archives/edgar/daily-index/bulkdata/submissions.zip"
+            }
+            BulkDataset::CompanyFacts => {
+                "https://www.sec.gov/Archives/edgar/daily-index/xbrl/companyfacts.zip"
+            }
+        }
+    }
+}
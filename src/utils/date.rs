@@ -0,0 +1,192 @@
+//! Utilities for working with dates.
+//!
+//! EDGAR writes dates as "YYYY-MM-DD" strings throughout its APIs. With the
+//! `chrono` feature, [`parse_date`] and [`parse_datetime`] turn them into
+//! `chrono` types.
+
+#[cfg(feature = "chrono")]
+use chrono::{DateTime, FixedOffset, NaiveDate};
+
+#[cfg(feature = "chrono")]
+use crate::error::{EdgarApiError, Result};
+
+/// Splits a "YYYY-MM-DD" date into year, month and day.
+///
+/// Returns `None` unless the string has exactly that shape and is a calendar
+/// date, so "2023-02-29" and "2024-04-31" are rejected.
+pub fn parse_ymd(date: &str) -> Option<(i32, u32, u32)> {
+    let bytes = date.as_bytes();
+    let shaped = bytes.len() == 10
+        && bytes.iter().enumerate().all(|(i, b)| match i {
+            4 | 7 => *b == b'-',
+            _ => b.is_ascii_digit(),
+        });
+    if !shaped {
+        return None;
+    }
+
+    let year = date[..4].parse().ok()?;
+    let month = date[5..7].parse().ok()?;
+    let day = date[8..].parse().ok()?;
+    ((1..=12).contains(&month) && day >= 1 && day <= days_in_month(year, month))
+        .then_some((year, month, day))
+}
+
+/// Returns the number of days in `month` (1-12) of `year`.
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 31,
+    }
+}
+
+/// Returns the number of days from 1970-01-01 to the given date in the
+/// proleptic Gregorian calendar.
+pub fn days_from_civil(year: i32, month: u32, day: u32) -> i64 {
+    // Shift the year to start in March so the leap day comes last
+    let year = i64::from(year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month = i64::from(month);
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Returns the date `days` days after 1970-01-01 as (year, month, day); the
+/// inverse of [`days_from_civil`].
+pub fn civil_from_days(days: i64) -> (i32, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * month + 2) / 5 + 1;
+    let month = if month < 10 { month + 3 } else { month - 9 };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year as i32, month as u32, day as u32)
+}
+
+/// Returns the "YYYY-MM-DD" date `days` days after `date`, or `None` if `date`
+/// is malformed.
+pub fn add_days(date: &str, days: i64) -> Option<String> {
+    let (year, month, day) = parse_ymd(date)?;
+    let (year, month, day) = civil_from_days(days_from_civil(year, month, day) + days);
+    Some(format!("{:04}-{:02}-{:02}", year, month, day))
+}
+
+/// Returns the number of days from `start` to `end`, both "YYYY-MM-DD", or
+/// `None` if either is malformed.
+pub fn days_between(start: &str, end: &str) -> Option<i64> {
+    let (start_year, start_month, start_day) = parse_ymd(start)?;
+    let (end_year, end_month, end_day) = parse_ymd(end)?;
+    Some(
+        days_from_civil(end_year, end_month, end_day)
+            - days_from_civil(start_year, start_month, start_day),
+    )
+}
+
+/// Parses a "YYYY-MM-DD" date.
+///
+/// # Errors
+///
+/// Returns [`EdgarApiError::InvalidDate`] if the string is not a valid calendar date.
+#[cfg(feature = "chrono")]
+pub fn parse_date(date: &str) -> Result<NaiveDate> {
+    parse_ymd(date)
+        .and_then(|(year, month, day)| NaiveDate::from_ymd_opt(year, month, day))
+        .ok_or_else(|| EdgarApiError::invalid_date(format!("{:?}, expected YYYY-MM-DD", date)))
+}
+
+/// Parses an optional date, treating a missing or empty string as `None`.
+#[cfg(feature = "chrono")]
+pub(crate) fn parse_optional_date(date: Option<&str>) -> Result<Option<NaiveDate>> {
+    match date {
+        Some(date) if !date.is_empty() => parse_date(date).map(Some),
+        _ => Ok(None),
+    }
+}
+
+/// Parses an RFC 3339 timestamp such as "2023-11-02T18:01:14.000Z", keeping its offset.
+///
+/// # Errors
+///
+/// Returns [`EdgarApiError::InvalidDate`] if the string is not a valid timestamp.
+#[cfg(feature = "chrono")]
+pub fn parse_datetime(datetime: &str) -> Result<DateTime<FixedOffset>> {
+    DateTime::parse_from_rfc3339(datetime).map_err(|e| {
+        EdgarApiError::invalid_date(format!(
+            "{:?}, expected an RFC 3339 timestamp: {}",
+            datetime, e
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ymd() {
+        assert_eq!(parse_ymd("2024-01-02"), Some((2024, 1, 2)));
+        assert_eq!(parse_ymd("2023-12-31"), Some((2023, 12, 31)));
+        assert_eq!(parse_ymd("2024-13-01"), None);
+        assert_eq!(parse_ymd("2024-02-29"), Some((2024, 2, 29)));
+        assert_eq!(parse_ymd("2023-02-29"), None);
+        assert_eq!(parse_ymd("2023-02-31"), None);
+        assert_eq!(parse_ymd("2023-04-31"), None);
+        assert_eq!(parse_ymd("2023-01-00"), None);
+        assert_eq!(parse_ymd("2024-1-1"), None);
+        assert_eq!(parse_ymd("20240102"), None);
+        assert_eq!(parse_ymd(""), None);
+    }
+
+    #[test]
+    fn test_days_between() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(days_between("2023-01-01", "2023-12-31"), Some(364));
+        assert_eq!(days_between("2024-01-01", "2024-12-31"), Some(365));
+        assert_eq!(days_between("2023-07-02", "2023-09-30"), Some(90));
+        assert_eq!(days_between("2023-12-31", "2023-01-01"), Some(-364));
+        assert_eq!(days_between("2023-01-01", "2023-13-01"), None);
+    }
+
+    #[test]
+    fn test_add_days() {
+        assert_eq!(civil_from_days(0), (1970, 1, 1));
+        assert_eq!(civil_from_days(11_017), (2000, 3, 1));
+        assert_eq!(add_days("2023-09-30", 1), Some("2023-10-01".to_string()));
+        assert_eq!(add_days("2023-12-31", 1), Some("2024-01-01".to_string()));
+        assert_eq!(add_days("2024-03-01", -1), Some("2024-02-29".to_string()));
+        assert_eq!(add_days("2023-02-30x", 1), None);
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn test_parse_date_and_datetime() {
+        assert_eq!(
+            parse_date("2024-02-29").unwrap(),
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+        );
+        assert!(matches!(
+            parse_date("2023-02-29"),
+            Err(EdgarApiError::InvalidDate(_))
+        ));
+        assert!(parse_date("2024/01/02").is_err());
+        assert_eq!(parse_optional_date(Some("")).unwrap(), None);
+
+        let accepted = parse_datetime("2023-11-02T18:01:14.000Z").unwrap();
+        assert_eq!(accepted.to_rfc3339(), "2023-11-02T18:01:14+00:00");
+        let updated = parse_datetime("2024-05-02T16:30:37-04:00").unwrap();
+        assert_eq!(updated.offset().local_minus_utc(), -4 * 3600);
+        assert!(matches!(
+            parse_datetime("2023-11-02 18:01:14"),
+            Err(EdgarApiError::InvalidDate(_))
+        ));
+    }
+}
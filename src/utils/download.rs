@@ -6,30 +6,22 @@
 #[cfg(feature = "native")]
 use std::fs::File;
 #[cfg(feature = "native")]
-use std::io::{self, Write};
+use std::io;
 #[cfg(feature = "native")]
 use std::path::{Path, PathBuf};
 
 use crate::error::{EdgarApiError, Result};
 
-/// Writes bytes to a temporary file.
-///
-/// # Parameters
-///
-/// * `bytes` - The bytes to write.
+/// Creates an empty temporary file that is not deleted automatically.
 ///
 /// # Returns
 ///
 /// The path to the temporary file.
 #[cfg(feature = "native")]
-pub fn write_temp_file(bytes: &[u8]) -> Result<PathBuf> {
-    let mut temp_file = tempfile::NamedTempFile::new()
+pub fn create_temp_file() -> Result<PathBuf> {
+    let temp_file = tempfile::NamedTempFile::new()
         .map_err(|e| EdgarApiError::request(format!("Failed to create temporary file: {}", e)))?;
 
-    // Write the bytes to the temporary file
-    temp_file.write_all(bytes)
-        .map_err(|e| EdgarApiError::request(format!("Failed to write file: {}", e)))?;
-
     // Persist the temporary file to prevent automatic deletion
     let path = temp_file.into_temp_path().keep()
         .map_err(|e| EdgarApiError::request(format!("Failed to persist temporary file: {}", e)))?;
@@ -93,19 +85,16 @@ pub fn extract_zip(zip_path: &Path, output_dir: &Path) -> Result<()> {
 #[cfg(all(test, feature = "native"))]
 mod tests {
     use super::*;
-    use std::io::Read;
 
     #[test]
-    fn test_write_temp_file() {
-        let data = b"Hello, world!";
-        let path = write_temp_file(data).unwrap();
+    fn test_create_temp_file() {
+        let path = create_temp_file().unwrap();
 
-        // Read the file back
-        let mut file = File::open(path).unwrap();
-        let mut contents = Vec::new();
-        file.read_to_end(&mut contents).unwrap();
+        let metadata = std::fs::metadata(&path).unwrap();
+        assert!(metadata.is_file());
+        assert_eq!(metadata.len(), 0);
 
-        assert_eq!(contents, data);
+        std::fs::remove_file(path).unwrap();
     }
 
 }
@@ -2,7 +2,9 @@
 //!
 //! This module contains utility functions and submodules used throughout the library.
 
-pub mod cik;
+pub mod date;
+pub mod text;
+pub mod url;
 
 #[cfg(feature = "native")]
 pub mod download;
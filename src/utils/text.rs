@@ -0,0 +1,25 @@
+//! Utilities for decoding text documents.
+
+/// Decodes `bytes` as UTF-8, or as ISO-8859-1 if they aren't valid UTF-8.
+///
+/// EDGAR serves its feeds and indexes as ISO-8859-1, but their content is mostly
+/// ASCII and some files are UTF-8. ISO-8859-1 is decoded one byte per character.
+pub(crate) fn decode_latin1_or_utf8(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => text.to_string(),
+        // Every Latin-1 byte maps to the Unicode code point of the same value
+        Err(_) => bytes.iter().map(|&b| char::from(b)).collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decode_latin1_or_utf8() {
+        assert_eq!(decode_latin1_or_utf8(b"Soci\xe9t\xe9"), "Société");
+        assert_eq!(decode_latin1_or_utf8("Société".as_bytes()), "Société");
+        assert_eq!(decode_latin1_or_utf8(b""), "");
+    }
+}
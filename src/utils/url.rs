@@ -0,0 +1,34 @@
+//! Utilities for building request URLs.
+
+/// Percent-encodes a query string component.
+///
+/// Every byte except the RFC 3986 unreserved characters (`A-Z a-z 0-9 - . _ ~`)
+/// is encoded, so the result is safe both as a parameter name and as a value.
+pub fn encode_query_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'.' | b'_' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_query_component() {
+        assert_eq!(encode_query_component("10-K"), "10-K");
+        assert_eq!(
+            encode_query_component("\"climate risk\""),
+            "%22climate%20risk%22"
+        );
+        assert_eq!(encode_query_component("a&b=c+d"), "a%26b%3Dc%2Bd");
+        assert_eq!(encode_query_component("Société"), "Soci%C3%A9t%C3%A9");
+    }
+}
@@ -0,0 +1,87 @@
+//! Headless browser tests for `BrowserClient`.
+//!
+//! The tests request the SEC endpoints through a local mock server standing in
+//! for a CORS proxy. Start it, then run the suite in a headless browser:
+//!
+//! ```text
+//! cargo run --example fixture_server &
+//! wasm-pack test --headless --firefox --no-default-features --features wasm-browser -- --test browser
+//! ```
+//!
+//! Set `EDGAR_MOCK_SERVER` at build time to use a server on another address.
+#![cfg(all(target_arch = "wasm32", feature = "wasm-browser"))]
+
+use edgar_rs::{
+    BrowserClient, Cik, Config, EdgarApi, EdgarApiError, EdgarClient, RateLimit, Taxonomy,
+};
+use wasm_bindgen_test::{wasm_bindgen_test, wasm_bindgen_test_configure};
+
+wasm_bindgen_test_configure!(run_in_browser);
+
+const MOCK_SERVER: &str = match option_env!("EDGAR_MOCK_SERVER") {
+    Some(url) => url,
+    None => "http://127.0.0.1:8787/",
+};
+
+const APPLE_CIK: Cik = Cik::new(320193);
+
+fn create_browser_client() -> EdgarClient<BrowserClient> {
+    let mut config = Config::new("edgar-rs-browser-tests test@example.com");
+    config.base_url = MOCK_SERVER.to_string();
+    EdgarClient::new_browser(config)
+}
+
+#[wasm_bindgen_test]
+async fn test_get_submissions_history() {
+    let client = create_browser_client();
+
+    let response = client.get_submissions_history(APPLE_CIK).await.unwrap();
+
+    assert_eq!(response.status, 200);
+    assert_eq!(response.data.cik, "0000320193");
+    assert_eq!(response.data.name, "Apple Inc.");
+}
+
+#[wasm_bindgen_test]
+async fn test_get_company_concept() {
+    let client = create_browser_client();
+    let tag = "RevenueFromContractWithCustomerExcludingAssessedTax";
+
+    let response = client
+        .get_company_concept(APPLE_CIK, Taxonomy::UsGaap, tag)
+        .await
+        .unwrap();
+
+    assert_eq!(response.data.entity_name, "Apple Inc.");
+    assert_eq!(response.data.tag, tag);
+}
+
+#[wasm_bindgen_test]
+async fn test_nonexistent_cik() {
+    let client = create_browser_client();
+
+    let result = client.get_submissions_history(Cik::new(1)).await;
+
+    assert!(matches!(
+        result,
+        Err(EdgarApiError::ApiError { status: 404, .. })
+    ));
+}
+
+#[wasm_bindgen_test]
+async fn test_requests_are_rate_limited() {
+    let mut config = Config::new("edgar-rs-browser-tests test@example.com")
+        .with_rate_limit(RateLimit::new(5).with_burst(1));
+    config.base_url = MOCK_SERVER.to_string();
+    let client = EdgarClient::new_browser(config);
+
+    // The first request spends the only token; each of the other five waits
+    // 200ms for a new one on the browser's timers
+    let start = js_sys::Date::now();
+    for _ in 0..6 {
+        client.get_company_tickers().await.unwrap();
+    }
+    let elapsed = js_sys::Date::now() - start;
+
+    assert!(elapsed >= 950.0, "6 requests at 5/s took {}ms", elapsed);
+}
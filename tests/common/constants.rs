@@ -1,11 +1,19 @@
 // Test constants for integration tests
 
+use edgar_rs::Cik;
+
 /// Apple Inc. CIK for consistent testing
-pub const APPLE_CIK: &str = "0000320193";
+pub const APPLE_CIK: Cik = Cik::new(320193);
 
 /// Test user agent for API requests
 pub const TEST_USER_AGENT: &str = "edgar-rs-integration-tests test@example.com";
 
+/// Directory of the SEC responses the tests replay, hand-trimmed excerpts of the live ones
+pub const FIXTURES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+/// Environment variable that switches the tests to recording missing fixtures
+pub const RECORD_FIXTURES_VAR: &str = "EDGAR_RECORD_FIXTURES";
+
 
 /// Common revenue concept for testing
 pub const REVENUE_CONCEPT: &str = "RevenueFromContractWithCustomerExcludingAssessedTax";
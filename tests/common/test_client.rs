@@ -1,10 +1,20 @@
-use edgar_rs::{EdgarClient, Config};
-use super::constants::TEST_USER_AGENT;
+use edgar_rs::{EdgarClient, Config, MockHttpClient, ReqwestClient};
+use super::constants::{FIXTURES_DIR, RECORD_FIXTURES_VAR, TEST_USER_AGENT};
 
 /// Creates an EdgarClient for testing
-pub fn create_test_client() -> Result<EdgarClient<edgar_rs::ReqwestClient>, Box<dyn std::error::Error>> {
-    let config = Config::new(TEST_USER_AGENT);
-    EdgarClient::new(config).map_err(Into::into)
+///
+/// Responses are replayed from the fixtures in `tests/fixtures`, so the tests run
+/// offline. With `EDGAR_RECORD_FIXTURES` set, requests without a fixture are sent
+/// to the SEC and recorded; delete a fixture to refresh it.
+pub fn create_test_client() -> Result<EdgarClient<MockHttpClient>, Box<dyn std::error::Error>> {
+    if std::env::var_os(RECORD_FIXTURES_VAR).is_some() {
+        let http_client = MockHttpClient::record(ReqwestClient::new()?, FIXTURES_DIR)?;
+        return Ok(EdgarClient::with_client(http_client, Config::new(TEST_USER_AGENT)));
+    }
+
+    let http_client = MockHttpClient::from_fixtures(FIXTURES_DIR)?;
+    let config = Config::new(TEST_USER_AGENT).without_rate_limit();
+    Ok(EdgarClient::with_client(http_client, config))
 }
 
 
@@ -17,4 +27,4 @@ mod tests {
         let _client = create_test_client().unwrap();
         // Basic test that client can be created
     }
-}
\ No newline at end of file
+}
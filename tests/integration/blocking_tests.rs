@@ -0,0 +1,18 @@
+use edgar_rs::{BlockingEdgarClient, Taxonomy};
+use crate::common::{test_client::create_test_client, constants::{APPLE_CIK, REVENUE_CONCEPT}};
+
+#[test]
+fn test_blocking_client() {
+    let client = create_test_client().expect("Failed to create test client");
+    let client = BlockingEdgarClient::from_async(client).expect("Failed to create blocking client");
+
+    let submissions = client.get_submissions_history(APPLE_CIK).unwrap();
+    assert_eq!(submissions.data.name, "Apple Inc.");
+
+    let concept = client
+        .get_company_concept(APPLE_CIK, Taxonomy::UsGaap, REVENUE_CONCEPT)
+        .unwrap();
+    assert_eq!(concept.data.tag, REVENUE_CONCEPT);
+
+    assert_eq!(client.resolve_cik("AAPL").unwrap(), APPLE_CIK);
+}
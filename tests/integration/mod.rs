@@ -1,4 +1,5 @@
 mod client_tests;
 mod xbrl_tests;
 mod tickers_tests;
-mod error_tests;
\ No newline at end of file
+mod error_tests;
+mod blocking_tests;
\ No newline at end of file
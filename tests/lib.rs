@@ -1,4 +1,5 @@
-// Integration tests for edgar-rs library
+// Integration tests for edgar-rs library, run on the native runtime
+#![cfg(feature = "native")]
 
 mod common;
 mod integration;
\ No newline at end of file